@@ -16,7 +16,7 @@ from google.protobuf import any_pb2 as google_dot_protobuf_dot_any__pb2
 from google.protobuf import descriptor_pb2 as google_dot_protobuf_dot_descriptor__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x13gpyrpc/gpyrpc.proto\x12\x06gpyrpc\x1a\x19google/protobuf/any.proto\x1a google/protobuf/descriptor.proto\")\n\nCmdArgSpec\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t\"[\n\x0f\x43mdOverrideSpec\x12\x0f\n\x07pkgpath\x18\x01 \x01(\t\x12\x12\n\nfield_path\x18\x02 \x01(\t\x12\x13\n\x0b\x66ield_value\x18\x03 \x01(\t\x12\x0e\n\x06\x61\x63tion\x18\x04 \x01(\t\"f\n\x0cRestResponse\x12$\n\x06result\x18\x01 \x01(\x0b\x32\x14.google.protobuf.Any\x12\r\n\x05\x65rror\x18\x02 \x01(\t\x12!\n\x07kcl_err\x18\x03 \x01(\x0b\x32\x10.gpyrpc.KclError\"`\n\x08KclError\x12\x0e\n\x06\x65wcode\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0b\n\x03msg\x18\x03 \x01(\t\x12)\n\x0b\x65rror_infos\x18\x04 \x03(\x0b\x32\x14.gpyrpc.KclErrorInfo\"w\n\x0cKclErrorInfo\x12\x11\n\terr_level\x18\x01 \x01(\t\x12\x0f\n\x07\x61rg_msg\x18\x02 \x01(\t\x12\x10\n\x08\x66ilename\x18\x03 \x01(\t\x12\x10\n\x08src_code\x18\x04 \x01(\t\x12\x0f\n\x07line_no\x18\x05 \x01(\t\x12\x0e\n\x06\x63ol_no\x18\x06 \x01(\t\"\x1a\n\tPing_Args\x12\r\n\x05value\x18\x01 \x01(\t\"\x1c\n\x0bPing_Result\x12\r\n\x05value\x18\x01 \x01(\t\"\x11\n\x0fListMethod_Args\"-\n\x11ListMethod_Result\x12\x18\n\x10method_name_list\x18\x01 \x03(\t\"Z\n\x17ParseFile_LarkTree_Args\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x13\n\x0bsource_code\x18\x02 \x01(\t\x12\x18\n\x10ignore_file_line\x18\x03 \x01(\x08\"3\n\x19ParseFile_LarkTree_Result\x12\x16\n\x0elark_tree_json\x18\x01 \x01(\t\";\n\x12ParseFile_AST_Args\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x13\n\x0bsource_code\x18\x02 \x01(\t\"(\n\x14ParseFile_AST_Result\x12\x10\n\x08\x61st_json\x18\x01 \x01(\t\"0\n\x15ParseProgram_AST_Args\x12\x17\n\x0fk_filename_list\x18\x01 \x03(\t\"+\n\x17ParseProgram_AST_Result\x12\x10\n\x08\x61st_json\x18\x01 \x01(\t\"\xe0\x02\n\x10\x45xecProgram_Args\x12\x10\n\x08work_dir\x18\x01 \x01(\t\x12\x17\n\x0fk_filename_list\x18\x02 \x03(\t\x12\x13\n\x0bk_code_list\x18\x03 \x03(\t\x12 \n\x04\x61rgs\x18\x04 \x03(\x0b\x32\x12.gpyrpc.CmdArgSpec\x12*\n\toverrides\x18\x05 \x03(\x0b\x32\x17.gpyrpc.CmdOverrideSpec\x12\x1b\n\x13\x64isable_yaml_result\x18\x06 \x01(\x08\x12\x1a\n\x12print_override_ast\x18\x07 \x01(\x08\x12\x1a\n\x12strict_range_check\x18\x08 \x01(\x08\x12\x14\n\x0c\x64isable_none\x18\t \x01(\x08\x12\x0f\n\x07verbose\x18\n \x01(\x05\x12\r\n\x05\x64\x65\x62ug\x18\x0b \x01(\x05\x12\x11\n\tsort_keys\x18\x0c \x01(\x08\x12 \n\x18include_schema_type_path\x18\r \x01(\x08\"T\n\x12\x45xecProgram_Result\x12\x13\n\x0bjson_result\x18\x01 \x01(\t\x12\x13\n\x0byaml_result\x18\x02 \x01(\t\x12\x14\n\x0c\x65scaped_time\x18\x65 \x01(\t\"\'\n\x10ResetPlugin_Args\x12\x13\n\x0bplugin_root\x18\x01 \x01(\t\"\x14\n\x12ResetPlugin_Result\"!\n\x0f\x46ormatCode_Args\x12\x0e\n\x06source\x18\x01 \x01(\t\"&\n\x11\x46ormatCode_Result\x12\x11\n\tformatted\x18\x01 \x01(\x0c\"\x1f\n\x0f\x46ormatPath_Args\x12\x0c\n\x04path\x18\x01 \x01(\t\")\n\x11\x46ormatPath_Result\x12\x14\n\x0c\x63hangedPaths\x18\x01 \x03(\t\"\x1d\n\rLintPath_Args\x12\x0c\n\x04path\x18\x01 \x01(\t\"\"\n\x0fLintPath_Result\x12\x0f\n\x07results\x18\x01 \x03(\t\"F\n\x11OverrideFile_Args\x12\x0c\n\x04\x66ile\x18\x01 \x01(\t\x12\r\n\x05specs\x18\x02 \x03(\t\x12\x14\n\x0cimport_paths\x18\x03 \x03(\t\"%\n\x13OverrideFile_Result\x12\x0e\n\x06result\x18\x01 \x01(\x08\"\x1d\n\rEvalCode_Args\x12\x0c\n\x04\x63ode\x18\x01 \x01(\t\"&\n\x0f\x45valCode_Result\x12\x13\n\x0bjson_result\x18\x02 \x01(\t\" \n\x10ResolveCode_Args\x12\x0c\n\x04\x63ode\x18\x01 \x01(\t\"%\n\x12ResolveCode_Result\x12\x0f\n\x07success\x18\x01 \x01(\x08\"E\n\x12GetSchemaType_Args\x12\x0c\n\x04\x66ile\x18\x01 \x01(\t\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\x12\x13\n\x0bschema_name\x18\x03 \x01(\t\"A\n\x14GetSchemaType_Result\x12)\n\x10schema_type_list\x18\x01 \x03(\x0b\x32\x0f.gpyrpc.KclType\"g\n\x11ValidateCode_Args\x12\x0c\n\x04\x64\x61ta\x18\x01 \x01(\t\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\x12\x0e\n\x06schema\x18\x03 \x01(\t\x12\x16\n\x0e\x61ttribute_name\x18\x04 \x01(\t\x12\x0e\n\x06\x66ormat\x18\x05 \x01(\t\";\n\x13ValidateCode_Result\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x13\n\x0b\x65rr_message\x18\x02 \x01(\t\"+\n\x0b\x43odeSnippet\x12\x0e\n\x06schema\x18\x01 \x01(\t\x12\x0c\n\x04rule\x18\x02 \x01(\t\"<\n\x0fSpliceCode_Args\x12)\n\x0c\x63odeSnippets\x18\x01 \x03(\x0b\x32\x13.gpyrpc.CodeSnippet\"\'\n\x11SpliceCode_Result\x12\x12\n\nspliceCode\x18\x01 \x01(\t\":\n\x08Position\x12\x0c\n\x04line\x18\x01 \x01(\x03\x12\x0e\n\x06\x63olumn\x18\x02 \x01(\x03\x12\x10\n\x08\x66ilename\x18\x03 \x01(\t\"J\n\rComplete_Args\x12\x1d\n\x03pos\x18\x01 \x01(\x0b\x32\x10.gpyrpc.Position\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0c\n\x04\x63ode\x18\x03 \x01(\t\"(\n\x0f\x43omplete_Result\x12\x15\n\rcompleteItems\x18\x01 \x01(\t\";\n\x0cGoToDef_Args\x12\x1d\n\x03pos\x18\x01 \x01(\x0b\x32\x10.gpyrpc.Position\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\"#\n\x0eGoToDef_Result\x12\x11\n\tlocations\x18\x01 \x01(\t\"1\n\x13\x44ocumentSymbol_Args\x12\x0c\n\x04\x66ile\x18\x01 \x01(\t\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\"\'\n\x15\x44ocumentSymbol_Result\x12\x0e\n\x06symbol\x18\x01 \x01(\t\"9\n\nHover_Args\x12\x1d\n\x03pos\x18\x01 \x01(\x0b\x32\x10.gpyrpc.Position\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\"#\n\x0cHover_Result\x12\x13\n\x0bhoverResult\x18\x01 \x01(\t\"i\n\x11ListDepFiles_Args\x12\x10\n\x08work_dir\x18\x01 \x01(\t\x12\x14\n\x0cuse_abs_path\x18\x02 \x01(\x08\x12\x13\n\x0binclude_all\x18\x03 \x01(\x08\x12\x17\n\x0fuse_fast_parser\x18\x04 \x01(\x08\"F\n\x13ListDepFiles_Result\x12\x0f\n\x07pkgroot\x18\x01 \x01(\t\x12\x0f\n\x07pkgpath\x18\x02 \x01(\t\x12\r\n\x05\x66iles\x18\x03 \x03(\t\"9\n\x16LoadSettingsFiles_Args\x12\x10\n\x08work_dir\x18\x01 \x01(\t\x12\r\n\x05\x66iles\x18\x02 \x03(\t\"q\n\x18LoadSettingsFiles_Result\x12*\n\x0fkcl_cli_configs\x18\x01 \x01(\x0b\x32\x11.gpyrpc.CliConfig\x12)\n\x0bkcl_options\x18\x02 \x03(\x0b\x32\x14.gpyrpc.KeyValuePair\"\xa6\x01\n\tCliConfig\x12\r\n\x05\x66iles\x18\x01 \x03(\t\x12\x0e\n\x06output\x18\x02 \x01(\t\x12\x11\n\toverrides\x18\x03 \x03(\t\x12\x15\n\rpath_selector\x18\x04 \x03(\t\x12\x1a\n\x12strict_range_check\x18\x05 \x01(\x08\x12\x14\n\x0c\x64isable_none\x18\x06 \x01(\x08\x12\x0f\n\x07verbose\x18\x07 \x01(\x03\x12\r\n\x05\x64\x65\x62ug\x18\x08 \x01(\x08\"*\n\x0cKeyValuePair\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t\"\xf4\x02\n\x07KclType\x12\x0c\n\x04type\x18\x01 \x01(\t\x12$\n\x0bunion_types\x18\x02 \x03(\x0b\x32\x0f.gpyrpc.KclType\x12\x0f\n\x07\x64\x65\x66\x61ult\x18\x03 \x01(\t\x12\x13\n\x0bschema_name\x18\x04 \x01(\t\x12\x12\n\nschema_doc\x18\x05 \x01(\t\x12\x33\n\nproperties\x18\x06 \x03(\x0b\x32\x1f.gpyrpc.KclType.PropertiesEntry\x12\x10\n\x08required\x18\x07 \x03(\t\x12\x1c\n\x03key\x18\x08 \x01(\x0b\x32\x0f.gpyrpc.KclType\x12\x1d\n\x04item\x18\t \x01(\x0b\x32\x0f.gpyrpc.KclType\x12\x0c\n\x04line\x18\n \x01(\x05\x12%\n\ndecorators\x18\x0b \x03(\x0b\x32\x11.gpyrpc.Decorator\x1a\x42\n\x0fPropertiesEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\x1e\n\x05value\x18\x02 \x01(\x0b\x32\x0f.gpyrpc.KclType:\x02\x38\x01\"\x90\x01\n\tDecorator\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x11\n\targuments\x18\x02 \x03(\t\x12\x31\n\x08keywords\x18\x03 \x03(\x0b\x32\x1f.gpyrpc.Decorator.KeywordsEntry\x1a/\n\rKeywordsEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t:\x02\x38\x01\x32\x82\x01\n\x0e\x42uiltinService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\x1a\x13.gpyrpc.Ping_Result\x12@\n\nListMethod\x12\x17.gpyrpc.ListMethod_Args\x1a\x19.gpyrpc.ListMethod_Result2\xb4\x0b\n\x0cKclvmService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\x1a\x13.gpyrpc.Ping_Result\x12X\n\x12ParseFile_LarkTree\x12\x1f.gpyrpc.ParseFile_LarkTree_Args\x1a!.gpyrpc.ParseFile_LarkTree_Result\x12I\n\rParseFile_AST\x12\x1a.gpyrpc.ParseFile_AST_Args\x1a\x1c.gpyrpc.ParseFile_AST_Result\x12R\n\x10ParseProgram_AST\x12\x1d.gpyrpc.ParseProgram_AST_Args\x1a\x1f.gpyrpc.ParseProgram_AST_Result\x12\x43\n\x0b\x45xecProgram\x12\x18.gpyrpc.ExecProgram_Args\x1a\x1a.gpyrpc.ExecProgram_Result\x12\x43\n\x0bResetPlugin\x12\x18.gpyrpc.ResetPlugin_Args\x1a\x1a.gpyrpc.ResetPlugin_Result\x12@\n\nFormatCode\x12\x17.gpyrpc.FormatCode_Args\x1a\x19.gpyrpc.FormatCode_Result\x12@\n\nFormatPath\x12\x17.gpyrpc.FormatPath_Args\x1a\x19.gpyrpc.FormatPath_Result\x12:\n\x08LintPath\x12\x15.gpyrpc.LintPath_Args\x1a\x17.gpyrpc.LintPath_Result\x12\x46\n\x0cOverrideFile\x12\x19.gpyrpc.OverrideFile_Args\x1a\x1b.gpyrpc.OverrideFile_Result\x12:\n\x08\x45valCode\x12\x15.gpyrpc.EvalCode_Args\x1a\x17.gpyrpc.EvalCode_Result\x12\x43\n\x0bResolveCode\x12\x18.gpyrpc.ResolveCode_Args\x1a\x1a.gpyrpc.ResolveCode_Result\x12I\n\rGetSchemaType\x12\x1a.gpyrpc.GetSchemaType_Args\x1a\x1c.gpyrpc.GetSchemaType_Result\x12\x46\n\x0cValidateCode\x12\x19.gpyrpc.ValidateCode_Args\x1a\x1b.gpyrpc.ValidateCode_Result\x12@\n\nSpliceCode\x12\x17.gpyrpc.SpliceCode_Args\x1a\x19.gpyrpc.SpliceCode_Result\x12:\n\x08\x43omplete\x12\x15.gpyrpc.Complete_Args\x1a\x17.gpyrpc.Complete_Result\x12\x37\n\x07GoToDef\x12\x14.gpyrpc.GoToDef_Args\x1a\x16.gpyrpc.GoToDef_Result\x12L\n\x0e\x44ocumentSymbol\x12\x1b.gpyrpc.DocumentSymbol_Args\x1a\x1d.gpyrpc.DocumentSymbol_Result\x12\x31\n\x05Hover\x12\x12.gpyrpc.Hover_Args\x1a\x14.gpyrpc.Hover_Result\x12\x46\n\x0cListDepFiles\x12\x19.gpyrpc.ListDepFiles_Args\x1a\x1b.gpyrpc.ListDepFiles_Result\x12U\n\x11LoadSettingsFiles\x12\x1e.gpyrpc.LoadSettingsFiles_Args\x1a .gpyrpc.LoadSettingsFiles_ResultB0Z.kusionstack.io/kclvm-go/pkg/spec/gpyrpc;gpyrpcb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x13gpyrpc/gpyrpc.proto\x12\x06gpyrpc\x1a\x19google/protobuf/any.proto\x1a google/protobuf/descriptor.proto\")\n\nCmdArgSpec\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t\"[\n\x0f\x43mdOverrideSpec\x12\x0f\n\x07pkgpath\x18\x01 \x01(\t\x12\x12\n\nfield_path\x18\x02 \x01(\t\x12\x13\n\x0b\x66ield_value\x18\x03 \x01(\t\x12\x0e\n\x06\x61\x63tion\x18\x04 \x01(\t\"f\n\x0cRestResponse\x12$\n\x06result\x18\x01 \x01(\x0b\x32\x14.google.protobuf.Any\x12\r\n\x05\x65rror\x18\x02 \x01(\t\x12!\n\x07kcl_err\x18\x03 \x01(\x0b\x32\x10.gpyrpc.KclError\"`\n\x08KclError\x12\x0e\n\x06\x65wcode\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0b\n\x03msg\x18\x03 \x01(\t\x12)\n\x0b\x65rror_infos\x18\x04 \x03(\x0b\x32\x14.gpyrpc.KclErrorInfo\"w\n\x0cKclErrorInfo\x12\x11\n\terr_level\x18\x01 \x01(\t\x12\x0f\n\x07\x61rg_msg\x18\x02 \x01(\t\x12\x10\n\x08\x66ilename\x18\x03 \x01(\t\x12\x10\n\x08src_code\x18\x04 \x01(\t\x12\x0f\n\x07line_no\x18\x05 \x01(\t\x12\x0e\n\x06\x63ol_no\x18\x06 \x01(\t\"\x1a\n\tPing_Args\x12\r\n\x05value\x18\x01 \x01(\t\"\x1c\n\x0bPing_Result\x12\r\n\x05value\x18\x01 \x01(\t\"\x11\n\x0fListMethod_Args\"-\n\x11ListMethod_Result\x12\x18\n\x10method_name_list\x18\x01 \x03(\t\"Z\n\x17ParseFile_LarkTree_Args\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x13\n\x0bsource_code\x18\x02 \x01(\t\x12\x18\n\x10ignore_file_line\x18\x03 \x01(\x08\"3\n\x19ParseFile_LarkTree_Result\x12\x16\n\x0elark_tree_json\x18\x01 \x01(\t\";\n\x12ParseFile_AST_Args\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x13\n\x0bsource_code\x18\x02 \x01(\t\"(\n\x14ParseFile_AST_Result\x12\x10\n\x08\x61st_json\x18\x01 \x01(\t\"0\n\x15ParseProgram_AST_Args\x12\x17\n\x0fk_filename_list\x18\x01 \x03(\t\"+\n\x17ParseProgram_AST_Result\x12\x10\n\x08\x61st_json\x18\x01 \x01(\t\"\xe0\x02\n\x10\x45xecProgram_Args\x12\x10\n\x08work_dir\x18\x01 \x01(\t\x12\x17\n\x0fk_filename_list\x18\x02 \x03(\t\x12\x13\n\x0bk_code_list\x18\x03 \x03(\t\x12 \n\x04\x61rgs\x18\x04 \x03(\x0b\x32\x12.gpyrpc.CmdArgSpec\x12*\n\toverrides\x18\x05 \x03(\x0b\x32\x17.gpyrpc.CmdOverrideSpec\x12\x1b\n\x13\x64isable_yaml_result\x18\x06 \x01(\x08\x12\x1a\n\x12print_override_ast\x18\x07 \x01(\x08\x12\x1a\n\x12strict_range_check\x18\x08 \x01(\x08\x12\x14\n\x0c\x64isable_none\x18\t \x01(\x08\x12\x0f\n\x07verbose\x18\n \x01(\x05\x12\r\n\x05\x64\x65\x62ug\x18\x0b \x01(\x05\x12\x11\n\tsort_keys\x18\x0c \x01(\x08\x12 \n\x18include_schema_type_path\x18\r \x01(\x08\"T\n\x12\x45xecProgram_Result\x12\x13\n\x0bjson_result\x18\x01 \x01(\t\x12\x13\n\x0byaml_result\x18\x02 \x01(\t\x12\x14\n\x0c\x65scaped_time\x18\x65 \x01(\t\"\'\n\x10ResetPlugin_Args\x12\x13\n\x0bplugin_root\x18\x01 \x01(\t\"\x14\n\x12ResetPlugin_Result\"!\n\x0f\x46ormatCode_Args\x12\x0e\n\x06source\x18\x01 \x01(\t\"&\n\x11\x46ormatCode_Result\x12\x11\n\tformatted\x18\x01 \x01(\x0c\"\x1f\n\x0f\x46ormatPath_Args\x12\x0c\n\x04path\x18\x01 \x01(\t\")\n\x11\x46ormatPath_Result\x12\x14\n\x0c\x63hangedPaths\x18\x01 \x03(\t\",\n\x0cTextPosition\x12\x0c\n\x04line\x18\x01 \x01(\x03\x12\x0e\n\x06\x63olumn\x18\x02 \x01(\x03\"S\n\tTextRange\x12#\n\x05start\x18\x01 \x01(\x0b\x32\x14.gpyrpc.TextPosition\x12!\n\x03\x65nd\x18\x02 \x01(\x0b\x32\x14.gpyrpc.TextPosition\">\n\x08TextEdit\x12 \n\x05range\x18\x01 \x01(\x0b\x32\x11.gpyrpc.TextRange\x12\x10\n\x08new_text\x18\x02 \x01(\t\"D\n\x10\x46ormatRange_Args\x12\x0e\n\x06source\x18\x01 \x01(\t\x12 \n\x05range\x18\x02 \x01(\x0b\x32\x11.gpyrpc.TextRange\"5\n\x12\x46ormatRange_Result\x12\x1f\n\x05\x65\x64its\x18\x01 \x03(\x0b\x32\x10.gpyrpc.TextEdit\"K\n\x11\x46ormatOnType_Args\x12\x0e\n\x06source\x18\x01 \x01(\t\x12&\n\x08position\x18\x02 \x01(\x0b\x32\x14.gpyrpc.TextPosition\"6\n\x13\x46ormatOnType_Result\x12\x1f\n\x05\x65\x64its\x18\x01 \x03(\x0b\x32\x10.gpyrpc.TextEdit\"\x1d\n\rLintPath_Args\x12\x0c\n\x04path\x18\x01 \x01(\t\"\"\n\x0fLintPath_Result\x12\x0f\n\x07results\x18\x01 \x03(\t\"^\n\x11OverrideFile_Args\x12\x0c\n\x04\x66ile\x18\x01 \x01(\t\x12\r\n\x05specs\x18\x02 \x03(\t\x12\x14\n\x0cimport_paths\x18\x03 \x03(\t\x12\x16\n\x0e\x63reate_missing\x18\x04 \x01(\x08\"X\n\x13OverrideFile_Result\x12\x0e\n\x06result\x18\x01 \x01(\x08\x12\x31\n\x0cspec_results\x18\x02 \x03(\x0b\x32\x1b.gpyrpc.OverrideSpec_Result\"j\n\x13OverrideSpec_Result\x12\x0c\n\x04spec\x18\x01 \x01(\t\x12\x0e\n\x06status\x18\x02 \x01(\t\x12\x0f\n\x07message\x18\x03 \x01(\t\x12\x12\n\nstart_line\x18\x04 \x01(\x03\x12\x10\n\x08\x65nd_line\x18\x05 \x01(\x03\"\x1d\n\rEvalCode_Args\x12\x0c\n\x04\x63ode\x18\x01 \x01(\t\"&\n\x0f\x45valCode_Result\x12\x13\n\x0bjson_result\x18\x02 \x01(\t\" \n\x10ResolveCode_Args\x12\x0c\n\x04\x63ode\x18\x01 \x01(\t\"%\n\x12ResolveCode_Result\x12\x0f\n\x07success\x18\x01 \x01(\x08\"E\n\x12GetSchemaType_Args\x12\x0c\n\x04\x66ile\x18\x01 \x01(\t\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\x12\x13\n\x0bschema_name\x18\x03 \x01(\t\"A\n\x14GetSchemaType_Result\x12)\n\x10schema_type_list\x18\x01 \x03(\x0b\x32\x0f.gpyrpc.KclType\"g\n\x11ValidateCode_Args\x12\x0c\n\x04\x64\x61ta\x18\x01 \x01(\t\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\x12\x0e\n\x06schema\x18\x03 \x01(\t\x12\x16\n\x0e\x61ttribute_name\x18\x04 \x01(\t\x12\x0e\n\x06\x66ormat\x18\x05 \x01(\t\";\n\x13ValidateCode_Result\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x13\n\x0b\x65rr_message\x18\x02 \x01(\t\"+\n\x0b\x43odeSnippet\x12\x0e\n\x06schema\x18\x01 \x01(\t\x12\x0c\n\x04rule\x18\x02 \x01(\t\"<\n\x0fSpliceCode_Args\x12)\n\x0c\x63odeSnippets\x18\x01 \x03(\x0b\x32\x13.gpyrpc.CodeSnippet\"\'\n\x11SpliceCode_Result\x12\x12\n\nspliceCode\x18\x01 \x01(\t\":\n\x08Position\x12\x0c\n\x04line\x18\x01 \x01(\x03\x12\x0e\n\x06\x63olumn\x18\x02 \x01(\x03\x12\x10\n\x08\x66ilename\x18\x03 \x01(\t\"J\n\rComplete_Args\x12\x1d\n\x03pos\x18\x01 \x01(\x0b\x32\x10.gpyrpc.Position\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0c\n\x04\x63ode\x18\x03 \x01(\t\"(\n\x0f\x43omplete_Result\x12\x15\n\rcompleteItems\x18\x01 \x01(\t\";\n\x0cGoToDef_Args\x12\x1d\n\x03pos\x18\x01 \x01(\x0b\x32\x10.gpyrpc.Position\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\"#\n\x0eGoToDef_Result\x12\x11\n\tlocations\x18\x01 \x01(\t\"1\n\x13\x44ocumentSymbol_Args\x12\x0c\n\x04\x66ile\x18\x01 \x01(\t\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\"\'\n\x15\x44ocumentSymbol_Result\x12\x0e\n\x06symbol\x18\x01 \x01(\t\"9\n\nHover_Args\x12\x1d\n\x03pos\x18\x01 \x01(\x0b\x32\x10.gpyrpc.Position\x12\x0c\n\x04\x63ode\x18\x02 \x01(\t\"#\n\x0cHover_Result\x12\x13\n\x0bhoverResult\x18\x01 \x01(\t\"i\n\x11ListDepFiles_Args\x12\x10\n\x08work_dir\x18\x01 \x01(\t\x12\x14\n\x0cuse_abs_path\x18\x02 \x01(\x08\x12\x13\n\x0binclude_all\x18\x03 \x01(\x08\x12\x17\n\x0fuse_fast_parser\x18\x04 \x01(\x08\"F\n\x13ListDepFiles_Result\x12\x0f\n\x07pkgroot\x18\x01 \x01(\t\x12\x0f\n\x07pkgpath\x18\x02 \x01(\t\x12\r\n\x05\x66iles\x18\x03 \x03(\t\"9\n\x16LoadSettingsFiles_Args\x12\x10\n\x08work_dir\x18\x01 \x01(\t\x12\r\n\x05\x66iles\x18\x02 \x03(\t\"q\n\x18LoadSettingsFiles_Result\x12*\n\x0fkcl_cli_configs\x18\x01 \x01(\x0b\x32\x11.gpyrpc.CliConfig\x12)\n\x0bkcl_options\x18\x02 \x03(\x0b\x32\x14.gpyrpc.KeyValuePair\"\xa6\x01\n\tCliConfig\x12\r\n\x05\x66iles\x18\x01 \x03(\t\x12\x0e\n\x06output\x18\x02 \x01(\t\x12\x11\n\toverrides\x18\x03 \x03(\t\x12\x15\n\rpath_selector\x18\x04 \x03(\t\x12\x1a\n\x12strict_range_check\x18\x05 \x01(\x08\x12\x14\n\x0c\x64isable_none\x18\x06 \x01(\x08\x12\x0f\n\x07verbose\x18\x07 \x01(\x03\x12\r\n\x05\x64\x65\x62ug\x18\x08 \x01(\x08\"*\n\x0cKeyValuePair\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t\"\xf4\x02\n\x07KclType\x12\x0c\n\x04type\x18\x01 \x01(\t\x12$\n\x0bunion_types\x18\x02 \x03(\x0b\x32\x0f.gpyrpc.KclType\x12\x0f\n\x07\x64\x65\x66\x61ult\x18\x03 \x01(\t\x12\x13\n\x0bschema_name\x18\x04 \x01(\t\x12\x12\n\nschema_doc\x18\x05 \x01(\t\x12\x33\n\nproperties\x18\x06 \x03(\x0b\x32\x1f.gpyrpc.KclType.PropertiesEntry\x12\x10\n\x08required\x18\x07 \x03(\t\x12\x1c\n\x03key\x18\x08 \x01(\x0b\x32\x0f.gpyrpc.KclType\x12\x1d\n\x04item\x18\t \x01(\x0b\x32\x0f.gpyrpc.KclType\x12\x0c\n\x04line\x18\n \x01(\x05\x12%\n\ndecorators\x18\x0b \x03(\x0b\x32\x11.gpyrpc.Decorator\x1a\x42\n\x0fPropertiesEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\x1e\n\x05value\x18\x02 \x01(\x0b\x32\x0f.gpyrpc.KclType:\x02\x38\x01\"\x90\x01\n\tDecorator\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x11\n\targuments\x18\x02 \x03(\t\x12\x31\n\x08keywords\x18\x03 \x03(\x0b\x32\x1f.gpyrpc.Decorator.KeywordsEntry\x1a/\n\rKeywordsEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t:\x02\x38\x01\x32\x82\x01\n\x0e\x42uiltinService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\x1a\x13.gpyrpc.Ping_Result\x12@\n\nListMethod\x12\x17.gpyrpc.ListMethod_Args\x1a\x19.gpyrpc.ListMethod_Result2\xc1\x0c\n\x0cKclvmService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\x1a\x13.gpyrpc.Ping_Result\x12X\n\x12ParseFile_LarkTree\x12\x1f.gpyrpc.ParseFile_LarkTree_Args\x1a!.gpyrpc.ParseFile_LarkTree_Result\x12I\n\rParseFile_AST\x12\x1a.gpyrpc.ParseFile_AST_Args\x1a\x1c.gpyrpc.ParseFile_AST_Result\x12R\n\x10ParseProgram_AST\x12\x1d.gpyrpc.ParseProgram_AST_Args\x1a\x1f.gpyrpc.ParseProgram_AST_Result\x12\x43\n\x0b\x45xecProgram\x12\x18.gpyrpc.ExecProgram_Args\x1a\x1a.gpyrpc.ExecProgram_Result\x12\x43\n\x0bResetPlugin\x12\x18.gpyrpc.ResetPlugin_Args\x1a\x1a.gpyrpc.ResetPlugin_Result\x12@\n\nFormatCode\x12\x17.gpyrpc.FormatCode_Args\x1a\x19.gpyrpc.FormatCode_Result\x12@\n\nFormatPath\x12\x17.gpyrpc.FormatPath_Args\x1a\x19.gpyrpc.FormatPath_Result\x12\x43\n\x0b\x46ormatRange\x12\x18.gpyrpc.FormatRange_Args\x1a\x1a.gpyrpc.FormatRange_Result\x12\x46\n\x0c\x46ormatOnType\x12\x19.gpyrpc.FormatOnType_Args\x1a\x1b.gpyrpc.FormatOnType_Result\x12:\n\x08LintPath\x12\x15.gpyrpc.LintPath_Args\x1a\x17.gpyrpc.LintPath_Result\x12\x46\n\x0cOverrideFile\x12\x19.gpyrpc.OverrideFile_Args\x1a\x1b.gpyrpc.OverrideFile_Result\x12:\n\x08\x45valCode\x12\x15.gpyrpc.EvalCode_Args\x1a\x17.gpyrpc.EvalCode_Result\x12\x43\n\x0bResolveCode\x12\x18.gpyrpc.ResolveCode_Args\x1a\x1a.gpyrpc.ResolveCode_Result\x12I\n\rGetSchemaType\x12\x1a.gpyrpc.GetSchemaType_Args\x1a\x1c.gpyrpc.GetSchemaType_Result\x12\x46\n\x0cValidateCode\x12\x19.gpyrpc.ValidateCode_Args\x1a\x1b.gpyrpc.ValidateCode_Result\x12@\n\nSpliceCode\x12\x17.gpyrpc.SpliceCode_Args\x1a\x19.gpyrpc.SpliceCode_Result\x12:\n\x08\x43omplete\x12\x15.gpyrpc.Complete_Args\x1a\x17.gpyrpc.Complete_Result\x12\x37\n\x07GoToDef\x12\x14.gpyrpc.GoToDef_Args\x1a\x16.gpyrpc.GoToDef_Result\x12L\n\x0e\x44ocumentSymbol\x12\x1b.gpyrpc.DocumentSymbol_Args\x1a\x1d.gpyrpc.DocumentSymbol_Result\x12\x31\n\x05Hover\x12\x12.gpyrpc.Hover_Args\x1a\x14.gpyrpc.Hover_Result\x12\x46\n\x0cListDepFiles\x12\x19.gpyrpc.ListDepFiles_Args\x1a\x1b.gpyrpc.ListDepFiles_Result\x12U\n\x11LoadSettingsFiles\x12\x1e.gpyrpc.LoadSettingsFiles_Args\x1a .gpyrpc.LoadSettingsFiles_ResultB0Z.kusionstack.io/kclvm-go/pkg/spec/gpyrpc;gpyrpcb\x06proto3')
 
 
 
@@ -43,10 +43,18 @@ _FORMATCODE_ARGS = DESCRIPTOR.message_types_by_name['FormatCode_Args']
 _FORMATCODE_RESULT = DESCRIPTOR.message_types_by_name['FormatCode_Result']
 _FORMATPATH_ARGS = DESCRIPTOR.message_types_by_name['FormatPath_Args']
 _FORMATPATH_RESULT = DESCRIPTOR.message_types_by_name['FormatPath_Result']
+_TEXTPOSITION = DESCRIPTOR.message_types_by_name['TextPosition']
+_TEXTRANGE = DESCRIPTOR.message_types_by_name['TextRange']
+_TEXTEDIT = DESCRIPTOR.message_types_by_name['TextEdit']
+_FORMATRANGE_ARGS = DESCRIPTOR.message_types_by_name['FormatRange_Args']
+_FORMATRANGE_RESULT = DESCRIPTOR.message_types_by_name['FormatRange_Result']
+_FORMATONTYPE_ARGS = DESCRIPTOR.message_types_by_name['FormatOnType_Args']
+_FORMATONTYPE_RESULT = DESCRIPTOR.message_types_by_name['FormatOnType_Result']
 _LINTPATH_ARGS = DESCRIPTOR.message_types_by_name['LintPath_Args']
 _LINTPATH_RESULT = DESCRIPTOR.message_types_by_name['LintPath_Result']
 _OVERRIDEFILE_ARGS = DESCRIPTOR.message_types_by_name['OverrideFile_Args']
 _OVERRIDEFILE_RESULT = DESCRIPTOR.message_types_by_name['OverrideFile_Result']
+_OVERRIDESPEC_RESULT = DESCRIPTOR.message_types_by_name['OverrideSpec_Result']
 _EVALCODE_ARGS = DESCRIPTOR.message_types_by_name['EvalCode_Args']
 _EVALCODE_RESULT = DESCRIPTOR.message_types_by_name['EvalCode_Result']
 _RESOLVECODE_ARGS = DESCRIPTOR.message_types_by_name['ResolveCode_Args']
@@ -238,6 +246,55 @@ FormatPath_Result = _reflection.GeneratedProtocolMessageType('FormatPath_Result'
   })
 _sym_db.RegisterMessage(FormatPath_Result)
 
+TextPosition = _reflection.GeneratedProtocolMessageType('TextPosition', (_message.Message,), {
+  'DESCRIPTOR' : _TEXTPOSITION,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.TextPosition)
+  })
+_sym_db.RegisterMessage(TextPosition)
+
+TextRange = _reflection.GeneratedProtocolMessageType('TextRange', (_message.Message,), {
+  'DESCRIPTOR' : _TEXTRANGE,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.TextRange)
+  })
+_sym_db.RegisterMessage(TextRange)
+
+TextEdit = _reflection.GeneratedProtocolMessageType('TextEdit', (_message.Message,), {
+  'DESCRIPTOR' : _TEXTEDIT,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.TextEdit)
+  })
+_sym_db.RegisterMessage(TextEdit)
+
+FormatRange_Args = _reflection.GeneratedProtocolMessageType('FormatRange_Args', (_message.Message,), {
+  'DESCRIPTOR' : _FORMATRANGE_ARGS,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.FormatRange_Args)
+  })
+_sym_db.RegisterMessage(FormatRange_Args)
+
+FormatRange_Result = _reflection.GeneratedProtocolMessageType('FormatRange_Result', (_message.Message,), {
+  'DESCRIPTOR' : _FORMATRANGE_RESULT,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.FormatRange_Result)
+  })
+_sym_db.RegisterMessage(FormatRange_Result)
+
+FormatOnType_Args = _reflection.GeneratedProtocolMessageType('FormatOnType_Args', (_message.Message,), {
+  'DESCRIPTOR' : _FORMATONTYPE_ARGS,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.FormatOnType_Args)
+  })
+_sym_db.RegisterMessage(FormatOnType_Args)
+
+FormatOnType_Result = _reflection.GeneratedProtocolMessageType('FormatOnType_Result', (_message.Message,), {
+  'DESCRIPTOR' : _FORMATONTYPE_RESULT,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.FormatOnType_Result)
+  })
+_sym_db.RegisterMessage(FormatOnType_Result)
+
 LintPath_Args = _reflection.GeneratedProtocolMessageType('LintPath_Args', (_message.Message,), {
   'DESCRIPTOR' : _LINTPATH_ARGS,
   '__module__' : 'gpyrpc.gpyrpc_pb2'
@@ -266,6 +323,13 @@ OverrideFile_Result = _reflection.GeneratedProtocolMessageType('OverrideFile_Res
   })
 _sym_db.RegisterMessage(OverrideFile_Result)
 
+OverrideSpec_Result = _reflection.GeneratedProtocolMessageType('OverrideSpec_Result', (_message.Message,), {
+  'DESCRIPTOR' : _OVERRIDESPEC_RESULT,
+  '__module__' : 'gpyrpc.gpyrpc_pb2'
+  # @@protoc_insertion_point(class_scope:gpyrpc.OverrideSpec_Result)
+  })
+_sym_db.RegisterMessage(OverrideSpec_Result)
+
 EvalCode_Args = _reflection.GeneratedProtocolMessageType('EvalCode_Args', (_message.Message,), {
   'DESCRIPTOR' : _EVALCODE_ARGS,
   '__module__' : 'gpyrpc.gpyrpc_pb2'
@@ -534,76 +598,92 @@ if _descriptor._USE_C_DESCRIPTORS == False:
   _FORMATPATH_ARGS._serialized_end=1628
   _FORMATPATH_RESULT._serialized_start=1630
   _FORMATPATH_RESULT._serialized_end=1671
-  _LINTPATH_ARGS._serialized_start=1673
-  _LINTPATH_ARGS._serialized_end=1702
-  _LINTPATH_RESULT._serialized_start=1704
-  _LINTPATH_RESULT._serialized_end=1738
-  _OVERRIDEFILE_ARGS._serialized_start=1740
-  _OVERRIDEFILE_ARGS._serialized_end=1810
-  _OVERRIDEFILE_RESULT._serialized_start=1812
-  _OVERRIDEFILE_RESULT._serialized_end=1849
-  _EVALCODE_ARGS._serialized_start=1851
-  _EVALCODE_ARGS._serialized_end=1880
-  _EVALCODE_RESULT._serialized_start=1882
-  _EVALCODE_RESULT._serialized_end=1920
-  _RESOLVECODE_ARGS._serialized_start=1922
-  _RESOLVECODE_ARGS._serialized_end=1954
-  _RESOLVECODE_RESULT._serialized_start=1956
-  _RESOLVECODE_RESULT._serialized_end=1993
-  _GETSCHEMATYPE_ARGS._serialized_start=1995
-  _GETSCHEMATYPE_ARGS._serialized_end=2064
-  _GETSCHEMATYPE_RESULT._serialized_start=2066
-  _GETSCHEMATYPE_RESULT._serialized_end=2131
-  _VALIDATECODE_ARGS._serialized_start=2133
-  _VALIDATECODE_ARGS._serialized_end=2236
-  _VALIDATECODE_RESULT._serialized_start=2238
-  _VALIDATECODE_RESULT._serialized_end=2297
-  _CODESNIPPET._serialized_start=2299
-  _CODESNIPPET._serialized_end=2342
-  _SPLICECODE_ARGS._serialized_start=2344
-  _SPLICECODE_ARGS._serialized_end=2404
-  _SPLICECODE_RESULT._serialized_start=2406
-  _SPLICECODE_RESULT._serialized_end=2445
-  _POSITION._serialized_start=2447
-  _POSITION._serialized_end=2505
-  _COMPLETE_ARGS._serialized_start=2507
-  _COMPLETE_ARGS._serialized_end=2581
-  _COMPLETE_RESULT._serialized_start=2583
-  _COMPLETE_RESULT._serialized_end=2623
-  _GOTODEF_ARGS._serialized_start=2625
-  _GOTODEF_ARGS._serialized_end=2684
-  _GOTODEF_RESULT._serialized_start=2686
-  _GOTODEF_RESULT._serialized_end=2721
-  _DOCUMENTSYMBOL_ARGS._serialized_start=2723
-  _DOCUMENTSYMBOL_ARGS._serialized_end=2772
-  _DOCUMENTSYMBOL_RESULT._serialized_start=2774
-  _DOCUMENTSYMBOL_RESULT._serialized_end=2813
-  _HOVER_ARGS._serialized_start=2815
-  _HOVER_ARGS._serialized_end=2872
-  _HOVER_RESULT._serialized_start=2874
-  _HOVER_RESULT._serialized_end=2909
-  _LISTDEPFILES_ARGS._serialized_start=2911
-  _LISTDEPFILES_ARGS._serialized_end=3016
-  _LISTDEPFILES_RESULT._serialized_start=3018
-  _LISTDEPFILES_RESULT._serialized_end=3088
-  _LOADSETTINGSFILES_ARGS._serialized_start=3090
-  _LOADSETTINGSFILES_ARGS._serialized_end=3147
-  _LOADSETTINGSFILES_RESULT._serialized_start=3149
-  _LOADSETTINGSFILES_RESULT._serialized_end=3262
-  _CLICONFIG._serialized_start=3265
-  _CLICONFIG._serialized_end=3431
-  _KEYVALUEPAIR._serialized_start=3433
-  _KEYVALUEPAIR._serialized_end=3475
-  _KCLTYPE._serialized_start=3478
-  _KCLTYPE._serialized_end=3850
-  _KCLTYPE_PROPERTIESENTRY._serialized_start=3784
-  _KCLTYPE_PROPERTIESENTRY._serialized_end=3850
-  _DECORATOR._serialized_start=3853
-  _DECORATOR._serialized_end=3997
-  _DECORATOR_KEYWORDSENTRY._serialized_start=3950
-  _DECORATOR_KEYWORDSENTRY._serialized_end=3997
-  _BUILTINSERVICE._serialized_start=4000
-  _BUILTINSERVICE._serialized_end=4130
-  _KCLVMSERVICE._serialized_start=4133
-  _KCLVMSERVICE._serialized_end=5593
+  _TEXTPOSITION._serialized_start=1673
+  _TEXTPOSITION._serialized_end=1717
+  _TEXTRANGE._serialized_start=1719
+  _TEXTRANGE._serialized_end=1802
+  _TEXTEDIT._serialized_start=1804
+  _TEXTEDIT._serialized_end=1866
+  _FORMATRANGE_ARGS._serialized_start=1868
+  _FORMATRANGE_ARGS._serialized_end=1936
+  _FORMATRANGE_RESULT._serialized_start=1938
+  _FORMATRANGE_RESULT._serialized_end=1991
+  _FORMATONTYPE_ARGS._serialized_start=1993
+  _FORMATONTYPE_ARGS._serialized_end=2068
+  _FORMATONTYPE_RESULT._serialized_start=2070
+  _FORMATONTYPE_RESULT._serialized_end=2124
+  _LINTPATH_ARGS._serialized_start=2126
+  _LINTPATH_ARGS._serialized_end=2155
+  _LINTPATH_RESULT._serialized_start=2157
+  _LINTPATH_RESULT._serialized_end=2191
+  _OVERRIDEFILE_ARGS._serialized_start=2193
+  _OVERRIDEFILE_ARGS._serialized_end=2287
+  _OVERRIDEFILE_RESULT._serialized_start=2289
+  _OVERRIDEFILE_RESULT._serialized_end=2377
+  _OVERRIDESPEC_RESULT._serialized_start=2379
+  _OVERRIDESPEC_RESULT._serialized_end=2485
+  _EVALCODE_ARGS._serialized_start=2487
+  _EVALCODE_ARGS._serialized_end=2516
+  _EVALCODE_RESULT._serialized_start=2518
+  _EVALCODE_RESULT._serialized_end=2556
+  _RESOLVECODE_ARGS._serialized_start=2558
+  _RESOLVECODE_ARGS._serialized_end=2590
+  _RESOLVECODE_RESULT._serialized_start=2592
+  _RESOLVECODE_RESULT._serialized_end=2629
+  _GETSCHEMATYPE_ARGS._serialized_start=2631
+  _GETSCHEMATYPE_ARGS._serialized_end=2700
+  _GETSCHEMATYPE_RESULT._serialized_start=2702
+  _GETSCHEMATYPE_RESULT._serialized_end=2767
+  _VALIDATECODE_ARGS._serialized_start=2769
+  _VALIDATECODE_ARGS._serialized_end=2872
+  _VALIDATECODE_RESULT._serialized_start=2874
+  _VALIDATECODE_RESULT._serialized_end=2933
+  _CODESNIPPET._serialized_start=2935
+  _CODESNIPPET._serialized_end=2978
+  _SPLICECODE_ARGS._serialized_start=2980
+  _SPLICECODE_ARGS._serialized_end=3040
+  _SPLICECODE_RESULT._serialized_start=3042
+  _SPLICECODE_RESULT._serialized_end=3081
+  _POSITION._serialized_start=3083
+  _POSITION._serialized_end=3141
+  _COMPLETE_ARGS._serialized_start=3143
+  _COMPLETE_ARGS._serialized_end=3217
+  _COMPLETE_RESULT._serialized_start=3219
+  _COMPLETE_RESULT._serialized_end=3259
+  _GOTODEF_ARGS._serialized_start=3261
+  _GOTODEF_ARGS._serialized_end=3320
+  _GOTODEF_RESULT._serialized_start=3322
+  _GOTODEF_RESULT._serialized_end=3357
+  _DOCUMENTSYMBOL_ARGS._serialized_start=3359
+  _DOCUMENTSYMBOL_ARGS._serialized_end=3408
+  _DOCUMENTSYMBOL_RESULT._serialized_start=3410
+  _DOCUMENTSYMBOL_RESULT._serialized_end=3449
+  _HOVER_ARGS._serialized_start=3451
+  _HOVER_ARGS._serialized_end=3508
+  _HOVER_RESULT._serialized_start=3510
+  _HOVER_RESULT._serialized_end=3545
+  _LISTDEPFILES_ARGS._serialized_start=3547
+  _LISTDEPFILES_ARGS._serialized_end=3652
+  _LISTDEPFILES_RESULT._serialized_start=3654
+  _LISTDEPFILES_RESULT._serialized_end=3724
+  _LOADSETTINGSFILES_ARGS._serialized_start=3726
+  _LOADSETTINGSFILES_ARGS._serialized_end=3783
+  _LOADSETTINGSFILES_RESULT._serialized_start=3785
+  _LOADSETTINGSFILES_RESULT._serialized_end=3898
+  _CLICONFIG._serialized_start=3901
+  _CLICONFIG._serialized_end=4067
+  _KEYVALUEPAIR._serialized_start=4069
+  _KEYVALUEPAIR._serialized_end=4111
+  _KCLTYPE._serialized_start=4114
+  _KCLTYPE._serialized_end=4486
+  _KCLTYPE_PROPERTIESENTRY._serialized_start=4420
+  _KCLTYPE_PROPERTIESENTRY._serialized_end=4486
+  _DECORATOR._serialized_start=4489
+  _DECORATOR._serialized_end=4633
+  _DECORATOR_KEYWORDSENTRY._serialized_start=4586
+  _DECORATOR_KEYWORDSENTRY._serialized_end=4633
+  _BUILTINSERVICE._serialized_start=4636
+  _BUILTINSERVICE._serialized_end=4766
+  _KCLVMSERVICE._serialized_start=4769
+  _KCLVMSERVICE._serialized_end=6370
 # @@protoc_insertion_point(module_scope)
@@ -73,6 +73,86 @@ fn test_c_api_call_override_file() {
     let except_result =
         parse_message_from_json::<OverrideFile_Result>(&except_result_json).unwrap();
     assert_eq!(result.result, except_result.result);
+    assert_eq!(
+        result
+            .spec_results
+            .iter()
+            .map(|r| (r.spec.as_str(), r.status.as_str()))
+            .collect::<Vec<_>>(),
+        except_result
+            .spec_results
+            .iter()
+            .map(|r| (r.spec.as_str(), r.status.as_str()))
+            .collect::<Vec<_>>()
+    );
+
+    kclvm_service_delete(serv);
+    kclvm_service_free_string(result_ptr);
+}
+
+#[test]
+fn test_c_api_call_format_range() {
+    let serv = kclvm_service_new(0);
+    let input_path = Path::new(TEST_DATA_PATH).join("format-range.json");
+    let input = fs::read_to_string(&input_path)
+        .expect(format!("Something went wrong reading {}", input_path.display()).as_str());
+    let args = unsafe {
+        CString::from_vec_unchecked(
+            parse_message_from_json::<FormatRange_Args>(&input)
+                .unwrap()
+                .write_to_bytes()
+                .unwrap(),
+        )
+    };
+    let call = CString::new("KclvmService.FormatRange").unwrap();
+    let result_ptr = kclvm_service_call(serv, call.as_ptr(), args.as_ptr()) as *mut i8;
+    let result = unsafe { CStr::from_ptr(result_ptr) };
+
+    let result = parse_message_from_protobuf::<FormatRange_Result>(result.to_bytes()).unwrap();
+    let except_result_path = Path::new(TEST_DATA_PATH).join("format-range.response.json");
+    let except_result_json = fs::read_to_string(&except_result_path).expect(
+        format!(
+            "Something went wrong reading {}",
+            except_result_path.display()
+        )
+        .as_str(),
+    );
+    let except_result = parse_message_from_json::<FormatRange_Result>(&except_result_json).unwrap();
+    assert_eq!(result.edits, except_result.edits);
+
+    kclvm_service_delete(serv);
+    kclvm_service_free_string(result_ptr);
+}
+
+#[test]
+fn test_c_api_call_lint_path() {
+    let serv = kclvm_service_new(0);
+    let input_path = Path::new(TEST_DATA_PATH).join("lint-path.json");
+    let input = fs::read_to_string(&input_path)
+        .expect(format!("Something went wrong reading {}", input_path.display()).as_str());
+    let args = unsafe {
+        CString::from_vec_unchecked(
+            parse_message_from_json::<LintPath_Args>(&input)
+                .unwrap()
+                .write_to_bytes()
+                .unwrap(),
+        )
+    };
+    let call = CString::new("KclvmService.LintPath").unwrap();
+    let result_ptr = kclvm_service_call(serv, call.as_ptr(), args.as_ptr()) as *mut i8;
+    let result = unsafe { CStr::from_ptr(result_ptr) };
+
+    let result = parse_message_from_protobuf::<LintPath_Result>(result.to_bytes()).unwrap();
+    let except_result_path = Path::new(TEST_DATA_PATH).join("lint-path.response.json");
+    let except_result_json = fs::read_to_string(&except_result_path).expect(
+        format!(
+            "Something went wrong reading {}",
+            except_result_path.display()
+        )
+        .as_str(),
+    );
+    let except_result = parse_message_from_json::<LintPath_Result>(&except_result_json).unwrap();
+    assert_eq!(result.results, except_result.results);
 
     kclvm_service_delete(serv);
     kclvm_service_free_string(result_ptr);
@@ -3356,6 +3356,957 @@ impl ::protobuf::reflect::ProtobufValue for FormatPath_Result {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.TextPosition)
+pub struct TextPosition {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.TextPosition.line)
+    pub line: i64,
+    // @@protoc_insertion_point(field:gpyrpc.TextPosition.column)
+    pub column: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.TextPosition.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TextPosition {
+    fn default() -> &'a TextPosition {
+        <TextPosition as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TextPosition {
+    pub fn new() -> TextPosition {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "line",
+            |m: &TextPosition| { &m.line },
+            |m: &mut TextPosition| { &mut m.line },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "column",
+            |m: &TextPosition| { &m.column },
+            |m: &mut TextPosition| { &mut m.column },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TextPosition>(
+            "TextPosition",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TextPosition {
+    const NAME: &'static str = "TextPosition";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.line = is.read_int64()?;
+                },
+                16 => {
+                    self.column = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.line != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.line);
+        }
+        if self.column != 0 {
+            my_size += ::protobuf::rt::int64_size(2, self.column);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.line != 0 {
+            os.write_int64(1, self.line)?;
+        }
+        if self.column != 0 {
+            os.write_int64(2, self.column)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TextPosition {
+        TextPosition::new()
+    }
+
+    fn clear(&mut self) {
+        self.line = 0;
+        self.column = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TextPosition {
+        static instance: TextPosition = TextPosition {
+            line: 0,
+            column: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TextPosition {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TextPosition").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TextPosition {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TextPosition {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.TextRange)
+pub struct TextRange {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.TextRange.start)
+    pub start: ::protobuf::MessageField<TextPosition>,
+    // @@protoc_insertion_point(field:gpyrpc.TextRange.end)
+    pub end: ::protobuf::MessageField<TextPosition>,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.TextRange.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TextRange {
+    fn default() -> &'a TextRange {
+        <TextRange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TextRange {
+    pub fn new() -> TextRange {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TextPosition>(
+            "start",
+            |m: &TextRange| { &m.start },
+            |m: &mut TextRange| { &mut m.start },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TextPosition>(
+            "end",
+            |m: &TextRange| { &m.end },
+            |m: &mut TextRange| { &mut m.end },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TextRange>(
+            "TextRange",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TextRange {
+    const NAME: &'static str = "TextRange";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.start)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.end)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.start.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.end.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.start.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.end.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TextRange {
+        TextRange::new()
+    }
+
+    fn clear(&mut self) {
+        self.start.clear();
+        self.end.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TextRange {
+        static instance: TextRange = TextRange {
+            start: ::protobuf::MessageField::none(),
+            end: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TextRange {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TextRange").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TextRange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TextRange {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.TextEdit)
+pub struct TextEdit {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.TextEdit.range)
+    pub range: ::protobuf::MessageField<TextRange>,
+    // @@protoc_insertion_point(field:gpyrpc.TextEdit.new_text)
+    pub new_text: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.TextEdit.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TextEdit {
+    fn default() -> &'a TextEdit {
+        <TextEdit as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TextEdit {
+    pub fn new() -> TextEdit {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TextRange>(
+            "range",
+            |m: &TextEdit| { &m.range },
+            |m: &mut TextEdit| { &mut m.range },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "new_text",
+            |m: &TextEdit| { &m.new_text },
+            |m: &mut TextEdit| { &mut m.new_text },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TextEdit>(
+            "TextEdit",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TextEdit {
+    const NAME: &'static str = "TextEdit";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.range)?;
+                },
+                18 => {
+                    self.new_text = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.range.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.new_text.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_text);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.range.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.new_text.is_empty() {
+            os.write_string(2, &self.new_text)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TextEdit {
+        TextEdit::new()
+    }
+
+    fn clear(&mut self) {
+        self.range.clear();
+        self.new_text.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TextEdit {
+        static instance: TextEdit = TextEdit {
+            range: ::protobuf::MessageField::none(),
+            new_text: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TextEdit {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TextEdit").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TextEdit {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TextEdit {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.FormatRange_Args)
+pub struct FormatRange_Args {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.FormatRange_Args.source)
+    pub source: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.FormatRange_Args.range)
+    pub range: ::protobuf::MessageField<TextRange>,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.FormatRange_Args.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FormatRange_Args {
+    fn default() -> &'a FormatRange_Args {
+        <FormatRange_Args as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FormatRange_Args {
+    pub fn new() -> FormatRange_Args {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "source",
+            |m: &FormatRange_Args| { &m.source },
+            |m: &mut FormatRange_Args| { &mut m.source },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TextRange>(
+            "range",
+            |m: &FormatRange_Args| { &m.range },
+            |m: &mut FormatRange_Args| { &mut m.range },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FormatRange_Args>(
+            "FormatRange_Args",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FormatRange_Args {
+    const NAME: &'static str = "FormatRange_Args";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.source = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.range)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.source.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.source);
+        }
+        if let Some(v) = self.range.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.source.is_empty() {
+            os.write_string(1, &self.source)?;
+        }
+        if let Some(v) = self.range.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FormatRange_Args {
+        FormatRange_Args::new()
+    }
+
+    fn clear(&mut self) {
+        self.source.clear();
+        self.range.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FormatRange_Args {
+        static instance: FormatRange_Args = FormatRange_Args {
+            source: ::std::string::String::new(),
+            range: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FormatRange_Args {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FormatRange_Args").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FormatRange_Args {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FormatRange_Args {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.FormatRange_Result)
+pub struct FormatRange_Result {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.FormatRange_Result.edits)
+    pub edits: ::std::vec::Vec<TextEdit>,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.FormatRange_Result.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FormatRange_Result {
+    fn default() -> &'a FormatRange_Result {
+        <FormatRange_Result as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FormatRange_Result {
+    pub fn new() -> FormatRange_Result {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "edits",
+            |m: &FormatRange_Result| { &m.edits },
+            |m: &mut FormatRange_Result| { &mut m.edits },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FormatRange_Result>(
+            "FormatRange_Result",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FormatRange_Result {
+    const NAME: &'static str = "FormatRange_Result";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.edits.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.edits {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.edits {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FormatRange_Result {
+        FormatRange_Result::new()
+    }
+
+    fn clear(&mut self) {
+        self.edits.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FormatRange_Result {
+        static instance: FormatRange_Result = FormatRange_Result {
+            edits: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FormatRange_Result {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FormatRange_Result").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FormatRange_Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FormatRange_Result {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.FormatOnType_Args)
+pub struct FormatOnType_Args {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.FormatOnType_Args.source)
+    pub source: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.FormatOnType_Args.position)
+    pub position: ::protobuf::MessageField<TextPosition>,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.FormatOnType_Args.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FormatOnType_Args {
+    fn default() -> &'a FormatOnType_Args {
+        <FormatOnType_Args as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FormatOnType_Args {
+    pub fn new() -> FormatOnType_Args {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "source",
+            |m: &FormatOnType_Args| { &m.source },
+            |m: &mut FormatOnType_Args| { &mut m.source },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TextPosition>(
+            "position",
+            |m: &FormatOnType_Args| { &m.position },
+            |m: &mut FormatOnType_Args| { &mut m.position },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FormatOnType_Args>(
+            "FormatOnType_Args",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FormatOnType_Args {
+    const NAME: &'static str = "FormatOnType_Args";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.source = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.position)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.source.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.source);
+        }
+        if let Some(v) = self.position.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.source.is_empty() {
+            os.write_string(1, &self.source)?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FormatOnType_Args {
+        FormatOnType_Args::new()
+    }
+
+    fn clear(&mut self) {
+        self.source.clear();
+        self.position.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FormatOnType_Args {
+        static instance: FormatOnType_Args = FormatOnType_Args {
+            source: ::std::string::String::new(),
+            position: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FormatOnType_Args {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FormatOnType_Args").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FormatOnType_Args {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FormatOnType_Args {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.FormatOnType_Result)
+pub struct FormatOnType_Result {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.FormatOnType_Result.edits)
+    pub edits: ::std::vec::Vec<TextEdit>,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.FormatOnType_Result.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FormatOnType_Result {
+    fn default() -> &'a FormatOnType_Result {
+        <FormatOnType_Result as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FormatOnType_Result {
+    pub fn new() -> FormatOnType_Result {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "edits",
+            |m: &FormatOnType_Result| { &m.edits },
+            |m: &mut FormatOnType_Result| { &mut m.edits },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FormatOnType_Result>(
+            "FormatOnType_Result",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FormatOnType_Result {
+    const NAME: &'static str = "FormatOnType_Result";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.edits.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.edits {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.edits {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FormatOnType_Result {
+        FormatOnType_Result::new()
+    }
+
+    fn clear(&mut self) {
+        self.edits.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FormatOnType_Result {
+        static instance: FormatOnType_Result = FormatOnType_Result {
+            edits: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FormatOnType_Result {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FormatOnType_Result").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FormatOnType_Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FormatOnType_Result {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:gpyrpc.LintPath_Args)
 pub struct LintPath_Args {
@@ -3610,6 +4561,8 @@ pub struct OverrideFile_Args {
     pub specs: ::std::vec::Vec<::std::string::String>,
     // @@protoc_insertion_point(field:gpyrpc.OverrideFile_Args.import_paths)
     pub import_paths: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:gpyrpc.OverrideFile_Args.create_missing)
+    pub create_missing: bool,
     // special fields
     // @@protoc_insertion_point(special_field:gpyrpc.OverrideFile_Args.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -3627,7 +4580,7 @@ impl OverrideFile_Args {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "file",
@@ -3644,6 +4597,11 @@ impl OverrideFile_Args {
             |m: &OverrideFile_Args| { &m.import_paths },
             |m: &mut OverrideFile_Args| { &mut m.import_paths },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "create_missing",
+            |m: &OverrideFile_Args| { &m.create_missing },
+            |m: &mut OverrideFile_Args| { &mut m.create_missing },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OverrideFile_Args>(
             "OverrideFile_Args",
             fields,
@@ -3671,6 +4629,9 @@ impl ::protobuf::Message for OverrideFile_Args {
                 26 => {
                     self.import_paths.push(is.read_string()?);
                 },
+                32 => {
+                    self.create_missing = is.read_bool()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -3692,6 +4653,9 @@ impl ::protobuf::Message for OverrideFile_Args {
         for value in &self.import_paths {
             my_size += ::protobuf::rt::string_size(3, &value);
         };
+        if self.create_missing != false {
+            my_size += 1 + 1;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -3707,6 +4671,9 @@ impl ::protobuf::Message for OverrideFile_Args {
         for v in &self.import_paths {
             os.write_string(3, &v)?;
         };
+        if self.create_missing != false {
+            os.write_bool(4, self.create_missing)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3727,6 +4694,7 @@ impl ::protobuf::Message for OverrideFile_Args {
         self.file.clear();
         self.specs.clear();
         self.import_paths.clear();
+        self.create_missing = false;
         self.special_fields.clear();
     }
 
@@ -3735,6 +4703,7 @@ impl ::protobuf::Message for OverrideFile_Args {
             file: ::std::string::String::new(),
             specs: ::std::vec::Vec::new(),
             import_paths: ::std::vec::Vec::new(),
+            create_missing: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -3764,6 +4733,8 @@ pub struct OverrideFile_Result {
     // message fields
     // @@protoc_insertion_point(field:gpyrpc.OverrideFile_Result.result)
     pub result: bool,
+    // @@protoc_insertion_point(field:gpyrpc.OverrideFile_Result.spec_results)
+    pub spec_results: ::std::vec::Vec<OverrideSpec_Result>,
     // special fields
     // @@protoc_insertion_point(special_field:gpyrpc.OverrideFile_Result.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -3781,13 +4752,18 @@ impl OverrideFile_Result {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "result",
             |m: &OverrideFile_Result| { &m.result },
             |m: &mut OverrideFile_Result| { &mut m.result },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "spec_results",
+            |m: &OverrideFile_Result| { &m.spec_results },
+            |m: &mut OverrideFile_Result| { &mut m.spec_results },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OverrideFile_Result>(
             "OverrideFile_Result",
             fields,
@@ -3809,6 +4785,9 @@ impl ::protobuf::Message for OverrideFile_Result {
                 8 => {
                     self.result = is.read_bool()?;
                 },
+                18 => {
+                    self.spec_results.push(is.read_message()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -3824,6 +4803,10 @@ impl ::protobuf::Message for OverrideFile_Result {
         if self.result != false {
             my_size += 1 + 1;
         }
+        for value in &self.spec_results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -3833,6 +4816,9 @@ impl ::protobuf::Message for OverrideFile_Result {
         if self.result != false {
             os.write_bool(1, self.result)?;
         }
+        for v in &self.spec_results {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3851,12 +4837,14 @@ impl ::protobuf::Message for OverrideFile_Result {
 
     fn clear(&mut self) {
         self.result = false;
+        self.spec_results.clear();
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static OverrideFile_Result {
         static instance: OverrideFile_Result = OverrideFile_Result {
             result: false,
+            spec_results: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -3880,6 +4868,200 @@ impl ::protobuf::reflect::ProtobufValue for OverrideFile_Result {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:gpyrpc.OverrideSpec_Result)
+pub struct OverrideSpec_Result {
+    // message fields
+    // @@protoc_insertion_point(field:gpyrpc.OverrideSpec_Result.spec)
+    pub spec: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.OverrideSpec_Result.status)
+    pub status: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.OverrideSpec_Result.message)
+    pub message: ::std::string::String,
+    // @@protoc_insertion_point(field:gpyrpc.OverrideSpec_Result.start_line)
+    pub start_line: i64,
+    // @@protoc_insertion_point(field:gpyrpc.OverrideSpec_Result.end_line)
+    pub end_line: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:gpyrpc.OverrideSpec_Result.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a OverrideSpec_Result {
+    fn default() -> &'a OverrideSpec_Result {
+        <OverrideSpec_Result as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OverrideSpec_Result {
+    pub fn new() -> OverrideSpec_Result {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "spec",
+            |m: &OverrideSpec_Result| { &m.spec },
+            |m: &mut OverrideSpec_Result| { &mut m.spec },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &OverrideSpec_Result| { &m.status },
+            |m: &mut OverrideSpec_Result| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &OverrideSpec_Result| { &m.message },
+            |m: &mut OverrideSpec_Result| { &mut m.message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "start_line",
+            |m: &OverrideSpec_Result| { &m.start_line },
+            |m: &mut OverrideSpec_Result| { &mut m.start_line },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end_line",
+            |m: &OverrideSpec_Result| { &m.end_line },
+            |m: &mut OverrideSpec_Result| { &mut m.end_line },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OverrideSpec_Result>(
+            "OverrideSpec_Result",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for OverrideSpec_Result {
+    const NAME: &'static str = "OverrideSpec_Result";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.spec = is.read_string()?;
+                },
+                18 => {
+                    self.status = is.read_string()?;
+                },
+                26 => {
+                    self.message = is.read_string()?;
+                },
+                32 => {
+                    self.start_line = is.read_int64()?;
+                },
+                40 => {
+                    self.end_line = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.spec.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.spec);
+        }
+        if !self.status.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.status);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
+        }
+        if self.start_line != 0 {
+            my_size += ::protobuf::rt::int64_size(4, self.start_line);
+        }
+        if self.end_line != 0 {
+            my_size += ::protobuf::rt::int64_size(5, self.end_line);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.spec.is_empty() {
+            os.write_string(1, &self.spec)?;
+        }
+        if !self.status.is_empty() {
+            os.write_string(2, &self.status)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
+        }
+        if self.start_line != 0 {
+            os.write_int64(4, self.start_line)?;
+        }
+        if self.end_line != 0 {
+            os.write_int64(5, self.end_line)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> OverrideSpec_Result {
+        OverrideSpec_Result::new()
+    }
+
+    fn clear(&mut self) {
+        self.spec.clear();
+        self.status.clear();
+        self.message.clear();
+        self.start_line = 0;
+        self.end_line = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static OverrideSpec_Result {
+        static instance: OverrideSpec_Result = OverrideSpec_Result {
+            spec: ::std::string::String::new(),
+            status: ::std::string::String::new(),
+            message: ::std::string::String::new(),
+            start_line: 0,
+            end_line: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for OverrideSpec_Result {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("OverrideSpec_Result").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for OverrideSpec_Result {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OverrideSpec_Result {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:gpyrpc.EvalCode_Args)
 pub struct EvalCode_Args {
@@ -8141,20 +9323,39 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x06source\x18\x01\x20\x01(\tR\x06source\"1\n\x11FormatCode_Result\x12\
     \x1c\n\tformatted\x18\x01\x20\x01(\x0cR\tformatted\"%\n\x0fFormatPath_Ar\
     gs\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\"7\n\x11FormatPath_Resu\
-    lt\x12\"\n\x0cchangedPaths\x18\x01\x20\x03(\tR\x0cchangedPaths\"#\n\rLin\
-    tPath_Args\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\"+\n\x0fLintPat\
-    h_Result\x12\x18\n\x07results\x18\x01\x20\x03(\tR\x07results\"`\n\x11Ove\
-    rrideFile_Args\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\x12\x14\n\
-    \x05specs\x18\x02\x20\x03(\tR\x05specs\x12!\n\x0cimport_paths\x18\x03\
-    \x20\x03(\tR\x0bimportPaths\"-\n\x13OverrideFile_Result\x12\x16\n\x06res\
-    ult\x18\x01\x20\x01(\x08R\x06result\"#\n\rEvalCode_Args\x12\x12\n\x04cod\
-    e\x18\x01\x20\x01(\tR\x04code\"2\n\x0fEvalCode_Result\x12\x1f\n\x0bjson_\
-    result\x18\x02\x20\x01(\tR\njsonResult\"&\n\x10ResolveCode_Args\x12\x12\
-    \n\x04code\x18\x01\x20\x01(\tR\x04code\".\n\x12ResolveCode_Result\x12\
-    \x18\n\x07success\x18\x01\x20\x01(\x08R\x07success\"]\n\x12GetSchemaType\
-    _Args\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\x12\x12\n\x04code\
-    \x18\x02\x20\x01(\tR\x04code\x12\x1f\n\x0bschema_name\x18\x03\x20\x01(\t\
-    R\nschemaName\"Q\n\x14GetSchemaType_Result\x129\n\x10schema_type_list\
+    lt\x12\"\n\x0cchangedPaths\x18\x01\x20\x03(\tR\x0cchangedPaths\":\n\x0cT\
+    extPosition\x12\x12\n\x04line\x18\x01\x20\x01(\x03R\x04line\x12\x16\n\
+    \x06column\x18\x02\x20\x01(\x03R\x06column\"_\n\tTextRange\x12*\n\x05sta\
+    rt\x18\x01\x20\x01(\x0b2\x14.gpyrpc.TextPositionR\x05start\x12&\n\x03end\
+    \x18\x02\x20\x01(\x0b2\x14.gpyrpc.TextPositionR\x03end\"N\n\x08TextEdit\
+    \x12'\n\x05range\x18\x01\x20\x01(\x0b2\x11.gpyrpc.TextRangeR\x05range\
+    \x12\x19\n\x08new_text\x18\x02\x20\x01(\tR\x07newText\"S\n\x10FormatRang\
+    e_Args\x12\x16\n\x06source\x18\x01\x20\x01(\tR\x06source\x12'\n\x05range\
+    \x18\x02\x20\x01(\x0b2\x11.gpyrpc.TextRangeR\x05range\"<\n\x12FormatRang\
+    e_Result\x12&\n\x05edits\x18\x01\x20\x03(\x0b2\x10.gpyrpc.TextEditR\x05e\
+    dits\"]\n\x11FormatOnType_Args\x12\x16\n\x06source\x18\x01\x20\x01(\tR\
+    \x06source\x120\n\x08position\x18\x02\x20\x01(\x0b2\x14.gpyrpc.TextPosit\
+    ionR\x08position\"=\n\x13FormatOnType_Result\x12&\n\x05edits\x18\x01\x20\
+    \x03(\x0b2\x10.gpyrpc.TextEditR\x05edits\"#\n\rLintPath_Args\x12\x12\n\
+    \x04path\x18\x01\x20\x01(\tR\x04path\"+\n\x0fLintPath_Result\x12\x18\n\
+    \x07results\x18\x01\x20\x03(\tR\x07results\"\x87\x01\n\x11OverrideFile_A\
+    rgs\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\x12\x14\n\x05specs\x18\
+    \x02\x20\x03(\tR\x05specs\x12!\n\x0cimport_paths\x18\x03\x20\x03(\tR\x0b\
+    importPaths\x12%\n\x0ecreate_missing\x18\x04\x20\x01(\x08R\rcreateMissin\
+    g\"m\n\x13OverrideFile_Result\x12\x16\n\x06result\x18\x01\x20\x01(\x08R\
+    \x06result\x12>\n\x0cspec_results\x18\x02\x20\x03(\x0b2\x1b.gpyrpc.Overr\
+    ideSpec_ResultR\x0bspecResults\"\x95\x01\n\x13OverrideSpec_Result\x12\
+    \x12\n\x04spec\x18\x01\x20\x01(\tR\x04spec\x12\x16\n\x06status\x18\x02\
+    \x20\x01(\tR\x06status\x12\x18\n\x07message\x18\x03\x20\x01(\tR\x07messa\
+    ge\x12\x1d\n\nstart_line\x18\x04\x20\x01(\x03R\tstartLine\x12\x19\n\x08e\
+    nd_line\x18\x05\x20\x01(\x03R\x07endLine\"#\n\rEvalCode_Args\x12\x12\n\
+    \x04code\x18\x01\x20\x01(\tR\x04code\"2\n\x0fEvalCode_Result\x12\x1f\n\
+    \x0bjson_result\x18\x02\x20\x01(\tR\njsonResult\"&\n\x10ResolveCode_Args\
+    \x12\x12\n\x04code\x18\x01\x20\x01(\tR\x04code\".\n\x12ResolveCode_Resul\
+    t\x12\x18\n\x07success\x18\x01\x20\x01(\x08R\x07success\"]\n\x12GetSchem\
+    aType_Args\x12\x12\n\x04file\x18\x01\x20\x01(\tR\x04file\x12\x12\n\x04co\
+    de\x18\x02\x20\x01(\tR\x04code\x12\x1f\n\x0bschema_name\x18\x03\x20\x01(\
+    \tR\nschemaName\"Q\n\x14GetSchemaType_Result\x129\n\x10schema_type_list\
     \x18\x01\x20\x03(\x0b2\x0f.gpyrpc.KclTypeR\x0eschemaTypeList\"\x92\x01\n\
     \x11ValidateCode_Args\x12\x12\n\x04data\x18\x01\x20\x01(\tR\x04data\x12\
     \x12\n\x04code\x18\x02\x20\x01(\tR\x04code\x12\x16\n\x06schema\x18\x03\
@@ -8219,7 +9420,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x02\x20\x01(\tR\x05value:\x028\x012\x82\x01\n\x0eBuiltinService\x12\
     .\n\x04Ping\x12\x11.gpyrpc.Ping_Args\x1a\x13.gpyrpc.Ping_Result\x12@\n\n\
     ListMethod\x12\x17.gpyrpc.ListMethod_Args\x1a\x19.gpyrpc.ListMethod_Resu\
-    lt2\xb4\x0b\n\x0cKclvmService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\
+    lt2\xc1\x0c\n\x0cKclvmService\x12.\n\x04Ping\x12\x11.gpyrpc.Ping_Args\
     \x1a\x13.gpyrpc.Ping_Result\x12X\n\x12ParseFile_LarkTree\x12\x1f.gpyrpc.\
     ParseFile_LarkTree_Args\x1a!.gpyrpc.ParseFile_LarkTree_Result\x12I\n\rPa\
     rseFile_AST\x12\x1a.gpyrpc.ParseFile_AST_Args\x1a\x1c.gpyrpc.ParseFile_A\
@@ -8229,23 +9430,26 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     Plugin\x12\x18.gpyrpc.ResetPlugin_Args\x1a\x1a.gpyrpc.ResetPlugin_Result\
     \x12@\n\nFormatCode\x12\x17.gpyrpc.FormatCode_Args\x1a\x19.gpyrpc.Format\
     Code_Result\x12@\n\nFormatPath\x12\x17.gpyrpc.FormatPath_Args\x1a\x19.gp\
-    yrpc.FormatPath_Result\x12:\n\x08LintPath\x12\x15.gpyrpc.LintPath_Args\
-    \x1a\x17.gpyrpc.LintPath_Result\x12F\n\x0cOverrideFile\x12\x19.gpyrpc.Ov\
-    errideFile_Args\x1a\x1b.gpyrpc.OverrideFile_Result\x12:\n\x08EvalCode\
-    \x12\x15.gpyrpc.EvalCode_Args\x1a\x17.gpyrpc.EvalCode_Result\x12C\n\x0bR\
-    esolveCode\x12\x18.gpyrpc.ResolveCode_Args\x1a\x1a.gpyrpc.ResolveCode_Re\
-    sult\x12I\n\rGetSchemaType\x12\x1a.gpyrpc.GetSchemaType_Args\x1a\x1c.gpy\
-    rpc.GetSchemaType_Result\x12F\n\x0cValidateCode\x12\x19.gpyrpc.ValidateC\
-    ode_Args\x1a\x1b.gpyrpc.ValidateCode_Result\x12@\n\nSpliceCode\x12\x17.g\
-    pyrpc.SpliceCode_Args\x1a\x19.gpyrpc.SpliceCode_Result\x12:\n\x08Complet\
-    e\x12\x15.gpyrpc.Complete_Args\x1a\x17.gpyrpc.Complete_Result\x127\n\x07\
-    GoToDef\x12\x14.gpyrpc.GoToDef_Args\x1a\x16.gpyrpc.GoToDef_Result\x12L\n\
-    \x0eDocumentSymbol\x12\x1b.gpyrpc.DocumentSymbol_Args\x1a\x1d.gpyrpc.Doc\
-    umentSymbol_Result\x121\n\x05Hover\x12\x12.gpyrpc.Hover_Args\x1a\x14.gpy\
-    rpc.Hover_Result\x12F\n\x0cListDepFiles\x12\x19.gpyrpc.ListDepFiles_Args\
-    \x1a\x1b.gpyrpc.ListDepFiles_Result\x12U\n\x11LoadSettingsFiles\x12\x1e.\
-    gpyrpc.LoadSettingsFiles_Args\x1a\x20.gpyrpc.LoadSettingsFiles_ResultB0Z\
-    .kusionstack.io/kclvm-go/pkg/spec/gpyrpc;gpyrpcb\x06proto3\
+    yrpc.FormatPath_Result\x12C\n\x0bFormatRange\x12\x18.gpyrpc.FormatRange_\
+    Args\x1a\x1a.gpyrpc.FormatRange_Result\x12F\n\x0cFormatOnType\x12\x19.gp\
+    yrpc.FormatOnType_Args\x1a\x1b.gpyrpc.FormatOnType_Result\x12:\n\x08Lint\
+    Path\x12\x15.gpyrpc.LintPath_Args\x1a\x17.gpyrpc.LintPath_Result\x12F\n\
+    \x0cOverrideFile\x12\x19.gpyrpc.OverrideFile_Args\x1a\x1b.gpyrpc.Overrid\
+    eFile_Result\x12:\n\x08EvalCode\x12\x15.gpyrpc.EvalCode_Args\x1a\x17.gpy\
+    rpc.EvalCode_Result\x12C\n\x0bResolveCode\x12\x18.gpyrpc.ResolveCode_Arg\
+    s\x1a\x1a.gpyrpc.ResolveCode_Result\x12I\n\rGetSchemaType\x12\x1a.gpyrpc\
+    .GetSchemaType_Args\x1a\x1c.gpyrpc.GetSchemaType_Result\x12F\n\x0cValida\
+    teCode\x12\x19.gpyrpc.ValidateCode_Args\x1a\x1b.gpyrpc.ValidateCode_Resu\
+    lt\x12@\n\nSpliceCode\x12\x17.gpyrpc.SpliceCode_Args\x1a\x19.gpyrpc.Spli\
+    ceCode_Result\x12:\n\x08Complete\x12\x15.gpyrpc.Complete_Args\x1a\x17.gp\
+    yrpc.Complete_Result\x127\n\x07GoToDef\x12\x14.gpyrpc.GoToDef_Args\x1a\
+    \x16.gpyrpc.GoToDef_Result\x12L\n\x0eDocumentSymbol\x12\x1b.gpyrpc.Docum\
+    entSymbol_Args\x1a\x1d.gpyrpc.DocumentSymbol_Result\x121\n\x05Hover\x12\
+    \x12.gpyrpc.Hover_Args\x1a\x14.gpyrpc.Hover_Result\x12F\n\x0cListDepFile\
+    s\x12\x19.gpyrpc.ListDepFiles_Args\x1a\x1b.gpyrpc.ListDepFiles_Result\
+    \x12U\n\x11LoadSettingsFiles\x12\x1e.gpyrpc.LoadSettingsFiles_Args\x1a\
+    \x20.gpyrpc.LoadSettingsFiles_ResultB0Z.kusionstack.io/kclvm-go/pkg/spec\
+    /gpyrpc;gpyrpcb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -8265,7 +9469,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             let mut deps = ::std::vec::Vec::with_capacity(2);
             deps.push(::protobuf::well_known_types::any::file_descriptor().clone());
             deps.push(::protobuf::descriptor::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(55);
+            let mut messages = ::std::vec::Vec::with_capacity(63);
             messages.push(CmdArgSpec::generated_message_descriptor_data());
             messages.push(CmdOverrideSpec::generated_message_descriptor_data());
             messages.push(RestResponse::generated_message_descriptor_data());
@@ -8289,10 +9493,18 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(FormatCode_Result::generated_message_descriptor_data());
             messages.push(FormatPath_Args::generated_message_descriptor_data());
             messages.push(FormatPath_Result::generated_message_descriptor_data());
+            messages.push(TextPosition::generated_message_descriptor_data());
+            messages.push(TextRange::generated_message_descriptor_data());
+            messages.push(TextEdit::generated_message_descriptor_data());
+            messages.push(FormatRange_Args::generated_message_descriptor_data());
+            messages.push(FormatRange_Result::generated_message_descriptor_data());
+            messages.push(FormatOnType_Args::generated_message_descriptor_data());
+            messages.push(FormatOnType_Result::generated_message_descriptor_data());
             messages.push(LintPath_Args::generated_message_descriptor_data());
             messages.push(LintPath_Result::generated_message_descriptor_data());
             messages.push(OverrideFile_Args::generated_message_descriptor_data());
             messages.push(OverrideFile_Result::generated_message_descriptor_data());
+            messages.push(OverrideSpec_Result::generated_message_descriptor_data());
             messages.push(EvalCode_Args::generated_message_descriptor_data());
             messages.push(EvalCode_Result::generated_message_descriptor_data());
             messages.push(ResolveCode_Args::generated_message_descriptor_data());
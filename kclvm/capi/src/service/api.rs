@@ -95,6 +95,9 @@ pub(crate) fn _kclvm_get_service_fn_ptr_by_name(name: &str) -> u64 {
         "KclvmService.Ping" => ping as *const () as u64,
         "KclvmService.ExecProgram" => exec_program as *const () as u64,
         "KclvmService.OverrideFile" => override_file as *const () as u64,
+        "KclvmService.LintPath" => lint_path as *const () as u64,
+        "KclvmService.FormatRange" => format_range as *const () as u64,
+        "KclvmService.FormatOnType" => format_on_type as *const () as u64,
         _ => panic!("unknown method name : {}", name),
     }
 }
@@ -169,3 +172,90 @@ pub fn override_file(serv: &mut KclvmService, args: &[u8]) -> *const c_char {
     };
     CString::new(result_byte).unwrap().into_raw()
 }
+
+/// lint_path collects the compile errors and lint warnings of the KCL file
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the path of the kcl file serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub fn lint_path(serv: &mut KclvmService, args: &[u8]) -> *const c_char {
+    let serv_ref = mut_ptr_as_ref(serv);
+    let args = LintPath_Args::parse_from_bytes(args).unwrap();
+    let res = serv_ref.lint_path(&args);
+    let result_byte = match res {
+        Ok(res) => match res.write_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{}", err.to_string()),
+        },
+        Err(err) => panic!("{}", err.clone()),
+    };
+    CString::new(result_byte).unwrap().into_raw()
+}
+
+/// format_range formats the top-level statements covering the range of the source
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the source and the range to be formatted serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub fn format_range(serv: &mut KclvmService, args: &[u8]) -> *const c_char {
+    let serv_ref = mut_ptr_as_ref(serv);
+    let args = FormatRange_Args::parse_from_bytes(args).unwrap();
+    let res = serv_ref.format_range(&args);
+    let result_byte = match res {
+        Ok(res) => match res.write_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{}", err.to_string()),
+        },
+        Err(err) => panic!("{}", err.clone()),
+    };
+    CString::new(result_byte).unwrap().into_raw()
+}
+
+/// format_on_type formats the top-level statement enclosing the position of the source
+///
+/// # Parameters
+///
+/// `serv`: [*mut kclvm_service]
+///     The pointer of &\[[KclvmService]]
+///
+///
+/// `args`: [&[u8]]
+///     the source and the cursor position serialized as protobuf byte sequence
+///
+/// # Returns
+///
+/// result: [*const c_char]
+///     Result of the call serialized as protobuf byte sequence
+pub fn format_on_type(serv: &mut KclvmService, args: &[u8]) -> *const c_char {
+    let serv_ref = mut_ptr_as_ref(serv);
+    let args = FormatOnType_Args::parse_from_bytes(args).unwrap();
+    let res = serv_ref.format_on_type(&args);
+    let result_byte = match res {
+        Ok(res) => match res.write_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(err) => panic!("{}", err.to_string()),
+        },
+        Err(err) => panic!("{}", err.clone()),
+    };
+    CString::new(result_byte).unwrap().into_raw()
+}
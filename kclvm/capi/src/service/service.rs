@@ -4,7 +4,9 @@ use crate::model::gpyrpc::*;
 
 use kclvm::ValueRef;
 use kclvm_parser::load_program;
-use kclvm_tools::query::override_file;
+use kclvm_tools::format::{self, format_on_type, format_range};
+use kclvm_tools::lint::lint_files;
+use kclvm_tools::query::{override_file, OverrideStatus};
 use protobuf_json_mapping::print_to_string_with_options;
 use protobuf_json_mapping::PrintOptions;
 
@@ -108,7 +110,10 @@ impl KclvmService {
                 return Err(json_result);
             }
         }
-        let (json_result, yaml_result) = kcl_val.plan();
+        let (json_result, yaml_result) = kcl_val.plan_with_options(
+            &native_args.get_json_encode_options(),
+            &native_args.get_yaml_encode_options(),
+        );
         result.json_result = json_result;
         if !args.disable_yaml_result {
             result.yaml_result = yaml_result;
@@ -131,6 +136,7 @@ impl KclvmService {
     /// };
     /// let override_result = serv.override_file(args).unwrap();
     /// assert!(override_result.result);
+    /// assert_eq!(override_result.spec_results[0].status, "applied");
     /// ```
     ///
     ///  - test.k (after override)
@@ -144,11 +150,153 @@ impl KclvmService {
     /// }
     /// ```
     pub fn override_file(&self, args: &OverrideFile_Args) -> Result<OverrideFile_Result, String> {
-        override_file(&args.file, &args.specs, &args.import_paths)
-            .map_err(|err| err.to_string())
-            .map(|result| OverrideFile_Result {
-                result,
-                ..Default::default()
-            })
+        let result = override_file(
+            &args.file,
+            &args.specs,
+            &args.import_paths,
+            args.create_missing,
+        )
+        .map_err(|err| err.to_string())?;
+        // Invalid specs and specs matched nothing don't abort the override, they are
+        // reported with the status of each spec.
+        Ok(OverrideFile_Result {
+            result: result.changed(),
+            spec_results: result
+                .specs
+                .iter()
+                .map(|spec_result| {
+                    let (status, message) = match &spec_result.status {
+                        OverrideStatus::Applied => ("applied", ""),
+                        OverrideStatus::NotFound => ("not_found", ""),
+                        OverrideStatus::Invalid(reason) => ("invalid", reason.as_str()),
+                        OverrideStatus::Unsupported(reason) => ("unsupported", reason.as_str()),
+                    };
+                    let (start_line, end_line) = spec_result.changed_lines.unwrap_or((0, 0));
+                    OverrideSpec_Result {
+                        spec: spec_result.spec.clone(),
+                        status: status.to_string(),
+                        message: message.to_string(),
+                        start_line: start_line as i64,
+                        end_line: end_line as i64,
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Lint KCL file with args, all the compile errors and lint warnings are
+    /// collected without raising the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &LintPath_Args {
+    ///     path: "./src/testdata/lint.k".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let lint_result = serv.lint_path(args).unwrap();
+    /// assert_eq!(lint_result.results, vec!["Module 'math' imported but unused".to_string()]);
+    /// ```
+    pub fn lint_path(&self, args: &LintPath_Args) -> Result<LintPath_Result, String> {
+        let (errors, warnings) = lint_files(&[args.path.as_str()], None, false);
+        Ok(LintPath_Result {
+            results: errors
+                .iter()
+                .chain(warnings.iter())
+                .filter_map(|diag| diag.messages.first())
+                .map(|msg| msg.message.clone())
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Format the top-level statements covering the range of the source and
+    /// return the text edits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use protobuf::MessageField;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &FormatRange_Args {
+    ///     source: "a   =   1\n".to_string(),
+    ///     range: MessageField::some(TextRange {
+    ///         start: MessageField::some(TextPosition { line: 1, column: 0, ..Default::default() }),
+    ///         end: MessageField::some(TextPosition { line: 1, column: 9, ..Default::default() }),
+    ///         ..Default::default()
+    ///     }),
+    ///     ..Default::default()
+    /// };
+    /// let format_result = serv.format_range(args).unwrap();
+    /// assert_eq!(format_result.edits[0].new_text, "a = 1\n");
+    /// ```
+    pub fn format_range(&self, args: &FormatRange_Args) -> Result<FormatRange_Result, String> {
+        let range = format::TextRange {
+            start: text_position_from_message(&args.range.start),
+            end: text_position_from_message(&args.range.end),
+        };
+        let edits = format_range(&args.source, range).map_err(|err| err.to_string())?;
+        Ok(FormatRange_Result {
+            edits: edits.iter().map(text_edit_to_message).collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Format the top-level statement enclosing the position e.g., the cursor
+    /// position after typing a newline, and return the text edits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kclvm_capi::model::gpyrpc::*;
+    /// use protobuf::MessageField;
+    /// let serv = &KclvmService { plugin_agent: 0 };
+    /// let args = &FormatOnType_Args {
+    ///     source: "a   =   1  # comment\n".to_string(),
+    ///     position: MessageField::some(TextPosition { line: 1, column: 9, ..Default::default() }),
+    ///     ..Default::default()
+    /// };
+    /// let format_result = serv.format_on_type(args).unwrap();
+    /// assert_eq!(format_result.edits[0].new_text, "a = 1  # comment\n");
+    /// ```
+    pub fn format_on_type(&self, args: &FormatOnType_Args) -> Result<FormatOnType_Result, String> {
+        let pos = text_position_from_message(&args.position);
+        let edits = format_on_type(&args.source, pos).map_err(|err| err.to_string())?;
+        Ok(FormatOnType_Result {
+            edits: edits.iter().map(text_edit_to_message).collect(),
+            ..Default::default()
+        })
+    }
+}
+
+fn text_position_from_message(pos: &TextPosition) -> format::TextPosition {
+    format::TextPosition {
+        line: pos.line.max(0) as u64,
+        column: pos.column.max(0) as u64,
+    }
+}
+
+fn text_position_to_message(pos: &format::TextPosition) -> TextPosition {
+    TextPosition {
+        line: pos.line as i64,
+        column: pos.column as i64,
+        ..Default::default()
+    }
+}
+
+fn text_edit_to_message(edit: &format::TextEdit) -> TextEdit {
+    TextEdit {
+        range: protobuf::MessageField::some(TextRange {
+            start: protobuf::MessageField::some(text_position_to_message(&edit.range.start)),
+            end: protobuf::MessageField::some(text_position_to_message(&edit.range.end)),
+            ..Default::default()
+        }),
+        new_text: edit.new_text.clone(),
+        ..Default::default()
     }
 }
@@ -15,6 +15,8 @@ pub struct KCLModFile {
     pub root_pkg: Option<String>,
     pub build: Option<KCLModFileBuildSection>,
     pub expected: Option<KCLModFileExpectedSection>,
+    pub format: Option<KCLModFileFormatSection>,
+    pub lint: Option<KCLModFileLintSection>,
 }
 
 #[allow(dead_code)]
@@ -35,6 +37,28 @@ pub struct KCLModFileExpectedSection {
     pub global_version: Option<String>,
 }
 
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+pub struct KCLModFileFormatSection {
+    pub indent_width: Option<usize>,
+    pub use_tabs: Option<bool>,
+    /// Only the list and config expressions that exceed the max line width are
+    /// wrapped, the other expressions e.g., call arguments are kept in one line.
+    pub max_line_width: Option<usize>,
+    pub quote_style: Option<String>,
+    pub trailing_comma: Option<String>,
+    pub blank_lines_between_schemas: Option<usize>,
+}
+
+#[allow(dead_code)]
+#[derive(Default, Deserialize)]
+pub struct KCLModFileLintSection {
+    pub allow: Option<Vec<String>>,
+    pub warn: Option<Vec<String>>,
+    pub deny: Option<Vec<String>>,
+    pub plugins: Option<Vec<String>>,
+}
+
 pub fn get_pkg_root_from_paths(file_paths: &[String]) -> Result<String, String> {
     if file_paths.is_empty() {
         return Err("No input KCL files or paths".to_string());
@@ -172,5 +196,15 @@ mod modfile_test {
                 .unwrap(),
             "v0.2.0"
         );
+        let format = kcl_mod.format.as_ref().unwrap();
+        assert_eq!(format.indent_width, Some(2));
+        assert_eq!(format.max_line_width, Some(80));
+        assert_eq!(format.quote_style.as_ref().unwrap(), "single");
+        assert_eq!(format.trailing_comma.as_ref().unwrap(), "always");
+        assert_eq!(format.blank_lines_between_schemas, Some(2));
+        let lint = kcl_mod.lint.as_ref().unwrap();
+        assert_eq!(lint.allow, Some(vec!["W0413".to_string()]));
+        assert_eq!(lint.warn, None);
+        assert_eq!(lint.deny, Some(vec!["unused_import".to_string()]));
     }
 }
@@ -4,3 +4,12 @@ cached_pkg_prefix="pkg.path"
 [expected]
 kclvm_version="v0.3.0"
 kcl_plugin_version="v0.2.0"
+[format]
+indent_width=2
+max_line_width=80
+quote_style="single"
+trailing_comma="always"
+blank_lines_between_schemas=2
+[lint]
+allow=["W0413"]
+deny=["unused_import"]
@@ -1,6 +1,7 @@
 use std::fmt;
 use std::hash::Hash;
 
+use kclvm::PanicInfo;
 use kclvm_span::Loc;
 use rustc_span::Pos;
 use termcolor::{Color, ColorSpec};
@@ -13,6 +14,9 @@ pub struct Diagnostic {
     pub level: Level,
     pub messages: Vec<Message>,
     pub code: Option<DiagnosticId>,
+    /// Machine-applicable fixes of the diagnostic, all suggestions of a diagnostic
+    /// should be applied together.
+    pub suggestions: Vec<Suggestion>,
 }
 
 /// Position describes an arbitrary source position including the filename,
@@ -114,6 +118,7 @@ impl Diagnostic {
                 note: None,
             }],
             code,
+            suggestions: vec![],
         }
     }
 
@@ -121,6 +126,35 @@ impl Diagnostic {
     pub fn is_error(&self) -> bool {
         matches!(self.level, Level::Error)
     }
+
+    /// New a diagnostic from the panic message of the compiler, which is the JSON
+    /// string of [PanicInfo] or the raw message without the position.
+    pub fn from_panic_message(message: &str) -> Self {
+        match serde_json::from_str::<PanicInfo>(message) {
+            Ok(panic_info) => panic_info.into(),
+            Err(_) => Diagnostic::new(Level::Error, message, Position::dummy_pos()),
+        }
+    }
+}
+
+impl From<PanicInfo> for Diagnostic {
+    fn from(panic_info: PanicInfo) -> Self {
+        let level = if panic_info.is_warning {
+            Level::Warning
+        } else {
+            Level::Error
+        };
+        let pos = Position {
+            filename: panic_info.kcl_file,
+            line: panic_info.kcl_line.max(0) as u64,
+            column: if panic_info.kcl_col > 0 {
+                Some(panic_info.kcl_col as u64)
+            } else {
+                None
+            },
+        };
+        Diagnostic::new(level, &panic_info.message, pos)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -131,10 +165,71 @@ pub struct Message {
     pub note: Option<String>,
 }
 
+/// A suggested fix replaces the source in the range `[start, end)` of the file with
+/// the replacement, the lines are 1-based and the columns are 0-based.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Suggestion {
+    pub filename: String,
+    pub start_line: u64,
+    pub start_column: u64,
+    pub end_line: u64,
+    pub end_column: u64,
+    pub replacement: String,
+}
+
+impl Suggestion {
+    /// New a suggestion which deletes the lines `[start_line, end_line]` of the file.
+    pub fn delete_lines(filename: &str, start_line: u64, end_line: u64) -> Self {
+        Suggestion {
+            filename: filename.to_string(),
+            start_line,
+            start_column: 0,
+            end_line: end_line + 1,
+            end_column: 0,
+            replacement: "".to_string(),
+        }
+    }
+
+    /// New a suggestion which inserts the text at the start of the line of the file.
+    pub fn insert_before_line(filename: &str, line: u64, text: &str) -> Self {
+        Suggestion {
+            filename: filename.to_string(),
+            start_line: line,
+            start_column: 0,
+            end_line: line,
+            end_column: 0,
+            replacement: text.to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DiagnosticId {
     Error(ErrorKind),
     Warning(WarningKind),
+    /// The rule code of a user-defined lint plugin e.g., `HOUSE001`.
+    Plugin(String),
+}
+
+impl DiagnosticId {
+    /// Returns the kind name of the diagnostic id e.g., `TypeError`.
+    pub fn name(&self) -> String {
+        match self {
+            DiagnosticId::Error(kind) => kind.name(),
+            DiagnosticId::Warning(kind) => kind.name(),
+            DiagnosticId::Plugin(_) => "PluginLint".to_string(),
+        }
+    }
+
+    /// Returns the registered code of the diagnostic id e.g., `E2G22` and `W0411`,
+    /// or the rule code of the lint plugin.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            DiagnosticId::Error(kind) => kind.code(),
+            DiagnosticId::Warning(kind) => kind.code(),
+            DiagnosticId::Plugin(code) => Some(code),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -1,13 +1,17 @@
 use crate::{
     diagnostic::{Diagnostic, Style},
+    json::JsonEmitter,
+    sarif::SarifEmitter,
     DiagnosticId, Level,
 };
 
+use indexmap::IndexSet;
 use kclvm_span::{FilePathMapping, SourceMap};
 use std::sync::Arc;
 use std::{
     io::{self, Write},
     path::Path,
+    str::FromStr,
 };
 use termcolor::{BufferWriter, Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
@@ -16,12 +20,67 @@ pub trait Emitter {
     fn format_diagnostic(&mut self, diag: &Diagnostic) -> Vec<String>;
     /// Emit a structured diagnostic.
     fn emit_diagnostic(&mut self, diag: &Diagnostic);
+    /// Emit a batch of structured diagnostics. Emitters that produce a single
+    /// document for all diagnostics e.g., SARIF need to override it.
+    fn emit_diagnostics(&mut self, diags: &IndexSet<Diagnostic>) {
+        for diag in diags {
+            self.emit_diagnostic(diag);
+        }
+    }
     /// Checks if we can use colors in the current output stream.
     fn supports_color(&self) -> bool {
         false
     }
 }
 
+/// Output format of diagnostics.
+/// - `Human` is the colored text format for terminals.
+/// - `Json` is the JSON lines format, one diagnostic per line.
+/// - `Sarif` is the SARIF 2.1.0 log format used by code scanning tools.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticFormat {
+    Human,
+    Json,
+    Sarif,
+}
+
+impl Default for DiagnosticFormat {
+    fn default() -> Self {
+        DiagnosticFormat::Human
+    }
+}
+
+impl FromStr for DiagnosticFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "human" | "text" => Ok(DiagnosticFormat::Human),
+            "json" => Ok(DiagnosticFormat::Json),
+            "sarif" => Ok(DiagnosticFormat::Sarif),
+            _ => Err(format!(
+                "invalid diagnostic format '{}', expected one of human, json and sarif",
+                s
+            )),
+        }
+    }
+}
+
+impl DiagnosticFormat {
+    /// New an emitter of the format. Machine-readable formats are written to stdout
+    /// and the human format is written to stderr.
+    pub fn emitter(&self, source_map: Option<Arc<SourceMap>>) -> Box<dyn Emitter> {
+        match self {
+            DiagnosticFormat::Human => match source_map {
+                Some(source_map) => Box::new(EmitterWriter::from_stderr(source_map)),
+                None => Box::new(EmitterWriter::default()),
+            },
+            DiagnosticFormat::Json => Box::new(JsonEmitter::default()),
+            DiagnosticFormat::Sarif => Box::new(SarifEmitter::default()),
+        }
+    }
+}
+
 /// Emitter writer.
 pub struct EmitterWriter {
     dst: Destination,
@@ -161,6 +220,7 @@ impl Emitter for EmitterWriter {
             let code_str = match code {
                 DiagnosticId::Error(kind) => kind.name(),
                 DiagnosticId::Warning(warn_msg) => warn_msg.to_string(),
+                DiagnosticId::Plugin(code) => code.clone(),
             };
             diag_str += &format!(" [{}]", code_str);
         }
@@ -30,6 +30,37 @@ register_errors! {
     E2D34: ErrorKind::IllegalInheritError, include_str!("./error_codes/E2D34.md"),
 }
 
+macro_rules! register_warnings {
+    ($($wcode:ident: $kind:expr, $message:expr,)*) => (
+        pub static WARNINGS: &[(&str, Warning)] = &[
+            $( (stringify!($wcode), Warning {
+                code: stringify!($wcode),
+                kind: $kind,
+                message: Some($message),
+            }), )*
+        ];
+        $(pub const $wcode: Warning = Warning {
+            code: stringify!($wcode),
+            kind: $kind,
+            message: Some($message),
+        };)*
+    )
+}
+
+// Warning messages for WXXXX warnings. Each message should start and end with a
+// new line.
+register_warnings! {
+    W0411: WarningKind::UnusedImportWarning, include_str!("./warning_codes/W0411.md"),
+    W0404: WarningKind::ReimportWarning, include_str!("./warning_codes/W0404.md"),
+    W0413: WarningKind::ImportPositionWarning, include_str!("./warning_codes/W0413.md"),
+    W0612: WarningKind::UnusedVariableWarning, include_str!("./warning_codes/W0612.md"),
+    W0613: WarningKind::UnusedArgumentWarning, include_str!("./warning_codes/W0613.md"),
+    W0622: WarningKind::ShadowingWarning, include_str!("./warning_codes/W0622.md"),
+    W0236: WarningKind::IncompatibleAttrTypeWarning, include_str!("./warning_codes/W0236.md"),
+    W0125: WarningKind::ConstantCheckWarning, include_str!("./warning_codes/W0125.md"),
+    W0121: WarningKind::NoneComparisonWarning, include_str!("./warning_codes/W0121.md"),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Error {
     pub code: &'static str,
@@ -85,13 +116,21 @@ impl ErrorKind {
     pub fn name(&self) -> String {
         return format!("{:?}", self);
     }
+
+    /// Returns the registered error code of the error kind e.g., `E2G22`.
+    pub fn code(&self) -> Option<&'static str> {
+        ERRORS
+            .iter()
+            .find(|(_, err)| &err.kind == self)
+            .map(|(code, _)| *code)
+    }
 }
 
 /// Warning information of KCL. Usually something that does not conform to the specification but does not cause an error.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Warning {
     pub code: &'static str,
-    pub kind: ErrorKind,
+    pub kind: WarningKind,
     pub message: Option<&'static str>,
 }
 
@@ -101,6 +140,12 @@ pub enum WarningKind {
     UnusedImportWarning,
     ReimportWarning,
     ImportPositionWarning,
+    UnusedVariableWarning,
+    UnusedArgumentWarning,
+    ShadowingWarning,
+    IncompatibleAttrTypeWarning,
+    ConstantCheckWarning,
+    NoneComparisonWarning,
 }
 
 /// Test warning `fmt`
@@ -156,4 +201,12 @@ impl WarningKind {
     pub fn name(&self) -> String {
         return format!("{:?}", self);
     }
+
+    /// Returns the registered warning code of the warning kind e.g., `W0411`.
+    pub fn code(&self) -> Option<&'static str> {
+        WARNINGS
+            .iter()
+            .find(|(_, warning)| &warning.kind == self)
+            .map(|(code, _)| *code)
+    }
 }
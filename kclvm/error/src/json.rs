@@ -0,0 +1,95 @@
+//! The JSON lines emitter, which writes each diagnostic as a single line
+//! JSON object, e.g.,
+//!
+//! ```json
+//! {"level":"error","code":"E2G22","kind":"TypeError","messages":[{"filename":"main.k","line":1,"column":1,"message":"expect int, got str(1)","note":null}],"suggestions":[]}
+//! ```
+//!
+//! All lines and columns in the JSON output are 1-based, which is consistent with
+//! the human readable format.
+use crate::{diagnostic::Message, Diagnostic, Emitter, Suggestion};
+
+use serde_json::{json, Value};
+use std::io::{self, Write};
+
+/// Emitter that writes diagnostics in the JSON lines format.
+pub struct JsonEmitter {
+    dst: Box<dyn Write + Send>,
+}
+
+impl Default for JsonEmitter {
+    fn default() -> Self {
+        Self {
+            dst: Box::new(io::stdout()),
+        }
+    }
+}
+
+impl JsonEmitter {
+    /// New a JSON emitter with the output destination.
+    pub fn new(dst: Box<dyn Write + Send>) -> Self {
+        Self { dst }
+    }
+}
+
+impl Emitter for JsonEmitter {
+    fn format_diagnostic(&mut self, diag: &Diagnostic) -> Vec<String> {
+        vec![diagnostic_to_json(diag).to_string()]
+    }
+
+    fn emit_diagnostic(&mut self, diag: &Diagnostic) {
+        for line in self.format_diagnostic(diag) {
+            if let Err(e) = writeln!(self.dst, "{}", line) {
+                panic!("failed to emit error: {}", e)
+            }
+        }
+        if let Err(e) = self.dst.flush() {
+            panic!("failed to emit error: {}", e)
+        }
+    }
+}
+
+/// Convert a diagnostic to the JSON value.
+pub(crate) fn diagnostic_to_json(diag: &Diagnostic) -> Value {
+    let messages = diag
+        .messages
+        .iter()
+        .map(message_to_json)
+        .collect::<Vec<Value>>();
+    json!({
+        "level": diag.level.to_str().to_lowercase(),
+        "code": diag.code.as_ref().and_then(|code| code.code()),
+        "kind": diag.code.as_ref().map(|code| code.name()),
+        "messages": messages,
+        "suggestions": diag
+            .suggestions
+            .iter()
+            .map(suggestion_to_json)
+            .collect::<Vec<Value>>(),
+    })
+}
+
+/// Convert a suggested fix to the JSON value. The (0-based) columns of the
+/// suggestion are converted to 1-based columns like the message columns.
+fn suggestion_to_json(suggestion: &Suggestion) -> Value {
+    json!({
+        "filename": suggestion.filename,
+        "start_line": suggestion.start_line,
+        "start_column": suggestion.start_column + 1,
+        "end_line": suggestion.end_line,
+        "end_column": suggestion.end_column + 1,
+        "replacement": suggestion.replacement,
+    })
+}
+
+/// Convert a diagnostic message to the JSON value. The column is 1-based
+/// and consistent with the human readable format.
+fn message_to_json(msg: &Message) -> Value {
+    json!({
+        "filename": msg.pos.filename,
+        "line": msg.pos.line,
+        "column": msg.pos.column.map(|column| column + 1),
+        "message": msg.message,
+        "note": msg.note,
+    })
+}
@@ -10,16 +10,20 @@ pub mod bug;
 mod diagnostic;
 mod emitter;
 mod error;
+mod json;
+mod sarif;
 #[cfg(test)]
 mod tests;
 
 use std::sync::Arc;
 
-pub use diagnostic::{Diagnostic, DiagnosticId, Level, Message, Position, Style};
-pub use emitter::{Emitter, EmitterWriter};
+pub use diagnostic::{Diagnostic, DiagnosticId, Level, Message, Position, Style, Suggestion};
+pub use emitter::{DiagnosticFormat, Emitter, EmitterWriter};
 pub use error::*;
 use indexmap::IndexSet;
+pub use json::JsonEmitter;
 use kclvm_span::SourceMap;
+pub use sarif::SarifEmitter;
 
 /// A handler deals with errors and other compiler output.
 /// Certain errors (error, bug) may cause immediate exit,
@@ -67,6 +71,11 @@ impl Handler {
         }
     }
 
+    /// New a handler using the emitter of the diagnostic output format.
+    pub fn with_format(format: DiagnosticFormat) -> Self {
+        Self::new(format.emitter(None))
+    }
+
     /// Panic program and report a bug
     #[inline]
     pub fn bug(&self, msg: &str) -> ! {
@@ -82,9 +91,7 @@ impl Handler {
 
     /// Emit all diagnostics and return whether has errors.
     pub fn emit(&mut self) -> bool {
-        for diag in &self.diagnostics {
-            self.emitter.emit_diagnostic(diag);
-        }
+        self.emitter.emit_diagnostics(&self.diagnostics);
         self.has_errors()
     }
     /// Format and return all diagnostics msg.
@@ -112,26 +119,38 @@ impl Handler {
         }
     }
 
-    /// Emit all diagnostics but do not abort.
-    #[inline]
+    /// Panic with a compile error [PanicInfo] if has any errors. Warnings are ignored,
+    /// the position of the panic info is the first error and the message lists every
+    /// error in order.
     pub fn alert_if_any_errors(&mut self) {
-        if self.has_errors() {
-            for diag in &self.diagnostics {
-                let pos = diag.messages[0].pos.clone();
-                let message = diag.messages[0].message.clone();
+        let errors = self
+            .diagnostics
+            .iter()
+            .filter(|diag| diag.is_error())
+            .collect::<Vec<&Diagnostic>>();
+        if let Some(first) = errors.first() {
+            let pos = first.messages[0].pos.clone();
+            let mut message = first.messages[0].message.clone();
+            for diag in &errors[1..] {
+                let msg = &diag.messages[0];
+                message += &format!("\n{}:{}", msg.pos.filename, msg.pos.line);
+                if let Some(column) = msg.pos.column {
+                    message += &format!(":{}", column);
+                }
+                message += &format!(": {}", msg.message);
+            }
 
-                let mut panic_info = PanicInfo::default();
+            let mut panic_info = PanicInfo::default();
 
-                panic_info.__kcl_PanicInfo__ = true;
-                panic_info.message = message;
-                panic_info.err_type_code = ErrType::CompileError_TYPE as i32;
+            panic_info.__kcl_PanicInfo__ = true;
+            panic_info.message = message;
+            panic_info.err_type_code = ErrType::CompileError_TYPE as i32;
 
-                panic_info.kcl_file = pos.filename.clone();
-                panic_info.kcl_line = pos.line as i32;
-                panic_info.kcl_col = pos.column.unwrap_or(0) as i32;
+            panic_info.kcl_file = pos.filename.clone();
+            panic_info.kcl_line = pos.line as i32;
+            panic_info.kcl_col = pos.column.unwrap_or(0) as i32;
 
-                panic!("{}", panic_info.to_json_string());
-            }
+            panic!("{}", panic_info.to_json_string());
         }
     }
 
@@ -210,6 +229,7 @@ impl Handler {
             level: Level::Error,
             messages: msgs.to_owned(),
             code: Some(DiagnosticId::Error(err)),
+            suggestions: vec![],
         };
         self.add_diagnostic(diag);
 
@@ -230,10 +250,34 @@ impl Handler {
     /// );
     /// ```
     pub fn add_warning(&mut self, warning: WarningKind, msgs: &[Message]) -> &mut Self {
+        self.add_warning_with_suggestions(warning, msgs, &[])
+    }
+
+    /// Add an warning with the suggested fixes into the handler
+    /// ```
+    /// use kclvm_error::*;
+    /// let mut handler = Handler::default();
+    /// handler.add_warning_with_suggestions(WarningKind::UnusedImportWarning, &[
+    ///     Message {
+    ///         pos: Position::dummy_pos(),
+    ///         style: Style::Line,
+    ///         message: "Module 'a' imported but unused.".to_string(),
+    ///         note: None,
+    ///     }],
+    ///     &[Suggestion::delete_lines("main.k", 1, 1)],
+    /// );
+    /// ```
+    pub fn add_warning_with_suggestions(
+        &mut self,
+        warning: WarningKind,
+        msgs: &[Message],
+        suggestions: &[Suggestion],
+    ) -> &mut Self {
         let diag = Diagnostic {
             level: Level::Warning,
             messages: msgs.to_owned(),
             code: Some(DiagnosticId::Warning(warning)),
+            suggestions: suggestions.to_owned(),
         };
         self.add_diagnostic(diag);
 
@@ -0,0 +1,174 @@
+//! The SARIF emitter, which writes all diagnostics into a single SARIF 2.1.0 log
+//! that can be uploaded to code scanning services to annotate pull requests.
+//!
+//! For details see the: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+use crate::{Diagnostic, Emitter, Level, Message};
+
+use indexmap::{IndexMap, IndexSet};
+use serde_json::{json, Value};
+use std::io::{self, Write};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "kclvm";
+const TOOL_INFORMATION_URI: &str = "https://github.com/KusionStack/KCLVM";
+
+/// Emitter that writes diagnostics in the SARIF 2.1.0 format.
+pub struct SarifEmitter {
+    dst: Box<dyn Write + Send>,
+}
+
+impl Default for SarifEmitter {
+    fn default() -> Self {
+        Self {
+            dst: Box::new(io::stdout()),
+        }
+    }
+}
+
+impl SarifEmitter {
+    /// New a SARIF emitter with the output destination.
+    pub fn new(dst: Box<dyn Write + Send>) -> Self {
+        Self { dst }
+    }
+
+    fn write_log(&mut self, log: &Value) {
+        let result = serde_json::to_string_pretty(log)
+            .map_err(io::Error::from)
+            .and_then(|log| writeln!(self.dst, "{}", log))
+            .and_then(|_| self.dst.flush());
+        if let Err(e) = result {
+            panic!("failed to emit error: {}", e)
+        }
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn format_diagnostic(&mut self, diag: &Diagnostic) -> Vec<String> {
+        vec![diagnostic_to_result(diag).to_string()]
+    }
+
+    fn emit_diagnostic(&mut self, diag: &Diagnostic) {
+        let mut diags = IndexSet::new();
+        diags.insert(diag.clone());
+        self.emit_diagnostics(&diags);
+    }
+
+    fn emit_diagnostics(&mut self, diags: &IndexSet<Diagnostic>) {
+        let log = diagnostics_to_sarif_log(diags);
+        self.write_log(&log);
+    }
+}
+
+/// Convert diagnostics to a SARIF log with a single run.
+pub(crate) fn diagnostics_to_sarif_log(diags: &IndexSet<Diagnostic>) -> Value {
+    // Rules are deduplicated by the rule id and keep the first appearance order.
+    let mut rules: IndexMap<String, Value> = IndexMap::new();
+    for diag in diags {
+        if let Some(code) = &diag.code {
+            let rule_id = rule_id(diag);
+            rules.entry(rule_id.clone()).or_insert_with(|| {
+                json!({
+                    "id": rule_id,
+                    "name": code.name(),
+                })
+            });
+        }
+    }
+    let rules = rules
+        .into_iter()
+        .map(|(_, rule)| rule)
+        .collect::<Vec<Value>>();
+    let results = diags
+        .iter()
+        .map(diagnostic_to_result)
+        .collect::<Vec<Value>>();
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": TOOL_NAME,
+                    "informationUri": TOOL_INFORMATION_URI,
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+/// Convert a diagnostic to a SARIF result. The first message is the primary
+/// location and the rest messages are related locations.
+fn diagnostic_to_result(diag: &Diagnostic) -> Value {
+    let (text, locations) = match diag.messages.first() {
+        Some(msg) => (message_text(msg), vec![location(msg)]),
+        None => ("".to_string(), vec![]),
+    };
+    let related_locations = diag
+        .messages
+        .iter()
+        .skip(1)
+        .enumerate()
+        .map(|(i, msg)| {
+            let mut location = location(msg);
+            location["id"] = json!(i);
+            location["message"] = json!({ "text": message_text(msg) });
+            location
+        })
+        .collect::<Vec<Value>>();
+    let mut result = json!({
+        "level": sarif_level(diag.level),
+        "message": { "text": text },
+        "locations": locations,
+        "relatedLocations": related_locations,
+    });
+    if diag.code.is_some() {
+        result["ruleId"] = json!(rule_id(diag));
+    }
+    result
+}
+
+/// The rule id is the registered code e.g., `E2G22` or the kind name when
+/// the code is not registered.
+fn rule_id(diag: &Diagnostic) -> String {
+    match &diag.code {
+        Some(code) => match code.code() {
+            Some(code) => code.to_string(),
+            None => code.name(),
+        },
+        None => "".to_string(),
+    }
+}
+
+fn sarif_level(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warning => "warning",
+        Level::Note => "note",
+    }
+}
+
+fn message_text(msg: &Message) -> String {
+    match &msg.note {
+        Some(note) => format!("{}\nNote: {}", msg.message, note),
+        None => msg.message.clone(),
+    }
+}
+
+/// SARIF lines and columns are both 1-based.
+fn location(msg: &Message) -> Value {
+    let mut region = json!({ "startLine": msg.pos.line });
+    if let Some(column) = msg.pos.column {
+        region["startColumn"] = json!(column + 1);
+    }
+    json!({
+        "physicalLocation": {
+            "artifactLocation": {
+                "uri": msg.pos.filename,
+            },
+            "region": region,
+        }
+    })
+}
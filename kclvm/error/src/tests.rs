@@ -1,4 +1,5 @@
 use crate::*;
+use indexmap::IndexSet;
 
 #[test]
 fn test_bug_macro() {
@@ -15,3 +16,174 @@ fn test_bug_macro() {
     });
     assert!(result.is_err());
 }
+
+fn test_diagnostics() -> IndexSet<Diagnostic> {
+    let mut handler = Handler::default();
+    handler.add_type_error(
+        "expect int, got str(1)",
+        Position {
+            filename: "main.k".to_string(),
+            line: 1,
+            column: Some(0),
+        },
+    );
+    handler.add_warning(
+        WarningKind::UnusedImportWarning,
+        &[Message {
+            pos: Position {
+                filename: "main.k".to_string(),
+                line: 2,
+                column: None,
+            },
+            style: Style::Line,
+            message: "Module 'a' imported but unused".to_string(),
+            note: Some("Consider removing this statement".to_string()),
+        }],
+    );
+    handler.diagnostics
+}
+
+#[test]
+fn test_diagnostic_codes() {
+    assert_eq!(ErrorKind::TypeError.code(), Some("E2G22"));
+    assert_eq!(ErrorKind::NameError.code(), None);
+    assert_eq!(WarningKind::UnusedImportWarning.code(), Some("W0411"));
+    assert_eq!(WarningKind::ReimportWarning.code(), Some("W0404"));
+    assert_eq!(WarningKind::ImportPositionWarning.code(), Some("W0413"));
+    assert_eq!(WarningKind::NoneComparisonWarning.code(), Some("W0121"));
+}
+
+#[test]
+fn test_diagnostic_format_from_str() {
+    assert_eq!(
+        "human".parse::<DiagnosticFormat>(),
+        Ok(DiagnosticFormat::Human)
+    );
+    assert_eq!(
+        "JSON".parse::<DiagnosticFormat>(),
+        Ok(DiagnosticFormat::Json)
+    );
+    assert_eq!(
+        "sarif".parse::<DiagnosticFormat>(),
+        Ok(DiagnosticFormat::Sarif)
+    );
+    assert!("xml".parse::<DiagnosticFormat>().is_err());
+}
+
+#[test]
+fn test_diagnostic_from_panic_message() {
+    let mut panic_info = kclvm::PanicInfo::default();
+    panic_info.__kcl_PanicInfo__ = true;
+    panic_info.message = "Invalid syntax: expected expression".to_string();
+    panic_info.kcl_file = "main.k".to_string();
+    panic_info.kcl_line = 2;
+    panic_info.kcl_col = 7;
+    let diag = Diagnostic::from_panic_message(&panic_info.to_json_string());
+    assert!(diag.is_error());
+    assert_eq!(diag.messages[0].message, panic_info.message);
+    assert_eq!(
+        diag.messages[0].pos,
+        Position {
+            filename: "main.k".to_string(),
+            line: 2,
+            column: Some(7),
+        }
+    );
+    let diag = Diagnostic::from_panic_message("No input KCL files");
+    assert!(diag.is_error());
+    assert_eq!(diag.messages[0].message, "No input KCL files");
+}
+
+#[test]
+fn test_alert_if_any_errors() {
+    let mut handler = Handler::default();
+    handler.diagnostics = test_diagnostics();
+    handler.diagnostics.shift_remove_index(0);
+    // Warnings are not raised as compile errors.
+    handler.alert_if_any_errors();
+    handler.add_compile_error(
+        "name 'b' is not defined",
+        Position {
+            filename: "main.k".to_string(),
+            line: 3,
+            column: Some(5),
+        },
+    );
+    handler.add_compile_error(
+        "name 'c' is not defined",
+        Position {
+            filename: "main.k".to_string(),
+            line: 4,
+            column: None,
+        },
+    );
+    let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        handler.alert_if_any_errors();
+    }))
+    .unwrap_err();
+    let panic_info: kclvm::PanicInfo =
+        serde_json::from_str(err.downcast_ref::<String>().unwrap()).unwrap();
+    assert_eq!(panic_info.kcl_line, 3);
+    assert_eq!(panic_info.kcl_col, 5);
+    assert_eq!(
+        panic_info.message,
+        "name 'b' is not defined\nmain.k:4: name 'c' is not defined"
+    );
+}
+
+#[test]
+fn test_json_emitter_format() {
+    let diags = test_diagnostics();
+    let values = diags
+        .iter()
+        .map(crate::json::diagnostic_to_json)
+        .collect::<Vec<serde_json::Value>>();
+    assert_eq!(values[0]["level"], "error");
+    assert_eq!(values[0]["code"], "E2G22");
+    assert_eq!(values[0]["kind"], "TypeError");
+    assert_eq!(values[0]["messages"][0]["filename"], "main.k");
+    assert_eq!(values[0]["messages"][0]["line"], 1);
+    assert_eq!(values[0]["messages"][0]["column"], 1);
+    assert_eq!(values[1]["level"], "warning");
+    assert_eq!(values[1]["code"], "W0411");
+    assert_eq!(values[1]["messages"][0]["column"], serde_json::Value::Null);
+    assert_eq!(
+        values[1]["messages"][0]["note"],
+        "Consider removing this statement"
+    );
+    // The suggestion columns are 1-based like the message columns.
+    let mut diag = diags[0].clone();
+    diag.suggestions = vec![Suggestion {
+        filename: "main.k".to_string(),
+        start_line: 1,
+        start_column: 0,
+        end_line: 1,
+        end_column: 5,
+        replacement: "".to_string(),
+    }];
+    let suggestion = &crate::json::diagnostic_to_json(&diag)["suggestions"][0];
+    assert_eq!(suggestion["start_column"], 1);
+    assert_eq!(suggestion["end_column"], 6);
+}
+
+#[test]
+fn test_sarif_emitter_format() {
+    let diags = test_diagnostics();
+    let log = crate::sarif::diagnostics_to_sarif_log(&diags);
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "E2G22");
+    assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "W0411");
+    let results = &run["results"];
+    assert_eq!(results[0]["ruleId"], "E2G22");
+    assert_eq!(results[0]["level"], "error");
+    let region = &results[0]["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 1);
+    assert_eq!(region["startColumn"], 1);
+    assert_eq!(results[1]["ruleId"], "W0411");
+    assert_eq!(results[1]["level"], "warning");
+    assert_eq!(
+        results[1]["message"]["text"],
+        "Module 'a' imported but unused\nNote: Consider removing this statement"
+    );
+}
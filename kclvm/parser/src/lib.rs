@@ -12,6 +12,7 @@ extern crate kclvm_error;
 use crate::session::ParseSession;
 use kclvm::{ErrType, PanicInfo};
 use kclvm_ast::ast;
+use kclvm_error::Diagnostic;
 use kclvm_span::{self, FilePathMapping, SourceMap};
 
 use lexer::parse_token_streams;
@@ -118,6 +119,60 @@ pub fn parse_expr(src: &str) -> Option<ast::NodeRef<ast::Expr>> {
     }
 }
 
+/// Parse a source string to a expression, the syntax error is returned instead of
+/// aborting the process, and the source must be exactly one expression.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_ast::ast;
+/// use kclvm_parser::try_parse_expr;
+///
+/// let expr = try_parse_expr("{a = 1}").unwrap();
+/// assert!(matches!(expr.node, ast::Expr::Config(_)));
+/// assert!(try_parse_expr("a = 1").is_err());
+/// assert!(try_parse_expr("").is_err());
+/// ```
+pub fn try_parse_expr(src: &str) -> Result<ast::NodeRef<ast::Expr>, String> {
+    if src.trim().is_empty() {
+        return Err("the expression is empty".to_string());
+    }
+    let sm = SourceMap::new(FilePathMapping::empty());
+    sm.new_source_file(PathBuf::from("").into(), src.to_string());
+    let sess = &ParseSession::with_source_map(Arc::new(sm));
+
+    // The parser aborts on the syntax error, catch it and return the error message.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        create_session_globals_then(|| {
+            let stream = parse_token_streams(sess, src, BytePos::from_u32(0));
+            let mut parser = Parser::new(sess, stream);
+            let expr = parser.parse_expr();
+            parser.skip_newlines();
+            (
+                expr,
+                matches!(parser.token.kind, kclvm_ast::token::TokenKind::Eof),
+            )
+        })
+    }));
+    match result {
+        Ok((expr, true)) => Ok(expr),
+        Ok((_, false)) => Err("unexpected tokens after the expression".to_string()),
+        Err(err) => {
+            let msg = if let Some(s) = err.downcast_ref::<String>() {
+                s.clone()
+            } else if let Some(s) = err.downcast_ref::<&str>() {
+                s.to_string()
+            } else {
+                "invalid syntax".to_string()
+            };
+            match serde_json::from_str::<PanicInfo>(&msg) {
+                Ok(panic_info) => Err(panic_info.message),
+                Err(_) => Err(msg),
+            }
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct LoadProgramOptions {
     pub work_dir: String,
@@ -142,6 +197,46 @@ pub fn load_program(
     }
 }
 
+/// Load the program like [load_program], the syntax errors and the load errors are
+/// returned as the error diagnostic instead of aborting the process.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_parser::{try_load_program, LoadProgramOptions};
+///
+/// let opts = LoadProgramOptions {
+///     k_code_list: vec!["a = 1 +".to_string()],
+///     ..Default::default()
+/// };
+/// let err = try_load_program(&["main.k"], Some(opts)).unwrap_err();
+/// assert!(err.is_error());
+/// assert_eq!(err.messages[0].pos.filename, "main.k");
+/// assert!(try_load_program(&["not_found.k"], None).is_err());
+/// ```
+pub fn try_load_program(
+    paths: &[&str],
+    opts: Option<LoadProgramOptions>,
+) -> Result<ast::Program, Diagnostic> {
+    // The parser aborts on the syntax error, catch it and convert it to the diagnostic.
+    let result =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| load_program(paths, opts)));
+    match result {
+        Ok(Ok(program)) => Ok(program),
+        Ok(Err(err)) => Err(Diagnostic::from_panic_message(&err)),
+        Err(err) => {
+            let msg = if let Some(s) = err.downcast_ref::<String>() {
+                s.clone()
+            } else if let Some(s) = err.downcast_ref::<&str>() {
+                s.to_string()
+            } else {
+                "invalid syntax".to_string()
+            };
+            Err(Diagnostic::from_panic_message(&msg))
+        }
+    }
+}
+
 struct Loader {
     paths: Vec<String>,
     opts: LoadProgramOptions,
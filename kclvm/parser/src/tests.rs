@@ -95,3 +95,16 @@ pub fn test_parse_expr_invalid() {
         check_result_panic_info(result);
     }
 }
+
+#[test]
+pub fn test_try_parse_expr() {
+    assert!(matches!(
+        try_parse_expr("[1, 2]").unwrap().node,
+        ast::Expr::List(_)
+    ));
+    for case in PARSE_EXPR_INVALID_TEST_CASES {
+        assert!(try_parse_expr(case).is_err());
+    }
+    assert!(try_parse_expr("a = 1").is_err());
+    assert!(try_parse_expr(" ").is_err());
+}
@@ -3,7 +3,7 @@ use std::path::Path;
 use assembler::KclvmLibAssembler;
 use command::Command;
 use kclvm_ast::ast::Program;
-use kclvm_sema::resolver::resolve_program;
+use kclvm_sema::resolver::{resolve_program_with_options, scope::ProgramScope};
 pub use runner::ExecProgramArgs;
 use runner::{KclvmRunner, KclvmRunnerOptions};
 use tempfile::tempdir;
@@ -12,6 +12,7 @@ pub mod assembler;
 pub mod command;
 pub mod linker;
 pub mod runner;
+pub mod testing;
 
 #[cfg(test)]
 pub mod tests;
@@ -27,7 +28,8 @@ pub mod tests;
 ///
 /// "args" is the items selected by the user in the KCLVM CLI.
 ///
-/// This method will first resolve “program” (ast.Program) and save the result to the "scope" (ProgramScope).
+/// This method will first resolve “program” (ast.Program) with the resolve options of "args" and save
+/// the result to the "scope" (ProgramScope).
 ///
 /// Then, dynamic link libraries is generated by KclvmAssembler, and method "KclvmAssembler::gen_libs"
 /// will return dynamic link library paths in a "Vec<String>";
@@ -67,10 +69,20 @@ pub fn execute(
     plugin_agent: u64,
     args: &ExecProgramArgs,
 ) -> Result<String, String> {
-    // Resolve ast
-    let scope = resolve_program(&mut program);
-    scope.check_scope_diagnostics();
+    // Resolve ast, the compile errors are raised by the resolve options.
+    let scope = resolve_program_with_options(&mut program, args.get_resolve_options());
+    execute_resolved(program, scope, plugin_agent, args)
+}
 
+/// Execute the program which has been resolved to the "scope" (ProgramScope) like [execute],
+/// so callers that report the compile errors of the scope themselves e.g., in the JSON format
+/// don't need to resolve the program again. The compile errors of the scope are not checked.
+pub fn execute_resolved(
+    program: Program,
+    scope: ProgramScope,
+    plugin_agent: u64,
+    args: &ExecProgramArgs,
+) -> Result<String, String> {
     // Create a temp entry file and the temp dir will be delete automatically
     let temp_dir = tempdir().unwrap();
     let temp_dir_path = temp_dir.path().to_str().unwrap();
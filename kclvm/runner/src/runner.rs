@@ -38,6 +38,23 @@ pub struct ExecProgramArgs {
     pub sort_keys: bool,
     // include schema type path in JSON/YAML result
     pub include_schema_type_path: bool,
+
+    // run the lint passes before executing
+    #[serde(default)]
+    pub lint_check: bool,
+
+    // json: indent width of the JSON result, 0 means the single-line output
+    #[serde(default)]
+    pub json_indent: i64,
+    // yaml: indent width of block collections in the YAML result
+    #[serde(default)]
+    pub yaml_indent: i64,
+    // yaml: emit multiline strings as literal block scalars
+    #[serde(default)]
+    pub yaml_block_scalars: bool,
+    // yaml: emit collections in the flow style
+    #[serde(default)]
+    pub yaml_flow_style: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -63,6 +80,37 @@ impl ExecProgramArgs {
         self.k_filename_list.iter().map(|s| s.as_str()).collect()
     }
 
+    /// Get the resolve options, compile errors are always raised before executing and
+    /// the lint passes are skipped unless `lint_check` is set.
+    pub fn get_resolve_options(&self) -> kclvm_sema::resolver::Options {
+        kclvm_sema::resolver::Options {
+            raise_err: true,
+            config_auto_fix: false,
+            lint_check: self.lint_check,
+            ..Default::default()
+        }
+    }
+
+    /// Get the encode options of the JSON result.
+    pub fn get_json_encode_options(&self) -> kclvm::JsonEncodeOptions {
+        kclvm::JsonEncodeOptions {
+            sort_keys: self.sort_keys,
+            indent: self.json_indent,
+            ..Default::default()
+        }
+    }
+
+    /// Get the encode options of the YAML result.
+    pub fn get_yaml_encode_options(&self) -> kclvm::YamlEncodeOptions {
+        kclvm::YamlEncodeOptions {
+            sort_keys: self.sort_keys,
+            indent: self.yaml_indent,
+            block_scalars: self.yaml_block_scalars,
+            flow_style: self.yaml_flow_style,
+            ..Default::default()
+        }
+    }
+
     pub fn get_load_program_options(&self) -> kclvm_parser::LoadProgramOptions {
         kclvm_parser::LoadProgramOptions {
             work_dir: self.work_dir.clone().unwrap_or_else(|| "".to_string()),
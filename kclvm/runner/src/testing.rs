@@ -0,0 +1,541 @@
+//! The KCL test runner.
+//!
+//! The runner discovers the `*_test.k` files of the packages and runs each top-level
+//! `test_*` lambda as a separate program consisting of the package files and the test
+//! file. The options set by `testing.arguments(name, value)` and
+//! `testing.setting_file(filename)` at the top level of the test file apply to all
+//! test cases of the file, and the ones in the lambda body only apply to the test case.
+//!
+//! ```no_check
+//! use kclvm_runner::testing::{load_test_suites, run_test_suites, TestOptions};
+//!
+//! let suites = load_test_suites(&["./pkg"]).unwrap();
+//! let results = run_test_suites(&suites, &TestOptions::default()).unwrap();
+//! ```
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use kclvm::PanicInfo;
+use kclvm_ast::ast;
+use kclvm_config::settings::load_file;
+use kclvm_parser::{load_program, parse_file};
+use regex::Regex;
+
+use crate::{execute, ExecProgramArgs};
+
+pub const TEST_FILE_SUFFIX: &str = "_test.k";
+pub const TEST_CASE_PREFIX: &str = "test_";
+const KCL_FILE_SUFFIX: &str = ".k";
+const TESTING_MODULE: &str = "testing";
+const TESTING_ARGUMENTS: &str = "arguments";
+const TESTING_SETTING_FILE: &str = "setting_file";
+/// The package path with the suffix `...` e.g., `./...` means the package and all
+/// sub packages of the directory.
+const RECURSIVE_PATH_SUFFIX: &str = "...";
+/// The variable to store the result of the test case lambda call.
+const TEST_RESULT_VAR: &str = "_kcl_test_result";
+
+/// Options of running the test suites.
+#[derive(Debug, Default, Clone)]
+pub struct TestOptions {
+    /// Only run the test cases whose names match the regular expression.
+    pub run_regexp: String,
+    /// The plugin agent pointer of the program execution.
+    pub plugin_agent: u64,
+    /// The execution arguments shared by all test cases.
+    pub exec_args: ExecProgramArgs,
+}
+
+/// The test suite of a package, which contains the test cases of all test files.
+#[derive(Debug, Default, Clone)]
+pub struct TestSuite {
+    /// The package directory.
+    pub pkg: String,
+    /// The KCL files of the package except the test files.
+    pub pkg_files: Vec<String>,
+    pub cases: Vec<TestCase>,
+}
+
+/// The test case, which is a top-level `test_*` lambda of a test file.
+#[derive(Debug, Default, Clone)]
+pub struct TestCase {
+    pub name: String,
+    pub file: String,
+    pub line: u64,
+    /// The options set by `testing.arguments` and `testing.setting_file`.
+    pub args: Vec<ast::CmdArgSpec>,
+}
+
+/// The test result of a test case, the error is `None` when the test case passed.
+#[derive(Debug, Default, Clone)]
+pub struct TestResult {
+    pub name: String,
+    pub file: String,
+    pub duration: Duration,
+    pub error: Option<TestError>,
+}
+
+/// The failure of a test case e.g., the assertion error with the source position.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TestError {
+    pub message: String,
+    pub file: String,
+    pub line: i32,
+    pub column: i32,
+}
+
+/// The test results of a test suite.
+#[derive(Debug, Default, Clone)]
+pub struct TestSuiteResult {
+    pub pkg: String,
+    pub results: Vec<TestResult>,
+}
+
+impl TestSuiteResult {
+    /// Returns the number of the failed test cases.
+    pub fn failures(&self) -> usize {
+        self.results.iter().filter(|r| r.error.is_some()).count()
+    }
+
+    /// Returns the total duration of the test cases.
+    pub fn duration(&self) -> Duration {
+        self.results.iter().map(|r| r.duration).sum()
+    }
+}
+
+impl std::fmt::Display for TestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.file.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(
+                f,
+                "{}:{}:{}: {}",
+                self.file, self.line, self.column, self.message
+            )
+        }
+    }
+}
+
+/// Discover the test suites of the paths. A path is a test file, a package directory
+/// or a directory with the suffix `...` including all sub packages.
+pub fn load_test_suites(paths: &[&str]) -> Result<Vec<TestSuite>, String> {
+    let mut test_files: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+    for path in paths {
+        let (dir, recursive) = match path.strip_suffix(RECURSIVE_PATH_SUFFIX) {
+            Some(dir) if dir.is_empty() => (Path::new("."), true),
+            Some(dir) => (Path::new(dir), true),
+            None => (Path::new(path), false),
+        };
+        if dir.is_file() {
+            if !path.ends_with(TEST_FILE_SUFFIX) {
+                return Err(format!("'{}' is not a KCL test file", path));
+            }
+            let pkg = dir.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
+            test_files.entry(pkg).or_default().push(dir.to_path_buf());
+        } else if dir.is_dir() {
+            let max_depth = if recursive { usize::MAX } else { 1 };
+            for entry in walkdir::WalkDir::new(dir)
+                .max_depth(max_depth)
+                .into_iter()
+                .flatten()
+            {
+                let file = entry.path();
+                if file.is_file() && file.to_string_lossy().ends_with(TEST_FILE_SUFFIX) {
+                    let pkg = file.parent().unwrap_or(dir).to_path_buf();
+                    test_files.entry(pkg).or_default().push(file.to_path_buf());
+                }
+            }
+        } else {
+            return Err(format!("Cannot find the test path '{}'", path));
+        }
+    }
+    let mut suites = vec![];
+    for (pkg, mut files) in test_files {
+        files.sort();
+        files.dedup();
+        let mut suite = TestSuite {
+            pkg: pkg.to_string_lossy().to_string(),
+            pkg_files: get_pkg_files(&pkg)?,
+            cases: vec![],
+        };
+        for file in files {
+            suite
+                .cases
+                .append(&mut load_test_cases(&file.to_string_lossy())?);
+        }
+        suites.push(suite);
+    }
+    Ok(suites)
+}
+
+/// Run the test cases of the test suites whose names match the option `run_regexp`.
+pub fn run_test_suites(
+    suites: &[TestSuite],
+    opts: &TestOptions,
+) -> Result<Vec<TestSuiteResult>, String> {
+    let run_regexp = Regex::new(&opts.run_regexp)
+        .map_err(|err| format!("invalid regular expression of --run: {}", err))?;
+    Ok(suites
+        .iter()
+        .map(|suite| TestSuiteResult {
+            pkg: suite.pkg.clone(),
+            results: suite
+                .cases
+                .iter()
+                .filter(|case| run_regexp.is_match(&case.name))
+                .map(|case| run_test_case(suite, case, opts))
+                .collect(),
+        })
+        .collect())
+}
+
+/// Run the test case as a program of the package files and the test file which
+/// calls the test case lambda at the end.
+pub fn run_test_case(suite: &TestSuite, case: &TestCase, opts: &TestOptions) -> TestResult {
+    let start_time = Instant::now();
+    let error = exec_test_case(suite, case, opts).err();
+    TestResult {
+        name: case.name.clone(),
+        file: case.file.clone(),
+        duration: start_time.elapsed(),
+        error,
+    }
+}
+
+fn exec_test_case(suite: &TestSuite, case: &TestCase, opts: &TestOptions) -> Result<(), TestError> {
+    let code = std::fs::read_to_string(&case.file).map_err(|err| TestError {
+        message: format!("failed to read the test file: {}", err),
+        ..Default::default()
+    })?;
+    // The test file is the first file to use the code with the lambda call.
+    let code = format!("{}\n{} = {}()\n", code, TEST_RESULT_VAR, case.name);
+    let mut files = vec![case.file.as_str()];
+    files.extend(suite.pkg_files.iter().map(|f| f.as_str()));
+
+    let mut args = opts.exec_args.clone();
+    args.args.extend(case.args.iter().cloned());
+    let mut load_opts = args.get_load_program_options();
+    load_opts.work_dir = suite.pkg.clone();
+    load_opts.k_code_list = vec![code];
+    let program = load_program(&files, Some(load_opts)).map_err(|err| TestError {
+        message: err,
+        ..Default::default()
+    })?;
+    match execute(program, opts.plugin_agent, &args) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(match serde_json::from_str::<PanicInfo>(&err) {
+            Ok(info) => TestError {
+                message: info.message,
+                file: info.kcl_file,
+                line: info.kcl_line,
+                column: info.kcl_col,
+            },
+            Err(_) => TestError {
+                message: err,
+                ..Default::default()
+            },
+        }),
+    }
+}
+
+/// Returns the KCL files of the package except the test files.
+fn get_pkg_files(pkg: &Path) -> Result<Vec<String>, String> {
+    let entries = std::fs::read_dir(pkg).map_err(|err| err.to_string())?;
+    let mut files = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let path = path.to_string_lossy();
+            path.ends_with(KCL_FILE_SUFFIX) && !path.ends_with(TEST_FILE_SUFFIX)
+        })
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+    files.sort();
+    Ok(files)
+}
+
+/// Load the top-level `test_*` lambdas of the test file and the options of them.
+pub fn load_test_cases(file: &str) -> Result<Vec<TestCase>, String> {
+    let module = parse_file(file, None)?;
+    let testing_names = get_testing_import_names(&module);
+    let base_dir = Path::new(file).parent().unwrap_or_else(|| Path::new("."));
+    // The options at the top level apply to all test cases of the file.
+    let mut file_args = vec![];
+    for stmt in &module.body {
+        if let ast::Stmt::Expr(expr_stmt) = &stmt.node {
+            file_args.append(&mut get_testing_args(
+                &expr_stmt.exprs,
+                &testing_names,
+                base_dir,
+            )?);
+        }
+    }
+    let mut cases = vec![];
+    for stmt in &module.body {
+        if let ast::Stmt::Assign(assign_stmt) = &stmt.node {
+            let name = match assign_stmt.targets.as_slice() {
+                [target] if target.node.names.len() == 1 => &target.node.names[0],
+                _ => continue,
+            };
+            if !name.starts_with(TEST_CASE_PREFIX) {
+                continue;
+            }
+            if let ast::Expr::Lambda(lambda_expr) = &assign_stmt.value.node {
+                let mut args = file_args.clone();
+                for stmt in &lambda_expr.body {
+                    if let ast::Stmt::Expr(expr_stmt) = &stmt.node {
+                        args.append(&mut get_testing_args(
+                            &expr_stmt.exprs,
+                            &testing_names,
+                            base_dir,
+                        )?);
+                    }
+                }
+                cases.push(TestCase {
+                    name: name.to_string(),
+                    file: file.to_string(),
+                    line: stmt.line,
+                    args,
+                });
+            }
+        }
+    }
+    Ok(cases)
+}
+
+/// Returns the names of the `testing` module imports e.g., `t` of `import testing as t`.
+fn get_testing_import_names(module: &ast::Module) -> Vec<String> {
+    module
+        .body
+        .iter()
+        .filter_map(|stmt| match &stmt.node {
+            ast::Stmt::Import(import_stmt) if import_stmt.path == TESTING_MODULE => Some(
+                import_stmt
+                    .asname
+                    .clone()
+                    .unwrap_or_else(|| import_stmt.name.clone()),
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the options set by the `testing.arguments` and `testing.setting_file` calls.
+fn get_testing_args(
+    exprs: &[ast::NodeRef<ast::Expr>],
+    testing_names: &[String],
+    base_dir: &Path,
+) -> Result<Vec<ast::CmdArgSpec>, String> {
+    let mut args = vec![];
+    for expr in exprs {
+        let call_expr = match &expr.node {
+            ast::Expr::Call(call_expr) => call_expr,
+            _ => continue,
+        };
+        let func_name = match &call_expr.func.node {
+            ast::Expr::Identifier(identifier) => match identifier.names.as_slice() {
+                [module, func] if testing_names.contains(module) => func.as_str(),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let pos = format!("{}:{}:{}", expr.filename, expr.line, expr.column);
+        match func_name {
+            TESTING_ARGUMENTS => match call_expr.args.as_slice() {
+                [name, value] => match (&name.node, literal_to_json(&value.node)) {
+                    (ast::Expr::StringLit(name), Some(value)) => args.push(ast::CmdArgSpec {
+                        name: name.value.clone(),
+                        value,
+                    }),
+                    _ => {
+                        return Err(format!(
+                            "{}: the arguments of testing.arguments must be a string name and a literal value",
+                            pos
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(format!(
+                        "{}: testing.arguments takes exactly 2 arguments",
+                        pos
+                    ))
+                }
+            },
+            TESTING_SETTING_FILE => {
+                let filename = match call_expr.args.as_slice() {
+                    [filename] => match &filename.node {
+                        ast::Expr::StringLit(filename) => base_dir.join(&filename.value),
+                        _ => {
+                            return Err(format!(
+                                "{}: the argument of testing.setting_file must be a string",
+                                pos
+                            ))
+                        }
+                    },
+                    _ => {
+                        return Err(format!(
+                            "{}: testing.setting_file takes exactly 1 argument",
+                            pos
+                        ))
+                    }
+                };
+                if !filename.is_file() {
+                    return Err(format!(
+                        "{}: cannot find the setting file '{}'",
+                        pos,
+                        filename.display()
+                    ));
+                }
+                let settings = load_file(&filename.to_string_lossy());
+                for option in settings.kcl_options.unwrap_or_default() {
+                    args.push(ast::CmdArgSpec {
+                        name: option.key,
+                        value: option.value,
+                    });
+                }
+            }
+            _ => continue,
+        }
+    }
+    Ok(args)
+}
+
+/// Returns the JSON string of the literal expression, which is the option value.
+fn literal_to_json(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::StringLit(string_lit) => serde_json::to_string(&string_lit.value).ok(),
+        ast::Expr::NumberLit(number_lit) if number_lit.binary_suffix.is_none() => {
+            match number_lit.value {
+                ast::NumberLitValue::Int(v) => Some(v.to_string()),
+                ast::NumberLitValue::Float(v) => Some(format!("{:?}", v)),
+            }
+        }
+        ast::Expr::NameConstantLit(name_constant_lit) => match name_constant_lit.value {
+            ast::NameConstant::True => Some("true".to_string()),
+            ast::NameConstant::False => Some("false".to_string()),
+            ast::NameConstant::None | ast::NameConstant::Undefined => Some("null".to_string()),
+        },
+        ast::Expr::Unary(unary_expr) if matches!(unary_expr.op, ast::UnaryOp::USub) => {
+            match &unary_expr.operand.node {
+                ast::Expr::NumberLit(_) => {
+                    literal_to_json(&unary_expr.operand.node).map(|v| format!("-{}", v))
+                }
+                _ => None,
+            }
+        }
+        ast::Expr::List(list_expr) => {
+            let items = list_expr
+                .elts
+                .iter()
+                .map(|elt| literal_to_json(&elt.node))
+                .collect::<Option<Vec<String>>>()?;
+            Some(format!("[{}]", items.join(", ")))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the human-readable report of the test results.
+pub fn format_test_results(suite_results: &[TestSuiteResult], verbose: bool) -> String {
+    let mut report = String::new();
+    for suite_result in suite_results {
+        for result in &suite_result.results {
+            match &result.error {
+                Some(err) => {
+                    report.push_str(&format!(
+                        "--- FAIL: {} ({:.2}s)\n    {}\n",
+                        result.name,
+                        result.duration.as_secs_f64(),
+                        err
+                    ));
+                }
+                None if verbose => {
+                    report.push_str(&format!(
+                        "--- PASS: {} ({:.2}s)\n",
+                        result.name,
+                        result.duration.as_secs_f64()
+                    ));
+                }
+                None => {}
+            }
+        }
+        let status = if suite_result.failures() > 0 {
+            "FAIL"
+        } else {
+            "ok"
+        };
+        report.push_str(&format!(
+            "{}\t{}\t{:.3}s\n",
+            status,
+            suite_result.pkg,
+            suite_result.duration().as_secs_f64()
+        ));
+    }
+    report
+}
+
+/// Returns the JUnit XML report of the test results.
+pub fn to_junit_xml(suite_results: &[TestSuiteResult]) -> String {
+    let tests: usize = suite_results.iter().map(|s| s.results.len()).sum();
+    let failures: usize = suite_results.iter().map(|s| s.failures()).sum();
+    let duration: Duration = suite_results.iter().map(|s| s.duration()).sum();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+        tests,
+        failures,
+        duration.as_secs_f64()
+    ));
+    for suite_result in suite_results {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            escape_xml(&suite_result.pkg),
+            suite_result.results.len(),
+            suite_result.failures(),
+            suite_result.duration().as_secs_f64()
+        ));
+        for result in &suite_result.results {
+            let testcase = format!(
+                "    <testcase name=\"{}\" classname=\"{}\" file=\"{}\" time=\"{:.3}\"",
+                escape_xml(&result.name),
+                escape_xml(&suite_result.pkg),
+                escape_xml(&result.file),
+                result.duration.as_secs_f64()
+            );
+            match &result.error {
+                Some(err) => {
+                    xml.push_str(&format!(
+                        "{}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                        testcase,
+                        escape_xml(&err.message),
+                        escape_xml(&err.to_string())
+                    ));
+                }
+                None => {
+                    xml.push_str(&testcase);
+                    xml.push_str("/>\n");
+                }
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
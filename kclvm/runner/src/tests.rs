@@ -2,6 +2,10 @@ use crate::assembler::KclvmAssembler;
 use crate::assembler::KclvmLibAssembler;
 use crate::assembler::LibAssembler;
 use crate::temp_file;
+use crate::testing::{
+    load_test_suites, run_test_suites, to_junit_xml, TestError, TestOptions, TestResult,
+    TestSuiteResult,
+};
 use crate::Command;
 use crate::{execute, runner::ExecProgramArgs};
 use kclvm_ast::ast::{Module, Program};
@@ -44,6 +48,8 @@ const SETTINGS_FILE_TEST_CASE: &[&'static (&str, &str); 1] =
 
 const EXPECTED_JSON_FILE_NAME: &str = "stdout.golden.json";
 const TEST_CASE_PATH: &str = "./src/test_datas";
+const TESTING_PKG_PATH: &str = "./src/test_datas/testing/pkg";
+const TESTING_FAIL_PKG_PATH: &str = "./src/test_datas/testing/fail_pkg";
 const KCL_FILE_NAME: &str = "main.k";
 const MAIN_PKG_NAME: &str = "__main__";
 
@@ -362,6 +368,105 @@ fn test_exec_file() {
     std::panic::set_hook(prev_hook);
 }
 
+#[test]
+fn test_load_test_suites() {
+    let suites = load_test_suites(&[TESTING_PKG_PATH]).unwrap();
+    assert_eq!(suites.len(), 1);
+    let suite = &suites[0];
+    assert_eq!(
+        suite.pkg_files,
+        vec![format!("{}/main.k", TESTING_PKG_PATH)]
+    );
+    let cases: Vec<(&str, Vec<(&str, &str)>)> = suite
+        .cases
+        .iter()
+        .map(|case| {
+            (
+                case.name.as_str(),
+                case.args
+                    .iter()
+                    .map(|arg| (arg.name.as_str(), arg.value.as_str()))
+                    .collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        cases,
+        vec![
+            ("test_name", vec![("name", "\"app\"")]),
+            (
+                "test_replicas",
+                vec![("name", "\"app\""), ("replicas", "3")]
+            ),
+        ]
+    );
+    assert!(load_test_suites(&["./src/test_datas/testing/not_exist"]).is_err());
+}
+
+#[test]
+fn test_run_test_suites() {
+    let suites = load_test_suites(&[TESTING_PKG_PATH, TESTING_FAIL_PKG_PATH]).unwrap();
+    let results = run_test_suites(&suites, &TestOptions::default()).unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].failures(), 1);
+    let err = results[0].results[0].error.as_ref().unwrap();
+    assert!(err.message.contains("one is not two"), "{}", err);
+    assert_eq!(err.line, 2);
+    assert_eq!(results[1].results.len(), 2);
+    assert_eq!(results[1].failures(), 0);
+
+    let opts = TestOptions {
+        run_regexp: "^test_name$".to_string(),
+        ..Default::default()
+    };
+    let results = run_test_suites(&suites, &opts).unwrap();
+    assert_eq!(results[0].results.len(), 0);
+    assert_eq!(results[1].results.len(), 1);
+    let opts = TestOptions {
+        run_regexp: "(".to_string(),
+        ..Default::default()
+    };
+    assert!(run_test_suites(&suites, &opts).is_err());
+}
+
+#[test]
+fn test_to_junit_xml() {
+    let results = vec![TestSuiteResult {
+        pkg: "pkg".to_string(),
+        results: vec![
+            TestResult {
+                name: "test_a".to_string(),
+                file: "pkg/a_test.k".to_string(),
+                ..Default::default()
+            },
+            TestResult {
+                name: "test_b".to_string(),
+                file: "pkg/a_test.k".to_string(),
+                error: Some(TestError {
+                    message: "a < b".to_string(),
+                    file: "pkg/a_test.k".to_string(),
+                    line: 5,
+                    column: 1,
+                }),
+                ..Default::default()
+            },
+        ],
+    }];
+    assert_eq!(
+        to_junit_xml(&results),
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites tests="2" failures="1" time="0.000">
+  <testsuite name="pkg" tests="2" failures="1" time="0.000">
+    <testcase name="test_a" classname="pkg" file="pkg/a_test.k" time="0.000"/>
+    <testcase name="test_b" classname="pkg" file="pkg/a_test.k" time="0.000">
+      <failure message="a &lt; b">pkg/a_test.k:5:1: a &lt; b</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+}
+
 fn exec(file: &str) -> Result<String, String> {
     let mut args = ExecProgramArgs::default();
     args.k_filename_list.push(file.to_string());
@@ -27,8 +27,14 @@ impl ApiType {
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum ApiFunc {
     kclvm_assert,
+    kclvm_base64_base32_decode,
+    kclvm_base64_base32_encode,
     kclvm_base64_decode,
     kclvm_base64_encode,
+    kclvm_base64_hex_decode,
+    kclvm_base64_hex_encode,
+    kclvm_base64_urlsafe_decode,
+    kclvm_base64_urlsafe_encode,
     kclvm_buffer_data,
     kclvm_buffer_delete,
     kclvm_buffer_new,
@@ -117,16 +123,24 @@ pub enum ApiFunc {
     kclvm_context_symbol_num,
     kclvm_context_symbol_value,
     kclvm_convert_collection_value,
+    kclvm_crypto_blake3,
+    kclvm_crypto_hmac_sha256,
     kclvm_crypto_md5,
     kclvm_crypto_sha1,
     kclvm_crypto_sha224,
     kclvm_crypto_sha256,
     kclvm_crypto_sha384,
     kclvm_crypto_sha512,
+    kclvm_crypto_uuid,
+    kclvm_datetime_add,
     kclvm_datetime_date,
+    kclvm_datetime_duration,
+    kclvm_datetime_format,
     kclvm_datetime_now,
+    kclvm_datetime_parse,
     kclvm_datetime_ticks,
     kclvm_datetime_today,
+    kclvm_datetime_validate,
     kclvm_debug_hello,
     kclvm_debug_invoke_func,
     kclvm_debug_print,
@@ -154,6 +168,11 @@ pub enum ApiFunc {
     kclvm_dict_update,
     kclvm_dict_update_key_value,
     kclvm_dict_values,
+    kclvm_file_exists,
+    kclvm_file_glob,
+    kclvm_file_modpath,
+    kclvm_file_read,
+    kclvm_file_workdir,
     kclvm_free,
     kclvm_iterator_cur_key,
     kclvm_iterator_cur_value,
@@ -198,8 +217,11 @@ pub enum ApiFunc {
     kclvm_math_modf,
     kclvm_math_pow,
     kclvm_math_sqrt,
+    kclvm_net_CIDR_contains,
+    kclvm_net_CIDR_overlaps,
     kclvm_net_IP_string,
     kclvm_net_fqdn,
+    kclvm_net_host_count,
     kclvm_net_is_IP,
     kclvm_net_is_IPv4,
     kclvm_net_is_global_unicast_IP,
@@ -210,8 +232,10 @@ pub enum ApiFunc {
     kclvm_net_is_multicast_IP,
     kclvm_net_is_unspecified_IP,
     kclvm_net_join_host_port,
+    kclvm_net_parse_CIDR,
     kclvm_net_parse_IP,
     kclvm_net_split_host_port,
+    kclvm_net_subnets,
     kclvm_net_to_IP16,
     kclvm_net_to_IP4,
     kclvm_plugin_init,
@@ -219,7 +243,9 @@ pub enum ApiFunc {
     kclvm_plugin_invoke_json,
     kclvm_regex_compile,
     kclvm_regex_findall,
+    kclvm_regex_groups,
     kclvm_regex_match,
+    kclvm_regex_named_groups,
     kclvm_regex_replace,
     kclvm_regex_search,
     kclvm_regex_split,
@@ -232,7 +258,13 @@ pub enum ApiFunc {
     kclvm_schema_optional_check,
     kclvm_schema_value_check,
     kclvm_schema_value_new,
+    kclvm_semver_bump,
+    kclvm_semver_compare,
+    kclvm_semver_parse,
+    kclvm_semver_satisfies,
     kclvm_strlen,
+    kclvm_template_execute,
+    kclvm_template_html_escape,
     kclvm_testing_arguments,
     kclvm_testing_setting_file,
     kclvm_type_BoolLit_value,
@@ -363,8 +395,10 @@ pub enum ApiFunc {
     kclvm_value_union,
     kclvm_value_union_all,
     kclvm_yaml_decode,
+    kclvm_yaml_decode_all,
     kclvm_yaml_dump_to_file,
     kclvm_yaml_encode,
+    kclvm_yaml_encode_all,
 }
 
 impl std::fmt::Display for ApiFunc {
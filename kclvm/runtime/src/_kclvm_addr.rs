@@ -6,8 +6,14 @@
 pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
     match name {
         "kclvm_assert" => crate::kclvm_assert as *const () as u64,
+        "kclvm_base64_base32_decode" => crate::kclvm_base64_base32_decode as *const () as u64,
+        "kclvm_base64_base32_encode" => crate::kclvm_base64_base32_encode as *const () as u64,
         "kclvm_base64_decode" => crate::kclvm_base64_decode as *const () as u64,
         "kclvm_base64_encode" => crate::kclvm_base64_encode as *const () as u64,
+        "kclvm_base64_hex_decode" => crate::kclvm_base64_hex_decode as *const () as u64,
+        "kclvm_base64_hex_encode" => crate::kclvm_base64_hex_encode as *const () as u64,
+        "kclvm_base64_urlsafe_decode" => crate::kclvm_base64_urlsafe_decode as *const () as u64,
+        "kclvm_base64_urlsafe_encode" => crate::kclvm_base64_urlsafe_encode as *const () as u64,
         "kclvm_buffer_data" => crate::kclvm_buffer_data as *const () as u64,
         "kclvm_buffer_delete" => crate::kclvm_buffer_delete as *const () as u64,
         "kclvm_buffer_new" => crate::kclvm_buffer_new as *const () as u64,
@@ -116,16 +122,24 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_convert_collection_value" => {
             crate::kclvm_convert_collection_value as *const () as u64
         }
+        "kclvm_crypto_blake3" => crate::kclvm_crypto_blake3 as *const () as u64,
+        "kclvm_crypto_hmac_sha256" => crate::kclvm_crypto_hmac_sha256 as *const () as u64,
         "kclvm_crypto_md5" => crate::kclvm_crypto_md5 as *const () as u64,
         "kclvm_crypto_sha1" => crate::kclvm_crypto_sha1 as *const () as u64,
         "kclvm_crypto_sha224" => crate::kclvm_crypto_sha224 as *const () as u64,
         "kclvm_crypto_sha256" => crate::kclvm_crypto_sha256 as *const () as u64,
         "kclvm_crypto_sha384" => crate::kclvm_crypto_sha384 as *const () as u64,
         "kclvm_crypto_sha512" => crate::kclvm_crypto_sha512 as *const () as u64,
+        "kclvm_crypto_uuid" => crate::kclvm_crypto_uuid as *const () as u64,
+        "kclvm_datetime_add" => crate::kclvm_datetime_add as *const () as u64,
         "kclvm_datetime_date" => crate::kclvm_datetime_date as *const () as u64,
+        "kclvm_datetime_duration" => crate::kclvm_datetime_duration as *const () as u64,
+        "kclvm_datetime_format" => crate::kclvm_datetime_format as *const () as u64,
         "kclvm_datetime_now" => crate::kclvm_datetime_now as *const () as u64,
+        "kclvm_datetime_parse" => crate::kclvm_datetime_parse as *const () as u64,
         "kclvm_datetime_ticks" => crate::kclvm_datetime_ticks as *const () as u64,
         "kclvm_datetime_today" => crate::kclvm_datetime_today as *const () as u64,
+        "kclvm_datetime_validate" => crate::kclvm_datetime_validate as *const () as u64,
         "kclvm_debug_hello" => crate::kclvm_debug_hello as *const () as u64,
         "kclvm_debug_invoke_func" => crate::kclvm_debug_invoke_func as *const () as u64,
         "kclvm_debug_print" => crate::kclvm_debug_print as *const () as u64,
@@ -159,6 +173,11 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_dict_update" => crate::kclvm_dict_update as *const () as u64,
         "kclvm_dict_update_key_value" => crate::kclvm_dict_update_key_value as *const () as u64,
         "kclvm_dict_values" => crate::kclvm_dict_values as *const () as u64,
+        "kclvm_file_exists" => crate::kclvm_file_exists as *const () as u64,
+        "kclvm_file_glob" => crate::kclvm_file_glob as *const () as u64,
+        "kclvm_file_modpath" => crate::kclvm_file_modpath as *const () as u64,
+        "kclvm_file_read" => crate::kclvm_file_read as *const () as u64,
+        "kclvm_file_workdir" => crate::kclvm_file_workdir as *const () as u64,
         "kclvm_free" => crate::kclvm_free as *const () as u64,
         "kclvm_iterator_cur_key" => crate::kclvm_iterator_cur_key as *const () as u64,
         "kclvm_iterator_cur_value" => crate::kclvm_iterator_cur_value as *const () as u64,
@@ -203,8 +222,11 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_math_modf" => crate::kclvm_math_modf as *const () as u64,
         "kclvm_math_pow" => crate::kclvm_math_pow as *const () as u64,
         "kclvm_math_sqrt" => crate::kclvm_math_sqrt as *const () as u64,
+        "kclvm_net_CIDR_contains" => crate::kclvm_net_CIDR_contains as *const () as u64,
+        "kclvm_net_CIDR_overlaps" => crate::kclvm_net_CIDR_overlaps as *const () as u64,
         "kclvm_net_IP_string" => crate::kclvm_net_IP_string as *const () as u64,
         "kclvm_net_fqdn" => crate::kclvm_net_fqdn as *const () as u64,
+        "kclvm_net_host_count" => crate::kclvm_net_host_count as *const () as u64,
         "kclvm_net_is_IP" => crate::kclvm_net_is_IP as *const () as u64,
         "kclvm_net_is_IPv4" => crate::kclvm_net_is_IPv4 as *const () as u64,
         "kclvm_net_is_global_unicast_IP" => {
@@ -223,8 +245,10 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_net_is_multicast_IP" => crate::kclvm_net_is_multicast_IP as *const () as u64,
         "kclvm_net_is_unspecified_IP" => crate::kclvm_net_is_unspecified_IP as *const () as u64,
         "kclvm_net_join_host_port" => crate::kclvm_net_join_host_port as *const () as u64,
+        "kclvm_net_parse_CIDR" => crate::kclvm_net_parse_CIDR as *const () as u64,
         "kclvm_net_parse_IP" => crate::kclvm_net_parse_IP as *const () as u64,
         "kclvm_net_split_host_port" => crate::kclvm_net_split_host_port as *const () as u64,
+        "kclvm_net_subnets" => crate::kclvm_net_subnets as *const () as u64,
         "kclvm_net_to_IP16" => crate::kclvm_net_to_IP16 as *const () as u64,
         "kclvm_net_to_IP4" => crate::kclvm_net_to_IP4 as *const () as u64,
         "kclvm_plugin_init" => crate::kclvm_plugin_init as *const () as u64,
@@ -232,7 +256,9 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_plugin_invoke_json" => crate::kclvm_plugin_invoke_json as *const () as u64,
         "kclvm_regex_compile" => crate::kclvm_regex_compile as *const () as u64,
         "kclvm_regex_findall" => crate::kclvm_regex_findall as *const () as u64,
+        "kclvm_regex_groups" => crate::kclvm_regex_groups as *const () as u64,
         "kclvm_regex_match" => crate::kclvm_regex_match as *const () as u64,
+        "kclvm_regex_named_groups" => crate::kclvm_regex_named_groups as *const () as u64,
         "kclvm_regex_replace" => crate::kclvm_regex_replace as *const () as u64,
         "kclvm_regex_search" => crate::kclvm_regex_search as *const () as u64,
         "kclvm_regex_split" => crate::kclvm_regex_split as *const () as u64,
@@ -247,7 +273,13 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_schema_optional_check" => crate::kclvm_schema_optional_check as *const () as u64,
         "kclvm_schema_value_check" => crate::kclvm_schema_value_check as *const () as u64,
         "kclvm_schema_value_new" => crate::kclvm_schema_value_new as *const () as u64,
+        "kclvm_semver_bump" => crate::kclvm_semver_bump as *const () as u64,
+        "kclvm_semver_compare" => crate::kclvm_semver_compare as *const () as u64,
+        "kclvm_semver_parse" => crate::kclvm_semver_parse as *const () as u64,
+        "kclvm_semver_satisfies" => crate::kclvm_semver_satisfies as *const () as u64,
         "kclvm_strlen" => crate::kclvm_strlen as *const () as u64,
+        "kclvm_template_execute" => crate::kclvm_template_execute as *const () as u64,
+        "kclvm_template_html_escape" => crate::kclvm_template_html_escape as *const () as u64,
         "kclvm_testing_arguments" => crate::kclvm_testing_arguments as *const () as u64,
         "kclvm_testing_setting_file" => crate::kclvm_testing_setting_file as *const () as u64,
         "kclvm_type_BoolLit_value" => crate::kclvm_type_BoolLit_value as *const () as u64,
@@ -396,8 +428,10 @@ pub fn _kclvm_get_fn_ptr_by_name(name: &str) -> u64 {
         "kclvm_value_union" => crate::kclvm_value_union as *const () as u64,
         "kclvm_value_union_all" => crate::kclvm_value_union_all as *const () as u64,
         "kclvm_yaml_decode" => crate::kclvm_yaml_decode as *const () as u64,
+        "kclvm_yaml_decode_all" => crate::kclvm_yaml_decode_all as *const () as u64,
         "kclvm_yaml_dump_to_file" => crate::kclvm_yaml_dump_to_file as *const () as u64,
         "kclvm_yaml_encode" => crate::kclvm_yaml_encode as *const () as u64,
+        "kclvm_yaml_encode_all" => crate::kclvm_yaml_encode_all as *const () as u64,
         _ => panic!("unknown {}", name),
     }
 }
@@ -2,7 +2,7 @@
 
 #[allow(non_camel_case_types)]
 type kclvm_value_ref_t = crate::ValueRef;
-use crate::{get_ref_mut, new_mut_ptr, IndexMap};
+use crate::{get_ref_mut, new_mut_ptr, IndexMap, RegexCache};
 use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -374,6 +374,8 @@ pub struct Context {
     pub buffer: ContextBuffer,
     /// objects is to store all KCL object pointers.
     pub objects: IndexSet<usize>,
+    /// regex_cache is to store the compiled regex patterns used by the regex system module.
+    pub regex_cache: RegexCache,
 }
 
 impl Context {
@@ -1,6 +1,6 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 
-use crate::{kclvm_value_Undefined, Context, ValueRef};
+use crate::{kclvm_value_Undefined, Context, ErrType, ValueRef};
 
 /// New a mutable raw pointer.
 pub fn new_mut_ptr(x: ValueRef) -> *mut ValueRef {
@@ -74,6 +74,14 @@ pub fn convert_double_pointer_to_vec(data: &mut &mut i8, len: usize) -> Vec<Stri
     }
 }
 
+/// Raise the runtime value error with the message e.g., on the invalid argument value.
+pub fn panic_value_error(msg: &str) -> ! {
+    let ctx = Context::current_context_mut();
+    ctx.set_err_type(&ErrType::ValueError_TYPE);
+
+    panic!("{}", msg)
+}
+
 pub fn assert_panic<F: FnOnce() -> () + std::panic::UnwindSafe>(msg: &str, func: F) {
     match std::panic::catch_unwind(func) {
         Ok(_v) => {
@@ -1,6 +1,8 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
+extern crate base32;
 extern crate base64;
-use base64::{decode, encode};
+extern crate hex;
+use base64::{decode, decode_config, encode, encode_config, URL_SAFE};
 
 use crate::*;
 
@@ -12,10 +14,14 @@ type kclvm_value_ref_t = ValueRef;
 pub extern "C" fn kclvm_base64_encode(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
-    let p = args.arg_0().unwrap();
+    let kwargs = ptr_as_ref(kwargs);
+    let p = match args.arg_i(0).or_else(|| kwargs.kwarg("value")) {
+        Some(p) => p,
+        None => panic!("encode() missing 1 required positional argument: 'value'"),
+    };
     match &*p.rc {
         Value::str_value(x) => {
             let s = encode(x.clone());
@@ -35,10 +41,14 @@ pub extern "C" fn kclvm_base64_encode(
 pub extern "C" fn kclvm_base64_decode(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let args = ptr_as_ref(args);
-    let p = args.arg_0().unwrap();
+    let kwargs = ptr_as_ref(kwargs);
+    let p = match args.arg_i(0).or_else(|| kwargs.kwarg("value")) {
+        Some(p) => p,
+        None => panic!("decode() missing 1 required positional argument: 'value'"),
+    };
     match &*p.rc {
         Value::str_value(x) => {
             let de_str = decode(x.clone()).unwrap();
@@ -55,3 +65,136 @@ pub extern "C" fn kclvm_base64_decode(
         }
     }
 }
+
+// urlsafe_encode(value: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_base64_urlsafe_encode(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let x = str_arg(args, kwargs, "urlsafe_encode");
+    let s = encode_config(x, URL_SAFE);
+    ValueRef::str(s.as_str()).into_raw()
+}
+
+// urlsafe_decode(value: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_base64_urlsafe_decode(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let x = str_arg(args, kwargs, "urlsafe_decode");
+    let de_str = decode_config(&x, URL_SAFE).ok();
+    decoded_str(de_str, "base64")
+}
+
+// base32_encode(value: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_base64_base32_encode(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let x = str_arg(args, kwargs, "base32_encode");
+    let s = base32::encode(base32::Alphabet::RFC4648 { padding: true }, x.as_bytes());
+    ValueRef::str(s.as_str()).into_raw()
+}
+
+// base32_decode(value: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_base64_base32_decode(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let x = str_arg(args, kwargs, "base32_decode");
+    let de_str = base32::decode(base32::Alphabet::RFC4648 { padding: true }, &x);
+    decoded_str(de_str, "base32")
+}
+
+// hex_encode(value: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_base64_hex_encode(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let x = str_arg(args, kwargs, "hex_encode");
+    let s = hex::encode(x);
+    ValueRef::str(s.as_str()).into_raw()
+}
+
+// hex_decode(value: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_base64_hex_decode(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+    let x = str_arg(args, kwargs, "hex_decode");
+    let de_str = hex::decode(&x).ok();
+    decoded_str(de_str, "hex")
+}
+
+fn str_arg(args: &ValueRef, kwargs: &ValueRef, name: &str) -> String {
+    match args.arg_i(0).or_else(|| kwargs.kwarg("value")) {
+        Some(p) => match &*p.rc {
+            Value::str_value(x) => x.clone(),
+            _ => {
+                let ctx = Context::current_context_mut();
+                ctx.set_err_type(&ErrType::TypeError_Runtime_TYPE);
+
+                panic!(
+                    "{}() argument should be a str, not '{}'",
+                    name,
+                    p.type_str()
+                );
+            }
+        },
+        None => panic!("{}() missing 1 required positional argument: 'value'", name),
+    }
+}
+
+fn decoded_str(bytes: Option<Vec<u8>>, encoding: &str) -> *const kclvm_value_ref_t {
+    match bytes.map(String::from_utf8) {
+        Some(Ok(s)) => ValueRef::str(s.as_str()).into_raw(),
+        Some(Err(_)) => {
+            let ctx = Context::current_context_mut();
+            ctx.set_err_type(&ErrType::ValueError_TYPE);
+
+            panic!("the {} decoded value is not a valid UTF-8 string", encoding);
+        }
+        None => {
+            let ctx = Context::current_context_mut();
+            ctx.set_err_type(&ErrType::ValueError_TYPE);
+
+            panic!("invalid {} encoded string", encoding);
+        }
+    }
+}
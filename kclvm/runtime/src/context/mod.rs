@@ -69,10 +69,22 @@ impl crate::Context {
 
     pub fn set_kcl_filename(&mut self, file: &str) {
         if !file.is_empty() {
+            self.set_main_pkg_path(file);
             self.panic_info.kcl_file = file.to_string();
         }
     }
 
+    /// The first executed file is the entry file of the main package, whose
+    /// directory is recorded as the main package path.
+    fn set_main_pkg_path(&mut self, file: &str) {
+        if self.main_pkg_path.is_empty() {
+            self.main_pkg_path = match std::path::Path::new(file).parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir.to_string_lossy().to_string(),
+                _ => ".".to_string(),
+            };
+        }
+    }
+
     pub fn set_kcl_line_col(&mut self, line: i32, col: i32) {
         self.panic_info.kcl_line = line;
         self.panic_info.kcl_col = col;
@@ -89,6 +101,7 @@ impl crate::Context {
             self.panic_info.kcl_arg_msg = s.to_string();
         }
         if let Some(s) = file {
+            self.set_main_pkg_path(s);
             self.panic_info.kcl_file = s.to_string();
         }
         if let Some(line) = line {
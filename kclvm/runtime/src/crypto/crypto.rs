@@ -2,11 +2,17 @@
 //!
 //! Copyright 2021 The KCL Authors. All rights reserved.
 
+extern crate blake3;
+extern crate hex;
+extern crate hmac;
 extern crate md5;
 extern crate sha1;
 extern crate sha2;
+extern crate uuid;
 
+use hmac::{Hmac, Mac, NewMac};
 use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use uuid::Uuid;
 
 use crate::*;
 
@@ -20,11 +26,15 @@ type kclvm_value_ref_t = ValueRef;
 pub extern "C" fn kclvm_crypto_md5(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(s) = args.arg_i_str(0, None) {
+    if let Some(s) = args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
         let hex = format!("{:x}", md5::compute(&s));
         return ValueRef::str(hex.as_ref()).into_raw();
     }
@@ -38,11 +48,15 @@ pub extern "C" fn kclvm_crypto_md5(
 pub extern "C" fn kclvm_crypto_sha1(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(s) = args.arg_i_str(0, None) {
+    if let Some(s) = args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
         let hex = sha1::Sha1::from(s).digest().to_string();
         return ValueRef::str(hex.as_ref()).into_raw();
     }
@@ -56,11 +70,15 @@ pub extern "C" fn kclvm_crypto_sha1(
 pub extern "C" fn kclvm_crypto_sha224(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(s) = args.arg_i_str(0, None) {
+    if let Some(s) = args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
         let mut hasher = Sha224::new();
         hasher.update(&s);
         let result = hasher.finalize();
@@ -84,11 +102,15 @@ pub extern "C" fn kclvm_crypto_sha224(
 pub extern "C" fn kclvm_crypto_sha256(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(s) = args.arg_i_str(0, None) {
+    if let Some(s) = args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
         let mut hasher = Sha256::new();
         hasher.update(&s);
         let result = hasher.finalize();
@@ -112,11 +134,15 @@ pub extern "C" fn kclvm_crypto_sha256(
 pub extern "C" fn kclvm_crypto_sha384(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(s) = args.arg_i_str(0, None) {
+    if let Some(s) = args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
         let mut hasher = Sha384::new();
         hasher.update(&s);
         let result = hasher.finalize();
@@ -140,11 +166,15 @@ pub extern "C" fn kclvm_crypto_sha384(
 pub extern "C" fn kclvm_crypto_sha512(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *const kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
-    if let Some(s) = args.arg_i_str(0, None) {
+    if let Some(s) = args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
         let mut hasher = Sha512::new();
         hasher.update(&s);
         let result = hasher.finalize();
@@ -160,3 +190,159 @@ pub extern "C" fn kclvm_crypto_sha512(
     }
     panic!("sha512() missing 1 required positional argument: 'value'");
 }
+
+// uuid(version: int = 4, name: str = None, namespace: str = "dns", seed: int|str = None) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_uuid(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let version = args
+        .arg_i_int(0, None)
+        .or_else(|| kwargs.kwarg_int("version", None))
+        .unwrap_or(4);
+    let uuid = match version {
+        4 => match args.arg_i(3).or_else(|| kwargs.kwarg("seed")) {
+            Some(seed) if !seed.is_none_or_undefined() => {
+                let seed = match &*seed.rc {
+                    Value::str_value(s) => s.clone(),
+                    Value::int_value(v) => v.to_string(),
+                    _ => panic_value_error(&format!(
+                        "uuid() seed must be an int or a str, not '{}'",
+                        seed.type_str()
+                    )),
+                };
+                seeded_uuid_v4(&seed)
+            }
+            _ => Uuid::new_v4(),
+        },
+        5 => {
+            let name = match args
+                .arg_i_str(1, None)
+                .or_else(|| kwargs.kwarg_str("name", None))
+            {
+                Some(name) => name,
+                None => panic_value_error("uuid() version 5 requires the argument 'name'"),
+            };
+            let namespace = args
+                .arg_i_str(2, None)
+                .or_else(|| kwargs.kwarg_str("namespace", None))
+                .unwrap_or_else(|| "dns".to_string());
+            match uuid_namespace(&namespace) {
+                Some(namespace) => Uuid::new_v5(&namespace, name.as_bytes()),
+                None => panic_value_error(&format!("invalid uuid namespace '{}'", namespace)),
+            }
+        }
+        _ => panic_value_error(&format!(
+            "unsupported uuid version {}, expected 4 or 5",
+            version
+        )),
+    };
+    ValueRef::str(uuid.to_string().as_ref()).into_raw()
+}
+
+// hmac_sha256(value: str, key: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_hmac_sha256(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let value = match args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
+        Some(value) => value,
+        None => panic!("hmac_sha256() missing 1 required positional argument: 'value'"),
+    };
+    let key = match args
+        .arg_i_str(1, None)
+        .or_else(|| kwargs.kwarg_str("key", None))
+    {
+        Some(key) => key,
+        None => panic!("hmac_sha256() missing 1 required positional argument: 'key'"),
+    };
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(value.as_bytes());
+    let hex = hex::encode(mac.finalize().into_bytes());
+    ValueRef::str(hex.as_ref()).into_raw()
+}
+
+// blake3(value: str) -> str
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_crypto_blake3(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    if let Some(s) = args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("value", None))
+    {
+        let hex = blake3::hash(s.as_bytes()).to_hex().to_string();
+        return ValueRef::str(hex.as_ref()).into_raw();
+    }
+    panic!("blake3() missing 1 required positional argument: 'value'");
+}
+
+/// Build a random UUID version 4 from the SHA-256 digest of the seed,
+/// the same seed always generates the same UUID.
+fn seeded_uuid_v4(seed: &str) -> Uuid {
+    let digest = Sha256::digest(seed.as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    uuid::Builder::from_random_bytes(bytes).into_uuid()
+}
+
+/// Get the UUID namespace from the well-known namespace name e.g., "dns"
+/// or the UUID string.
+fn uuid_namespace(namespace: &str) -> Option<Uuid> {
+    match namespace.to_lowercase().as_str() {
+        "dns" => Some(Uuid::NAMESPACE_DNS),
+        "url" => Some(Uuid::NAMESPACE_URL),
+        "oid" => Some(Uuid::NAMESPACE_OID),
+        "x500" => Some(Uuid::NAMESPACE_X500),
+        _ => Uuid::parse_str(namespace).ok(),
+    }
+}
+
+#[cfg(test)]
+mod test_crypto {
+    use super::*;
+
+    #[test]
+    fn test_seeded_uuid_v4() {
+        let uuid = seeded_uuid_v4("kcl");
+        assert_eq!(uuid, seeded_uuid_v4("kcl"));
+        assert_ne!(uuid, seeded_uuid_v4("kcl-lang"));
+        assert_eq!(uuid.get_version_num(), 4);
+    }
+
+    #[test]
+    fn test_uuid_namespace() {
+        assert_eq!(uuid_namespace("dns"), Some(Uuid::NAMESPACE_DNS));
+        assert_eq!(uuid_namespace("URL"), Some(Uuid::NAMESPACE_URL));
+        assert_eq!(
+            uuid_namespace("6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+            Some(Uuid::NAMESPACE_DNS)
+        );
+        assert_eq!(uuid_namespace("invalid"), None);
+    }
+}
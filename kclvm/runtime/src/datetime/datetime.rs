@@ -4,7 +4,9 @@
 
 extern crate chrono;
 
+use chrono::format::{Item, StrftimeItems};
 use chrono::prelude::Local;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone};
 
 use crate::*;
 
@@ -62,3 +64,359 @@ pub extern "C" fn kclvm_datetime_date(
     let s = Local::today().to_string();
     return ValueRef::str(s.as_ref()).into_raw();
 }
+
+/// The default strftime format of the datetime string.
+const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+// def KMANGLED_parse(s: str, fmt: str = "%Y-%m-%d %H:%M:%S", tz: str = "UTC") -> float:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_parse(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let s = match arg_str(args, kwargs, 0, "s") {
+        Some(s) => s,
+        None => panic!("parse() missing 1 required positional argument: 's'"),
+    };
+    let fmt =
+        arg_str(args, kwargs, 1, "fmt").unwrap_or_else(|| DEFAULT_DATETIME_FORMAT.to_string());
+    let tz = timezone_arg(args, kwargs, 2);
+    match parse_datetime(&s, &fmt, &tz) {
+        Ok(ts) => ValueRef::float(ts).into_raw(),
+        Err(err) => panic_value_error(&format!(
+            "failed to parse the datetime '{}' with the format '{}': {}",
+            s, fmt, err
+        )),
+    }
+}
+
+// def KMANGLED_format(ts: float, fmt: str = "%Y-%m-%d %H:%M:%S", tz: str = "UTC") -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_format(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let ts = match arg_num(args, kwargs, 0, "ts") {
+        Some(ts) => ts,
+        None => panic!("format() missing 1 required positional argument: 'ts'"),
+    };
+    let fmt =
+        arg_str(args, kwargs, 1, "fmt").unwrap_or_else(|| DEFAULT_DATETIME_FORMAT.to_string());
+    let tz = timezone_arg(args, kwargs, 2);
+    match format_timestamp(ts, &fmt, &tz) {
+        Ok(s) => ValueRef::str(s.as_ref()).into_raw(),
+        Err(err) => panic_value_error(&format!(
+            "failed to format the time {} with the format '{}': {}",
+            ts, fmt, err
+        )),
+    }
+}
+
+// def KMANGLED_validate(s: str, fmt: str = "%Y-%m-%d %H:%M:%S") -> bool:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_validate(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let s = match arg_str(args, kwargs, 0, "s") {
+        Some(s) => s,
+        None => panic!("validate() missing 1 required positional argument: 's'"),
+    };
+    let fmt =
+        arg_str(args, kwargs, 1, "fmt").unwrap_or_else(|| DEFAULT_DATETIME_FORMAT.to_string());
+    let tz = TimeZoneSpec::Fixed(FixedOffset::east(0));
+    ValueRef::bool(parse_datetime(&s, &fmt, &tz).is_ok()).into_raw()
+}
+
+// def KMANGLED_duration(s: str) -> float:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_duration(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let s = match arg_str(args, kwargs, 0, "s") {
+        Some(s) => s,
+        None => panic!("duration() missing 1 required positional argument: 's'"),
+    };
+    match parse_duration(&s) {
+        Ok(seconds) => ValueRef::float(seconds).into_raw(),
+        Err(err) => panic_value_error(&err),
+    }
+}
+
+// def KMANGLED_add(ts: float, duration: str|float) -> float:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_datetime_add(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let ts = match arg_num(args, kwargs, 0, "ts") {
+        Some(ts) => ts,
+        None => panic!("add() missing 1 required positional argument: 'ts'"),
+    };
+    let seconds = match arg_str(args, kwargs, 1, "duration") {
+        Some(s) => match parse_duration(&s) {
+            Ok(seconds) => seconds,
+            Err(err) => panic_value_error(&err),
+        },
+        None => match arg_num(args, kwargs, 1, "duration") {
+            Some(seconds) => seconds,
+            None => panic!("add() missing 1 required positional argument: 'duration'"),
+        },
+    };
+    ValueRef::float(ts + seconds).into_raw()
+}
+
+/// The timezone of the datetime string without the offset or the formatted datetime string.
+enum TimeZoneSpec {
+    Local,
+    Fixed(FixedOffset),
+    Named(chrono_tz::Tz),
+}
+
+impl TimeZoneSpec {
+    /// Parse the timezone e.g., "UTC", "Local", "+08:00" and "Asia/Shanghai".
+    fn parse(tz: &str) -> Option<Self> {
+        match tz {
+            "" | "UTC" | "utc" | "Z" => Some(TimeZoneSpec::Fixed(FixedOffset::east(0))),
+            "Local" | "local" => Some(TimeZoneSpec::Local),
+            _ => parse_fixed_offset(tz)
+                .map(TimeZoneSpec::Fixed)
+                .or_else(|| tz.parse::<chrono_tz::Tz>().ok().map(TimeZoneSpec::Named)),
+        }
+    }
+
+    fn offset_from_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            TimeZoneSpec::Local => Local.offset_from_utc_datetime(utc),
+            TimeZoneSpec::Fixed(offset) => *offset,
+            TimeZoneSpec::Named(tz) => tz.offset_from_utc_datetime(utc).fix(),
+        }
+    }
+
+    fn offset_from_local(&self, local: &NaiveDateTime) -> Option<FixedOffset> {
+        match self {
+            TimeZoneSpec::Local => Local.offset_from_local_datetime(local).earliest(),
+            TimeZoneSpec::Fixed(offset) => Some(*offset),
+            TimeZoneSpec::Named(tz) => tz
+                .offset_from_local_datetime(local)
+                .earliest()
+                .map(|offset| offset.fix()),
+        }
+    }
+}
+
+/// Parse the fixed offset e.g., "+08:00", "-0530" and "+08".
+fn parse_fixed_offset(tz: &str) -> Option<FixedOffset> {
+    let sign = match tz.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits = tz[1..].replace(':', "");
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Parse the datetime string and return the time in seconds since the Epoch, the
+/// datetime without the offset is in the timezone `tz`.
+fn parse_datetime(s: &str, fmt: &str, tz: &TimeZoneSpec) -> Result<f64, String> {
+    if let Ok(datetime) = DateTime::parse_from_str(s, fmt) {
+        return Ok(timestamp(&datetime.naive_utc()));
+    }
+    let local = match NaiveDateTime::parse_from_str(s, fmt) {
+        Ok(local) => local,
+        Err(err) => match NaiveDate::parse_from_str(s, fmt) {
+            Ok(date) => date.and_hms(0, 0, 0),
+            Err(_) => return Err(err.to_string()),
+        },
+    };
+    match tz.offset_from_local(&local) {
+        Some(offset) => Ok(timestamp(&(local - offset))),
+        None => Err("the datetime does not exist in the timezone".to_string()),
+    }
+}
+
+/// Format the time in seconds since the Epoch in the timezone `tz`.
+fn format_timestamp(ts: f64, fmt: &str, tz: &TimeZoneSpec) -> Result<String, String> {
+    let items = StrftimeItems::new(fmt).collect::<Vec<Item>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err("invalid format".to_string());
+    }
+    if !ts.is_finite() {
+        return Err("the time is not a finite number".to_string());
+    }
+    let secs = ts.floor();
+    let nsecs = (((ts - secs) * 1e9).round() as u32).min(999_999_999);
+    let utc = match NaiveDateTime::from_timestamp_opt(secs as i64, nsecs) {
+        Some(utc) => utc,
+        None => return Err("out of range".to_string()),
+    };
+    let datetime = DateTime::<FixedOffset>::from_utc(utc, tz.offset_from_utc(&utc));
+    Ok(datetime.format_with_items(items.into_iter()).to_string())
+}
+
+/// Returns the time in seconds since the Epoch of the UTC datetime.
+fn timestamp(utc: &NaiveDateTime) -> f64 {
+    utc.timestamp() as f64 + utc.timestamp_subsec_nanos() as f64 / 1e9
+}
+
+/// Parse the duration string e.g., "1h30m", "1.5d" and "-90s", and return the
+/// duration in seconds.
+fn parse_duration(s: &str) -> Result<f64, String> {
+    let invalid = || format!("invalid duration '{}'", s);
+    let (sign, mut rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if rest == "0" {
+        return Ok(0.0);
+    }
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+    let mut seconds = 0.0;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_len].parse().map_err(|_| invalid())?;
+        rest = &rest[number_len..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let unit = match &rest[..unit_len] {
+            "ns" => 1e-9,
+            "us" | "µs" => 1e-6,
+            "ms" => 1e-3,
+            "s" => 1.0,
+            "m" => 60.0,
+            "h" => 3600.0,
+            "d" => 86400.0,
+            "w" => 604800.0,
+            _ => return Err(invalid()),
+        };
+        seconds += number * unit;
+        rest = &rest[unit_len..];
+    }
+    Ok(sign * seconds)
+}
+
+fn arg_str(args: &ValueRef, kwargs: &ValueRef, i: usize, name: &str) -> Option<String> {
+    args.arg_i_str(i, None)
+        .or_else(|| kwargs.kwarg_str(name, None))
+}
+
+fn arg_num(args: &ValueRef, kwargs: &ValueRef, i: usize, name: &str) -> Option<f64> {
+    args.arg_i_num(i, None)
+        .or_else(|| match kwargs.kwarg(name).map(|x| &*x.rc) {
+            Some(Value::int_value(v)) => Some(*v as f64),
+            Some(Value::float_value(v)) => Some(*v),
+            _ => None,
+        })
+}
+
+fn timezone_arg(args: &ValueRef, kwargs: &ValueRef, i: usize) -> TimeZoneSpec {
+    let tz = arg_str(args, kwargs, i, "tz").unwrap_or_default();
+    match TimeZoneSpec::parse(&tz) {
+        Some(tz) => tz,
+        None => panic_value_error(&format!("unknown timezone '{}'", tz)),
+    }
+}
+
+#[cfg(test)]
+mod test_datetime {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_datetime() {
+        let utc = TimeZoneSpec::parse("UTC").unwrap();
+        let shanghai = TimeZoneSpec::parse("Asia/Shanghai").unwrap();
+        let offset = TimeZoneSpec::parse("+08:00").unwrap();
+        let ts = parse_datetime("2022-01-02 03:04:05", DEFAULT_DATETIME_FORMAT, &utc).unwrap();
+        assert_eq!(ts, 1641092645.0);
+        assert_eq!(
+            parse_datetime("2022-01-02 11:04:05", DEFAULT_DATETIME_FORMAT, &shanghai).unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse_datetime("2022-01-02T11:04:05+08:00", "%Y-%m-%dT%H:%M:%S%:z", &utc).unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse_datetime("2022-01-02", "%Y-%m-%d", &utc).unwrap(),
+            1641081600.0
+        );
+        assert!(parse_datetime("2022-13-02", "%Y-%m-%d", &utc).is_err());
+        assert_eq!(
+            format_timestamp(ts, DEFAULT_DATETIME_FORMAT, &offset).unwrap(),
+            "2022-01-02 11:04:05"
+        );
+        assert_eq!(
+            format_timestamp(ts, "%Y-%m-%dT%H:%M:%S%:z", &shanghai).unwrap(),
+            "2022-01-02T11:04:05+08:00"
+        );
+        assert!(format_timestamp(f64::NAN, DEFAULT_DATETIME_FORMAT, &utc).is_err());
+        assert!(format_timestamp(f64::INFINITY, DEFAULT_DATETIME_FORMAT, &utc).is_err());
+        assert!(format_timestamp(ts, "%Q", &utc).is_err());
+        assert!(TimeZoneSpec::parse("Mars/Base").is_none());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let cases = [
+            ("0", 0.0),
+            ("90s", 90.0),
+            ("1h30m", 5400.0),
+            ("1.5d", 129600.0),
+            ("-2w", -1209600.0),
+            ("500ms", 0.5),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(parse_duration(s), Ok(expected));
+        }
+        for s in ["", "1", "1y", "h", "1..5s"] {
+            assert!(parse_duration(s).is_err(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! KCL file system module
+//!
+//! The file access is restricted to the module root, which is the directory of
+//! the nearest `kcl.mod` file of the running KCL file or the directory of the
+//! entry KCL file when there is no `kcl.mod` file. The root can be configured
+//! e.g., widened to a parent directory with the `KCLVM_FILE_ROOT` environment
+//! variable.
+//!
+//! Copyright 2021 The KCL Authors. All rights reserved.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::*;
+
+#[allow(non_camel_case_types)]
+type kclvm_value_ref_t = ValueRef;
+
+const KCL_MOD_FILE: &str = "kcl.mod";
+/// The environment variable of the configured module root, relative paths are
+/// relative to the work directory.
+pub const KCLVM_FILE_ROOT_ENV: &str = "KCLVM_FILE_ROOT";
+
+// def KMANGLED_read(filepath: str) -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_file_read(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if let Some(filepath) = args.arg_i_str(0, None) {
+        let path = sandbox_path(&filepath);
+        match std::fs::read_to_string(&path) {
+            Ok(content) => return ValueRef::str(content.as_ref()).into_raw(),
+            Err(err) => {
+                let ctx = Context::current_context_mut();
+                ctx.set_err_type(&ErrType::EvaluationError_TYPE);
+
+                panic!("failed to read the file '{}': {}", filepath, err)
+            }
+        }
+    }
+    panic!("read() missing 1 required positional argument: 'filepath'")
+}
+
+// def KMANGLED_glob(pattern: str) -> [str]:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_file_glob(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if let Some(pattern) = args.arg_i_str(0, None) {
+        if Path::new(&pattern).has_root() {
+            let ctx = Context::current_context_mut();
+            ctx.set_err_type(&ErrType::ValueError_TYPE);
+
+            panic!(
+                "invalid glob pattern '{}': the pattern must be relative to the module root",
+                pattern
+            )
+        }
+        let root = module_root();
+        // The root is matched literally, only the pattern has the glob syntax.
+        let full_pattern = format!(
+            "{}{}{}",
+            glob::Pattern::escape(&root.to_string_lossy()),
+            std::path::MAIN_SEPARATOR,
+            pattern
+        );
+        let paths = match glob::glob(&full_pattern) {
+            Ok(paths) => paths,
+            Err(err) => {
+                let ctx = Context::current_context_mut();
+                ctx.set_err_type(&ErrType::ValueError_TYPE);
+
+                panic!("invalid glob pattern '{}': {}", pattern, err)
+            }
+        };
+        let mut list = ValueRef::list(None);
+        for path in paths.flatten() {
+            // Skip the matched files outside of the module root e.g., the pattern `../*`.
+            let path = normalize_path(&path);
+            if !is_in_root(&path, &root) {
+                continue;
+            }
+            let path = path.strip_prefix(&root).unwrap_or(&path);
+            list.list_append(&ValueRef::str(path.to_string_lossy().as_ref()));
+        }
+        return list.into_raw();
+    }
+    panic!("glob() missing 1 required positional argument: 'pattern'")
+}
+
+// def KMANGLED_exists(filepath: str) -> bool:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_file_exists(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if let Some(filepath) = args.arg_i_str(0, None) {
+        let path = sandbox_path(&filepath);
+        return ValueRef::bool(path.exists()).into_raw();
+    }
+    panic!("exists() missing 1 required positional argument: 'filepath'")
+}
+
+// def KMANGLED_modpath() -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_file_modpath(
+    _ctx: *mut kclvm_context_t,
+    _args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    ValueRef::str(module_root().to_string_lossy().as_ref()).into_raw()
+}
+
+// def KMANGLED_workdir() -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_file_workdir(
+    _ctx: *mut kclvm_context_t,
+    _args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    ValueRef::str(work_dir().to_string_lossy().as_ref()).into_raw()
+}
+
+fn work_dir() -> PathBuf {
+    std::env::current_dir().unwrap_or_default()
+}
+
+/// Returns the configured module root, the directory of the nearest `kcl.mod`
+/// file of the running KCL file, or the directory of the entry KCL file when
+/// there is no `kcl.mod` file.
+fn module_root() -> PathBuf {
+    let work_dir = work_dir();
+    if let Some(root) = std::env::var_os(KCLVM_FILE_ROOT_ENV).filter(|root| !root.is_empty()) {
+        return normalize_path(&work_dir.join(root));
+    }
+    let ctx = Context::current_context();
+    let filename = &ctx.panic_info.kcl_file;
+    if !filename.is_empty() {
+        let filepath = normalize_path(&work_dir.join(filename));
+        for dir in filepath.ancestors().skip(1) {
+            if dir.join(KCL_MOD_FILE).is_file() {
+                return dir.to_path_buf();
+            }
+        }
+    }
+    if !ctx.main_pkg_path.is_empty() {
+        return normalize_path(&work_dir.join(&ctx.main_pkg_path));
+    }
+    work_dir
+}
+
+/// Returns the path of the file relative to the module root and raises an
+/// error when the path is outside of the module root.
+fn sandbox_path(filepath: &str) -> PathBuf {
+    let root = module_root();
+    let path = normalize_path(&root.join(filepath));
+    if !is_in_root(&path, &root) {
+        let ctx = Context::current_context_mut();
+        ctx.set_err_type(&ErrType::EvaluationError_TYPE);
+
+        panic!(
+            "the file '{}' is outside of the module root '{}'",
+            filepath,
+            root.display()
+        )
+    }
+    path
+}
+
+/// Whether the path is in the module root, the symbolic links are resolved when the path exists.
+fn is_in_root(path: &Path, root: &Path) -> bool {
+    match (path.canonicalize(), root.canonicalize()) {
+        (Ok(path), Ok(root)) => path.starts_with(root),
+        _ => path.starts_with(root),
+    }
+}
+
+/// Normalize the `.` and `..` components of the path without accessing the file system.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod test_file {
+    use super::*;
+
+    #[test]
+    fn test_module_root() {
+        let root = std::env::temp_dir().join("kclvm_test_file_module_root");
+        let pkg = root.join("pkg");
+        std::fs::create_dir_all(&pkg).unwrap();
+        let ctx = Context::current_context_mut();
+        ctx.main_pkg_path.clear();
+        // Without kcl.mod, the root is the directory of the entry file instead
+        // of the directory of the running file.
+        ctx.set_kcl_filename(&root.join("main.k").to_string_lossy());
+        ctx.set_kcl_filename(&pkg.join("pkg.k").to_string_lossy());
+        assert_eq!(module_root(), root);
+        std::env::set_var(KCLVM_FILE_ROOT_ENV, &pkg);
+        assert_eq!(module_root(), pkg);
+        std::env::remove_var(KCLVM_FILE_ROOT_ENV);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        // The glob special characters in the root are matched literally.
+        let root = std::env::temp_dir().join("kclvm_test_file_[glob]");
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        std::fs::write(root.join("data").join("a.yaml"), "").unwrap();
+        std::env::set_var(KCLVM_FILE_ROOT_ENV, &root);
+        let glob = |pattern: &str| {
+            let args = ValueRef::list(Some(&[&ValueRef::str(pattern)]));
+            let kwargs = ValueRef::dict(None);
+            ptr_as_ref(kclvm_file_glob(std::ptr::null_mut(), &args, &kwargs)).clone()
+        };
+        let expected = Path::new("data").join("a.yaml");
+        assert_eq!(
+            glob("data/*.yaml"),
+            ValueRef::list_str(&[expected.to_string_lossy().to_string()])
+        );
+        std::env::remove_var(KCLVM_FILE_ROOT_ENV);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -80,6 +80,9 @@ pub use self::crypto::*;
 pub mod datetime;
 pub use self::datetime::*;
 
+pub mod file;
+pub use self::file::*;
+
 pub mod json;
 pub use self::json::*;
 
@@ -92,9 +95,15 @@ pub use self::net::*;
 pub mod regex;
 pub use self::regex::*;
 
+pub mod semver;
+pub use self::semver::*;
+
 pub mod stdlib;
 pub use self::stdlib::*;
 
+pub mod template;
+pub use self::template::*;
+
 pub mod testing;
 pub use self::testing::*;
 
@@ -2,6 +2,7 @@
 //!
 //! Copyright 2021 The KCL Authors. All rights reserved.
 
+use std::net::IpAddr;
 use std::net::Ipv4Addr;
 use std::net::Ipv6Addr;
 use std::str::FromStr;
@@ -416,3 +417,362 @@ pub extern "C" fn kclvm_net_is_unspecified_IP(
     }
     panic!("is_unspecified_IP() missing 1 required positional argument: 'ip'");
 }
+
+// parse_CIDR(cidr: str) -> {str:}
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_parse_CIDR(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let cidr = cidr_arg(args, kwargs, 0, "cidr", "parse_CIDR");
+    let mut result = ValueRef::dict(None);
+    result.dict_update_key_value("ip", ValueRef::str(cidr.ip.to_string().as_ref()));
+    result.dict_update_key_value("network", ValueRef::str(cidr.to_string().as_ref()));
+    result.dict_update_key_value("prefix", ValueRef::int(cidr.prefix as i64));
+    result.dict_update_key_value(
+        "netmask",
+        ValueRef::str(cidr.to_ip(cidr.netmask()).to_string().as_ref()),
+    );
+    result.dict_update_key_value(
+        "first",
+        ValueRef::str(cidr.to_ip(cidr.first()).to_string().as_ref()),
+    );
+    result.dict_update_key_value(
+        "last",
+        ValueRef::str(cidr.to_ip(cidr.last()).to_string().as_ref()),
+    );
+    result.dict_update_key_value("version", ValueRef::int(cidr.version()));
+    result.into_raw()
+}
+
+// CIDR_contains(cidr: str, ip: str) -> bool
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_contains(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let cidr = cidr_arg(args, kwargs, 0, "cidr", "CIDR_contains");
+    let ip = match args
+        .arg_i_str(1, None)
+        .or_else(|| kwargs.kwarg_str("ip", None))
+    {
+        Some(ip) => ip,
+        None => panic!("CIDR_contains() missing 1 required positional argument: 'ip'"),
+    };
+    // The IP address or the CIDR subnet is contained in the CIDR.
+    let other = match ip.parse::<IpAddr>() {
+        Ok(addr) => Cidr::new(addr, Cidr::max_prefix(&addr)),
+        Err(_) => match Cidr::parse(&ip) {
+            Some(other) => other,
+            None => panic_value_error(&format!("invalid IP or CIDR address '{}'", ip)),
+        },
+    };
+    kclvm_value_Bool(cidr.contains(&other) as i8)
+}
+
+// CIDR_overlaps(a: str, b: str) -> bool
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_CIDR_overlaps(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let a = cidr_arg(args, kwargs, 0, "a", "CIDR_overlaps");
+    let b = cidr_arg(args, kwargs, 1, "b", "CIDR_overlaps");
+    kclvm_value_Bool(a.overlaps(&b) as i8)
+}
+
+// subnets(cidr: str, new_prefix: int) -> List[str]
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_subnets(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let cidr = cidr_arg(args, kwargs, 0, "cidr", "subnets");
+    let new_prefix = match args
+        .arg_i_int(1, None)
+        .or_else(|| kwargs.kwarg_int("new_prefix", None))
+    {
+        Some(new_prefix) => new_prefix,
+        None => panic!("subnets() missing 1 required positional argument: 'new_prefix'"),
+    };
+    match cidr.subnets(new_prefix) {
+        Ok(subnets) => {
+            let mut list = ValueRef::list(None);
+            for subnet in subnets {
+                list.list_append(&ValueRef::str(subnet.to_string().as_ref()));
+            }
+            list.into_raw()
+        }
+        Err(err) => panic_value_error(&err),
+    }
+}
+
+// host_count(cidr: str) -> int
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_net_host_count(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let cidr = cidr_arg(args, kwargs, 0, "cidr", "host_count");
+    match cidr
+        .host_count()
+        .and_then(|count| i64::try_from(count).ok())
+    {
+        Some(count) => ValueRef::int(count).into_raw(),
+        None => panic_value_error(&format!(
+            "the host count of the CIDR '{}' overflows the int type",
+            cidr
+        )),
+    }
+}
+
+/// The maximum number of the subnets returned by `subnets()`.
+const MAX_SUBNETS: u32 = 1 << 16;
+
+/// The IPv4 or IPv6 CIDR block, the IP addresses are computed as `u128`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cidr {
+    ip: IpAddr,
+    prefix: u32,
+}
+
+impl Cidr {
+    fn new(ip: IpAddr, prefix: u32) -> Self {
+        Cidr { ip, prefix }
+    }
+
+    /// Parse the CIDR notation e.g., "10.0.0.0/16" or "2001:db8::/32".
+    fn parse(cidr: &str) -> Option<Self> {
+        let (ip, prefix) = cidr.trim().split_once('/')?;
+        let ip = IpAddr::from_str(ip).ok()?;
+        let prefix = prefix.parse::<u32>().ok()?;
+        if prefix > Cidr::max_prefix(&ip) {
+            return None;
+        }
+        Some(Cidr::new(ip, prefix))
+    }
+
+    fn max_prefix(ip: &IpAddr) -> u32 {
+        match ip {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+
+    fn version(&self) -> i64 {
+        match self.ip {
+            IpAddr::V4(_) => 4,
+            IpAddr::V6(_) => 6,
+        }
+    }
+
+    fn host_bits(&self) -> u32 {
+        Cidr::max_prefix(&self.ip) - self.prefix
+    }
+
+    fn hostmask(&self) -> u128 {
+        1u128
+            .checked_shl(self.host_bits())
+            .map(|v| v - 1)
+            .unwrap_or(u128::MAX)
+    }
+
+    fn netmask(&self) -> u128 {
+        let all = match self.ip {
+            IpAddr::V4(_) => u32::MAX as u128,
+            IpAddr::V6(_) => u128::MAX,
+        };
+        all & !self.hostmask()
+    }
+
+    fn first(&self) -> u128 {
+        let ip = match self.ip {
+            IpAddr::V4(ip) => u32::from(ip) as u128,
+            IpAddr::V6(ip) => u128::from(ip),
+        };
+        ip & self.netmask()
+    }
+
+    fn last(&self) -> u128 {
+        self.first() | self.hostmask()
+    }
+
+    fn to_ip(&self, v: u128) -> IpAddr {
+        match self.ip {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(v as u32)),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(v)),
+        }
+    }
+
+    fn is_same_version(&self, other: &Cidr) -> bool {
+        self.version() == other.version()
+    }
+
+    fn contains(&self, other: &Cidr) -> bool {
+        self.is_same_version(other)
+            && self.prefix <= other.prefix
+            && self.first() <= other.first()
+            && other.last() <= self.last()
+    }
+
+    fn overlaps(&self, other: &Cidr) -> bool {
+        self.is_same_version(other) && self.first() <= other.last() && other.first() <= self.last()
+    }
+
+    fn subnets(&self, new_prefix: i64) -> Result<Vec<Cidr>, String> {
+        let max_prefix = Cidr::max_prefix(&self.ip) as i64;
+        if new_prefix < self.prefix as i64 || new_prefix > max_prefix {
+            return Err(format!(
+                "invalid new prefix {} for the CIDR '{}', expected in the range [{}, {}]",
+                new_prefix, self, self.prefix, max_prefix
+            ));
+        }
+        let new_prefix = new_prefix as u32;
+        let count = match 1u32.checked_shl(new_prefix - self.prefix) {
+            Some(count) if count <= MAX_SUBNETS => count,
+            _ => {
+                return Err(format!(
+                    "too many subnets of the CIDR '{}' with the new prefix {}, the maximum is {}",
+                    self, new_prefix, MAX_SUBNETS
+                ))
+            }
+        };
+        let step = 1u128
+            .checked_shl(Cidr::max_prefix(&self.ip) - new_prefix)
+            .unwrap_or(0);
+        Ok((0..count as u128)
+            .map(|i| Cidr::new(self.to_ip(self.first() + i * step), new_prefix))
+            .collect())
+    }
+
+    /// The number of the host addresses, the network and broadcast addresses
+    /// of the IPv4 CIDR are excluded except the "/31" and "/32" CIDRs.
+    fn host_count(&self) -> Option<u128> {
+        let count = 1u128.checked_shl(self.host_bits())?;
+        match self.ip {
+            IpAddr::V4(_) if self.host_bits() >= 2 => Some(count - 2),
+            _ => Some(count),
+        }
+    }
+}
+
+impl std::fmt::Display for Cidr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.to_ip(self.first()), self.prefix)
+    }
+}
+
+fn cidr_arg(args: &ValueRef, kwargs: &ValueRef, i: usize, name: &str, func: &str) -> Cidr {
+    let cidr = match args
+        .arg_i_str(i, None)
+        .or_else(|| kwargs.kwarg_str(name, None))
+    {
+        Some(cidr) => cidr,
+        None => panic!(
+            "{}() missing 1 required positional argument: '{}'",
+            func, name
+        ),
+    };
+    match Cidr::parse(&cidr) {
+        Some(cidr) => cidr,
+        None => panic_value_error(&format!("invalid CIDR address '{}'", cidr)),
+    }
+}
+
+#[cfg(test)]
+mod test_net {
+    use super::*;
+
+    fn cidr(s: &str) -> Cidr {
+        Cidr::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_cidr() {
+        let v4 = cidr("10.1.2.3/16");
+        assert_eq!(v4.to_string(), "10.1.0.0/16");
+        assert_eq!(v4.to_ip(v4.netmask()).to_string(), "255.255.0.0");
+        assert_eq!(v4.to_ip(v4.last()).to_string(), "10.1.255.255");
+        let v6 = cidr("2001:db8::1/32");
+        assert_eq!(v6.to_string(), "2001:db8::/32");
+        assert_eq!(v6.to_ip(v6.netmask()).to_string(), "ffff:ffff::");
+        assert_eq!(
+            cidr("0.0.0.0/0")
+                .to_ip(cidr("0.0.0.0/0").last())
+                .to_string(),
+            "255.255.255.255"
+        );
+        assert_eq!(cidr("::/0").last(), u128::MAX);
+        assert!(Cidr::parse("10.0.0.0/33").is_none());
+        assert!(Cidr::parse("10.0.0.0").is_none());
+        assert!(Cidr::parse("10.0.0/8").is_none());
+    }
+
+    #[test]
+    fn test_cidr_contains_and_overlaps() {
+        assert!(cidr("10.0.0.0/8").contains(&cidr("10.1.0.0/16")));
+        assert!(!cidr("10.1.0.0/16").contains(&cidr("10.0.0.0/8")));
+        assert!(!cidr("10.0.0.0/8").contains(&cidr("::/0")));
+        assert!(cidr("10.0.0.0/8").overlaps(&cidr("10.1.0.0/16")));
+        assert!(!cidr("10.0.0.0/16").overlaps(&cidr("10.1.0.0/16")));
+        assert!(cidr("fd00::/8").overlaps(&cidr("fd12::/16")));
+    }
+
+    #[test]
+    fn test_cidr_subnets() {
+        let subnets: Vec<String> = cidr("10.0.0.0/22")
+            .subnets(24)
+            .unwrap()
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            subnets,
+            vec!["10.0.0.0/24", "10.0.1.0/24", "10.0.2.0/24", "10.0.3.0/24"]
+        );
+        assert_eq!(cidr("::/0").subnets(0).unwrap(), vec![cidr("::/0")]);
+        assert_eq!(cidr("2001:db8::/32").subnets(48).unwrap().len(), 1 << 16);
+        assert!(cidr("2001:db8::/32").subnets(49).is_err());
+        assert!(cidr("10.0.0.0/16").subnets(8).is_err());
+        assert!(cidr("10.0.0.0/16").subnets(33).is_err());
+    }
+
+    #[test]
+    fn test_cidr_host_count() {
+        assert_eq!(cidr("10.0.0.0/24").host_count(), Some(254));
+        assert_eq!(cidr("10.0.0.0/31").host_count(), Some(2));
+        assert_eq!(cidr("10.0.0.1/32").host_count(), Some(1));
+        assert_eq!(cidr("2001:db8::/64").host_count(), Some(1 << 64));
+        assert_eq!(cidr("::/0").host_count(), None);
+    }
+}
@@ -1,17 +1,95 @@
 //! KCL regex system module
 //! regex.match(string: str, pattern: str) -> bool
-//! regex.replace(string: str, pattern: str, replace: str, count: int = 0) -> str
+//! regex.replace(string: str, pattern: str, replace: str, count: int = 0, backslash_refs: bool = False) -> str
 //! regex.compile(pattern: str) -> bool
 //! regex.findall(string: str, pattern: str) -> [str]
 //! regex.search(string: str, pattern: str) -> bool
 //! regex.split(string: str, pattern: str, maxsplit: int = 0) -> [str]
+//! regex.groups(string: str, pattern: str) -> [str]
+//! regex.named_groups(string: str, pattern: str) -> {str:str}
+//!
+//! The replacement string of `regex.replace` refers to the capture groups with
+//! `$1` and `${name}`. With `backslash_refs=True`, the Python style references
+//! `\1` and `\g<name>` are also accepted and `\\` denotes a literal backslash.
+//! The compiled patterns are cached in the LRU cache of the runtime context
+//! keyed by the pattern string.
 //!
 //! Copyright 2021 The KCL Authors. All rights reserved.
 
 extern crate fancy_regex;
 
+use std::rc::Rc;
+
+use fancy_regex::Regex;
+
 use crate::*;
 
+/// The default capacity of the compiled regex pattern cache.
+pub const REGEX_CACHE_CAPACITY: usize = 128;
+
+/// RegexCache is the LRU cache of the compiled regex patterns keyed by the
+/// pattern string, the least recently used pattern is evicted when full.
+#[derive(Clone, Debug)]
+pub struct RegexCache {
+    pub capacity: usize,
+    patterns: IndexMap<String, Rc<Regex>>,
+}
+
+impl Default for RegexCache {
+    fn default() -> Self {
+        Self::with_capacity(REGEX_CACHE_CAPACITY)
+    }
+}
+
+impl PartialEq for RegexCache {
+    fn eq(&self, other: &Self) -> bool {
+        self.capacity == other.capacity && self.patterns.keys().eq(other.patterns.keys())
+    }
+}
+
+impl RegexCache {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            patterns: IndexMap::default(),
+        }
+    }
+
+    /// Get the compiled regex of the pattern from the cache or compile it.
+    pub fn get(&mut self, pattern: &str) -> Result<Rc<Regex>, fancy_regex::Error> {
+        if let Some((index, _, re)) = self.patterns.get_full(pattern) {
+            let re = re.clone();
+            // Move the pattern to the end as the most recently used one.
+            if index + 1 != self.patterns.len() {
+                if let Some((key, re)) = self.patterns.shift_remove_index(index) {
+                    self.patterns.insert(key, re);
+                }
+            }
+            return Ok(re);
+        }
+        let re = Rc::new(Regex::new(pattern)?);
+        if self.capacity > 0 {
+            if self.patterns.len() >= self.capacity {
+                self.patterns.shift_remove_index(0);
+            }
+            self.patterns.insert(pattern.to_string(), re.clone());
+        }
+        Ok(re)
+    }
+
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.patterns.contains_key(pattern)
+    }
+}
+
 #[allow(non_camel_case_types)]
 type kclvm_value_ref_t = ValueRef;
 
@@ -28,7 +106,7 @@ pub extern "C" fn kclvm_regex_match(
 
     if let Some(string) = args.arg_i_str(0, None) {
         if let Some(pattern) = args.arg_i_str(1, None) {
-            let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
+            let re = compile_pattern(&pattern);
             match re.is_match(string.as_ref()) {
                 Ok(ok) => {
                     if ok {
@@ -45,23 +123,33 @@ pub extern "C" fn kclvm_regex_match(
     panic!("match() missing 2 required positional arguments: 'string' and 'pattern'")
 }
 
-// def KMANGLED_replace(string: str, pattern: str, replace: str, count: int = 0):
+// def KMANGLED_replace(string: str, pattern: str, replace: str, count: int = 0, backslash_refs: bool = False):
 
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_regex_replace(
     _ctx: *mut kclvm_context_t,
     args: *const kclvm_value_ref_t,
-    _kwargs: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
 ) -> *mut kclvm_value_ref_t {
     let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
 
     if let Some(string) = args.arg_i_str(0, None) {
         if let Some(pattern) = args.arg_i_str(1, None) {
             if let Some(replace) = args.arg_i_str(2, None) {
                 if let Some(count) = args.arg_i_int(3, Some(0)) {
-                    let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
-                    let s = re.replacen(string.as_ref(), count as usize, replace.as_ref() as &str);
+                    let backslash_refs = args
+                        .arg_i_bool(4, None)
+                        .or_else(|| kwargs.kwarg_bool("backslash_refs", None))
+                        .unwrap_or(false);
+                    let re = compile_pattern(&pattern);
+                    let replace = if backslash_refs {
+                        expand_group_refs(&replace)
+                    } else {
+                        replace
+                    };
+                    let s = re.replacen(string.as_ref(), count as usize, replace.as_str());
                     return ValueRef::str(&s).into_raw();
                 }
             }
@@ -82,7 +170,8 @@ pub extern "C" fn kclvm_regex_compile(
     let args = ptr_as_ref(args);
 
     if let Some(pattern) = args.arg_i_str(0, None) {
-        match fancy_regex::Regex::new(pattern.as_ref()) {
+        let ctx = Context::current_context_mut();
+        match ctx.regex_cache.get(&pattern) {
             Ok(_) => return kclvm_value_Bool(1),
             _ => return kclvm_value_Bool(0),
         }
@@ -105,8 +194,7 @@ pub extern "C" fn kclvm_regex_findall(
         if let Some(pattern) = args.arg_i_str(1, None) {
             let mut list = ValueRef::list(None);
 
-            for x in fancy_regex::Regex::new(pattern.as_ref())
-                .unwrap()
+            for x in compile_pattern(&pattern)
                 .captures_iter(string.as_ref())
                 .flatten()
             {
@@ -142,7 +230,7 @@ pub extern "C" fn kclvm_regex_search(
 
     if let Some(string) = args.arg_i_str(0, None) {
         if let Some(pattern) = args.arg_i_str(1, None) {
-            let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
+            let re = compile_pattern(&pattern);
 
             if let Ok(Some(..)) = re.find(string.as_ref()) {
                 return kclvm_value_Bool(1);
@@ -169,7 +257,7 @@ pub extern "C" fn kclvm_regex_split(
             if let Some(maxsplit) = args.arg_i_int(2, Some(0)) {
                 let mut list = ValueRef::list(None);
 
-                let re = fancy_regex::Regex::new(pattern.as_ref()).unwrap();
+                let re = compile_pattern(&pattern);
 
                 let mut fields: Vec<String> = Vec::new();
                 let mut current_pos = 0;
@@ -198,3 +286,152 @@ pub extern "C" fn kclvm_regex_split(
     }
     panic!("split() missing 2 required positional arguments: 'string' and 'pattern'");
 }
+
+// def KMANGLED_groups(string: str, pattern: str) -> [str]:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_regex_groups(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *mut kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if let Some(string) = args.arg_i_str(0, None) {
+        if let Some(pattern) = args.arg_i_str(1, None) {
+            let re = compile_pattern(&pattern);
+            let mut list = ValueRef::list(None);
+            if let Ok(Some(caps)) = re.captures(string.as_ref()) {
+                for i in 1..caps.len() {
+                    match caps.get(i) {
+                        Some(m) => list.list_append(&ValueRef::str(m.as_str())),
+                        None => list.list_append(&ValueRef::none()),
+                    }
+                }
+            }
+            return list.into_raw();
+        }
+    }
+    panic!("groups() missing 2 required positional arguments: 'string' and 'pattern'");
+}
+
+// def KMANGLED_named_groups(string: str, pattern: str) -> {str:str}:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_regex_named_groups(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *mut kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if let Some(string) = args.arg_i_str(0, None) {
+        if let Some(pattern) = args.arg_i_str(1, None) {
+            let re = compile_pattern(&pattern);
+            let mut dict = ValueRef::dict(None);
+            if let Ok(Some(caps)) = re.captures(string.as_ref()) {
+                for name in re.capture_names().flatten() {
+                    match caps.name(name) {
+                        Some(m) => dict.dict_update_key_value(name, ValueRef::str(m.as_str())),
+                        None => dict.dict_update_key_value(name, ValueRef::none()),
+                    }
+                }
+            }
+            return dict.into_raw();
+        }
+    }
+    panic!("named_groups() missing 2 required positional arguments: 'string' and 'pattern'");
+}
+
+/// Get the compiled regex of the pattern from the regex cache of the context.
+fn compile_pattern(pattern: &str) -> Rc<Regex> {
+    let ctx = Context::current_context_mut();
+    match ctx.regex_cache.get(pattern) {
+        Ok(re) => re,
+        Err(err) => {
+            ctx.set_err_type(&ErrType::ValueError_TYPE);
+
+            panic!("invalid regex pattern '{}': {}", pattern, err)
+        }
+    }
+}
+
+/// Convert the group references `\1` and `\g<name>` in the replacement string
+/// to the `${1}` and `${name}` forms of the regex expander, the escaped
+/// backslash `\\` is consumed first as a literal backslash.
+fn expand_group_refs(replace: &str) -> String {
+    let mut result = String::with_capacity(replace.len());
+    let mut rest = replace;
+    while let Some(pos) = rest.find('\\') {
+        result.push_str(&rest[..pos]);
+        let tail = &rest[pos + 1..];
+        if let Some(escaped) = tail.strip_prefix('\\') {
+            result.push('\\');
+            rest = escaped;
+            continue;
+        }
+        let digits = tail.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits > 0 {
+            result.push_str(&format!("${{{}}}", &tail[..digits]));
+            rest = &tail[digits..];
+        } else if let Some(name_end) = tail
+            .strip_prefix("g<")
+            .and_then(|name| name.find('>'))
+            .filter(|end| *end > 0)
+        {
+            result.push_str(&format!("${{{}}}", &tail[2..2 + name_end]));
+            rest = &tail[3 + name_end..];
+        } else {
+            result.push('\\');
+            rest = tail;
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod test_regex {
+    use super::*;
+
+    #[test]
+    fn test_regex_cache() {
+        let mut cache = RegexCache::with_capacity(2);
+        let re = cache.get("a+").unwrap();
+        assert!(Rc::ptr_eq(&re, &cache.get("a+").unwrap()));
+        cache.get("b+").unwrap();
+        // "a+" is the most recently used pattern and "b+" is evicted.
+        cache.get("a+").unwrap();
+        cache.get("c+").unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains("a+"));
+        assert!(!cache.contains("b+"));
+        assert!(cache.contains("c+"));
+        assert!(cache.get("(").is_err());
+        assert_eq!(cache.len(), 2);
+
+        let mut cache = RegexCache::with_capacity(0);
+        assert!(cache.get("a+").is_ok());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_expand_group_refs() {
+        let cases = [
+            ("$1-${name}", "$1-${name}"),
+            ("\\2.\\1", "${2}.${1}"),
+            ("\\12", "${12}"),
+            ("\\g<major>.x", "${major}.x"),
+            ("\\g<>", "\\g<>"),
+            ("a\\b\\", "a\\b\\"),
+            ("\\\\1", "\\1"),
+            ("\\\\\\1", "\\${1}"),
+            ("\\\\g<name>", "\\g<name>"),
+        ];
+        for (replace, expected) in cases {
+            assert_eq!(expand_group_refs(replace), expected);
+        }
+    }
+}
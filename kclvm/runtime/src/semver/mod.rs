@@ -0,0 +1,4 @@
+// Copyright 2021 The KCL Authors. All rights reserved.
+
+pub mod semver;
+pub use self::semver::*;
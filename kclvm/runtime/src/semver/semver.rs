@@ -0,0 +1,301 @@
+//! KCL semver system module
+//! semver.parse(version: str) -> {str:}
+//! semver.compare(v1: str, v2: str) -> int
+//! semver.satisfies(version: str, constraint: str) -> bool
+//! semver.bump(version: str, part: str = "patch") -> str
+//!
+//! The versions follow the Semantic Versioning 2.0.0 e.g., "1.2.3-rc.1+build.5",
+//! the leading "v" e.g., "v1.2.3" and the missing minor or patch numbers e.g.,
+//! "1.2" are accepted. The constraints are comma-separated comparators e.g.,
+//! "^1.2", "~1.2.3", ">=1.0, <2.0" or "1.*", and the "||" separated constraints
+//! are satisfied when any of them is satisfied.
+//!
+//! Copyright 2021 The KCL Authors. All rights reserved.
+
+extern crate semver;
+
+use std::cmp::Ordering;
+
+use ::semver::{BuildMetadata, Prerelease, Version, VersionReq};
+
+use crate::*;
+
+#[allow(non_camel_case_types)]
+type kclvm_value_ref_t = ValueRef;
+
+const VERSION_PREFIXES: [char; 2] = ['v', 'V'];
+
+// def KMANGLED_parse(version: str) -> {str:}:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_parse(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let version = version_arg(args, kwargs, 0, "version", "parse");
+    let mut result = ValueRef::dict(None);
+    result.dict_update_key_value("major", ValueRef::int(version.major as i64));
+    result.dict_update_key_value("minor", ValueRef::int(version.minor as i64));
+    result.dict_update_key_value("patch", ValueRef::int(version.patch as i64));
+    result.dict_update_key_value("pre", ValueRef::str(version.pre.as_str()));
+    result.dict_update_key_value("build", ValueRef::str(version.build.as_str()));
+    result.into_raw()
+}
+
+// def KMANGLED_compare(v1: str, v2: str) -> int:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_compare(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let v1 = version_arg(args, kwargs, 0, "v1", "compare");
+    let v2 = version_arg(args, kwargs, 1, "v2", "compare");
+    let result = match compare_versions(&v1, &v2) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    };
+    ValueRef::int(result).into_raw()
+}
+
+// def KMANGLED_satisfies(version: str, constraint: str) -> bool:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_satisfies(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let version = version_arg(args, kwargs, 0, "version", "satisfies");
+    let constraint = match args
+        .arg_i_str(1, None)
+        .or_else(|| kwargs.kwarg_str("constraint", None))
+    {
+        Some(constraint) => constraint,
+        None => panic!("satisfies() missing 1 required positional argument: 'constraint'"),
+    };
+    match satisfies(&version, &constraint) {
+        Ok(ok) => kclvm_value_Bool(ok as i8),
+        Err(err) => panic_value_error(&err),
+    }
+}
+
+// def KMANGLED_bump(version: str, part: str = "patch") -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_semver_bump(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let raw = match args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("version", None))
+    {
+        Some(raw) => raw,
+        None => panic!("bump() missing 1 required positional argument: 'version'"),
+    };
+    let part = args
+        .arg_i_str(1, None)
+        .or_else(|| kwargs.kwarg_str("part", None))
+        .unwrap_or_else(|| "patch".to_string());
+    let version = match parse_version(&raw) {
+        Ok(version) => version,
+        Err(err) => panic_value_error(&err),
+    };
+    let version = match bump_version(&version, &part) {
+        Ok(version) => version,
+        Err(err) => panic_value_error(&err),
+    };
+    // Keep the leading "v" of the version e.g., the image tag "v1.2.3".
+    let result = match raw.trim().chars().next() {
+        Some(prefix) if VERSION_PREFIXES.contains(&prefix) => format!("{}{}", prefix, version),
+        _ => version.to_string(),
+    };
+    ValueRef::str(result.as_ref()).into_raw()
+}
+
+/// Parse the semantic version, the leading "v" and the missing minor or
+/// patch numbers are accepted.
+fn parse_version(version: &str) -> Result<Version, String> {
+    let trimmed = version.trim();
+    let trimmed = trimmed
+        .strip_prefix(&VERSION_PREFIXES[..])
+        .unwrap_or(trimmed);
+    let core_end = trimmed
+        .find(|c| c == '-' || c == '+')
+        .unwrap_or(trimmed.len());
+    let (core, rest) = trimmed.split_at(core_end);
+    let core = match core.split('.').count() {
+        1 => format!("{}.0.0", core),
+        2 => format!("{}.0", core),
+        _ => core.to_string(),
+    };
+    Version::parse(&format!("{}{}", core, rest))
+        .map_err(|err| format!("invalid semantic version '{}': {}", version, err))
+}
+
+/// Compare the versions by the precedence of the Semantic Versioning,
+/// the build metadata is ignored.
+fn compare_versions(v1: &Version, v2: &Version) -> Ordering {
+    (v1.major, v1.minor, v1.patch, &v1.pre).cmp(&(v2.major, v2.minor, v2.patch, &v2.pre))
+}
+
+fn satisfies(version: &Version, constraint: &str) -> Result<bool, String> {
+    for req in constraint.split("||") {
+        let req = VersionReq::parse(req.trim())
+            .map_err(|err| format!("invalid version constraint '{}': {}", constraint, err))?;
+        if req.matches(version) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Increment the part of the version like npm and cargo, a prerelease version
+/// is promoted to its release version when the lower parts are 0 e.g., the
+/// patch of "1.2.3-rc.1" is bumped to "1.2.3" and the major of "2.0.0-rc.1"
+/// is bumped to "2.0.0".
+fn bump_version(version: &Version, part: &str) -> Result<Version, String> {
+    let mut version = version.clone();
+    let is_pre = !version.pre.is_empty();
+    let increment = |n: u64| {
+        n.checked_add(1)
+            .ok_or_else(|| format!("the {} version number {} overflows", part, n))
+    };
+    match part {
+        "major" => {
+            if !(is_pre && version.minor == 0 && version.patch == 0) {
+                version.major = increment(version.major)?;
+            }
+            version.minor = 0;
+            version.patch = 0;
+        }
+        "minor" => {
+            if !(is_pre && version.patch == 0) {
+                version.minor = increment(version.minor)?;
+            }
+            version.patch = 0;
+        }
+        "patch" => {
+            if !is_pre {
+                version.patch = increment(version.patch)?;
+            }
+        }
+        _ => {
+            return Err(format!(
+                "invalid version part '{}', expected 'major', 'minor' or 'patch'",
+                part
+            ))
+        }
+    }
+    version.pre = Prerelease::EMPTY;
+    version.build = BuildMetadata::EMPTY;
+    Ok(version)
+}
+
+fn version_arg(args: &ValueRef, kwargs: &ValueRef, i: usize, name: &str, func: &str) -> Version {
+    let version = match args
+        .arg_i_str(i, None)
+        .or_else(|| kwargs.kwarg_str(name, None))
+    {
+        Some(version) => version,
+        None => panic!(
+            "{}() missing 1 required positional argument: '{}'",
+            func, name
+        ),
+    };
+    match parse_version(&version) {
+        Ok(version) => version,
+        Err(err) => panic_value_error(&err),
+    }
+}
+
+#[cfg(test)]
+mod test_semver {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("1.2.3").unwrap(), Version::new(1, 2, 3));
+        assert_eq!(parse_version("v1.2").unwrap(), Version::new(1, 2, 0));
+        assert_eq!(parse_version("2").unwrap(), Version::new(2, 0, 0));
+        let version = parse_version("1.2-rc.1+build.5").unwrap();
+        assert_eq!(version.pre.as_str(), "rc.1");
+        assert_eq!(version.build.as_str(), "build.5");
+        assert!(parse_version("1.2.x").is_err());
+        assert!(parse_version("latest").is_err());
+        assert!(parse_version("vv1.2.3").is_err());
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        let cases = [
+            ("1.2.3", "1.2.3", Ordering::Equal),
+            ("1.2.3", "1.10.0", Ordering::Less),
+            ("1.0.0-alpha", "1.0.0", Ordering::Less),
+            ("1.0.0-alpha.2", "1.0.0-alpha.10", Ordering::Less),
+            ("1.0.0+build.2", "1.0.0+build.1", Ordering::Equal),
+            ("2.0.0", "1.99.99", Ordering::Greater),
+        ];
+        for (v1, v2, expected) in cases {
+            let v1 = parse_version(v1).unwrap();
+            let v2 = parse_version(v2).unwrap();
+            assert_eq!(compare_versions(&v1, &v2), expected);
+        }
+    }
+
+    #[test]
+    fn test_satisfies() {
+        let version = parse_version("1.4.2").unwrap();
+        assert!(satisfies(&version, "^1.2").unwrap());
+        assert!(satisfies(&version, ">=1.0, <2.0").unwrap());
+        assert!(!satisfies(&version, "~1.2").unwrap());
+        assert!(satisfies(&version, "~1.2 || ~1.4").unwrap());
+        assert!(satisfies(&version, "*").unwrap());
+        assert!(satisfies(&version, ">=a.b").is_err());
+    }
+
+    #[test]
+    fn test_bump_version() {
+        let bump = |version: &str, part: &str| {
+            bump_version(&parse_version(version).unwrap(), part).map(|v| v.to_string())
+        };
+        let cases = [
+            ("1.2.3", "major", "2.0.0"),
+            ("1.2.3", "minor", "1.3.0"),
+            ("1.2.3+build.5", "patch", "1.2.4"),
+            ("1.2.3-rc.1", "major", "2.0.0"),
+            ("1.2.3-rc.1", "minor", "1.3.0"),
+            ("1.2.3-rc.1", "patch", "1.2.3"),
+            ("1.2.0-rc.1", "minor", "1.2.0"),
+            ("2.0.0-rc.1", "major", "2.0.0"),
+            ("2.0.0-rc.1", "minor", "2.0.0"),
+        ];
+        for (version, part, expected) in cases {
+            assert_eq!(bump(version, part).unwrap(), expected);
+        }
+        assert!(bump("1.2.3", "build").is_err());
+        assert!(bump(&format!("1.2.{}", u64::MAX), "patch").is_err());
+        assert!(bump(&format!("{}.2.3", u64::MAX), "major").is_err());
+    }
+}
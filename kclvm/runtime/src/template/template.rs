@@ -0,0 +1,122 @@
+//! KCL template system module
+//!
+//! The templates are rendered with the Handlebars syntax e.g., `{{name}}`,
+//! `{{#if enabled}}...{{/if}}` and `{{#each items}}{{this}}{{/each}}`. The
+//! templates usually render configurations instead of HTML, so the values are
+//! not HTML-escaped unless `escape=True` is given, and referencing a missing
+//! value is an error instead of rendering an empty string.
+//!
+//! Copyright 2021 The KCL Authors. All rights reserved.
+
+extern crate handlebars;
+
+use handlebars::{html_escape, no_escape, Handlebars, RenderError};
+
+use crate::*;
+
+#[allow(non_camel_case_types)]
+type kclvm_value_ref_t = ValueRef;
+
+// def KMANGLED_execute(template: str, data: {str:any} = {}, escape: bool = False) -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_template_execute(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let template = match args
+        .arg_i_str(0, None)
+        .or_else(|| kwargs.kwarg_str("template", None))
+    {
+        Some(template) => template,
+        None => panic!("execute() missing 1 required positional argument: 'template'"),
+    };
+    let data = match args.arg_i(1).or_else(|| kwargs.kwarg("data")) {
+        Some(data) if data.is_config() => data.clone(),
+        Some(data) if data.is_none_or_undefined() => ValueRef::dict(None),
+        None => ValueRef::dict(None),
+        Some(data) => {
+            let ctx = Context::current_context_mut();
+            ctx.set_err_type(&ErrType::TypeError_Runtime_TYPE);
+
+            panic!(
+                "execute() argument 'data' must be a dict, not '{}'",
+                data.type_str()
+            )
+        }
+    };
+    let escape = args
+        .arg_i_bool(2, None)
+        .or_else(|| kwargs.kwarg_bool("escape", None))
+        .unwrap_or_default();
+    // Use the JSON value of the data as the template context.
+    let data: serde_json::Value = serde_json::from_str(&data.to_json_string()).unwrap();
+
+    match render_template(&template, &data, escape) {
+        Ok(result) => ValueRef::str(result.as_ref()).into_raw(),
+        Err(err) => {
+            let ctx = Context::current_context_mut();
+            ctx.set_err_type(&ErrType::EvaluationError_TYPE);
+
+            panic!("failed to execute the template: {}", err)
+        }
+    }
+}
+
+/// Render the template in the strict mode, the values are HTML-escaped only when
+/// `escape` is true.
+fn render_template(
+    template: &str,
+    data: &serde_json::Value,
+    escape: bool,
+) -> Result<String, RenderError> {
+    let mut registry = Handlebars::new();
+    registry.set_strict_mode(true);
+    if !escape {
+        registry.register_escape_fn(no_escape);
+    }
+    registry.render_template(template, data)
+}
+
+// def KMANGLED_html_escape(data: str) -> str:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_template_html_escape(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if let Some(data) = args.arg_i_str(0, None) {
+        return ValueRef::str(html_escape(&data).as_ref()).into_raw();
+    }
+    panic!("html_escape() missing 1 required positional argument: 'data'")
+}
+
+#[cfg(test)]
+mod test_template {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let data = serde_json::json!({"name": "<b>", "tls": true, "hosts": ["a", "b"]});
+        let template = "{{name}} {{#if tls}}ssl{{/if}} {{#each hosts}}{{this}}{{/each}}";
+        assert_eq!(
+            render_template(template, &data, false).unwrap(),
+            "<b> ssl ab"
+        );
+        assert_eq!(
+            render_template(template, &data, true).unwrap(),
+            "&lt;b&gt; ssl ab"
+        );
+        // Missing values are errors in the strict mode.
+        assert!(render_template("{{image}}", &data, false).is_err());
+    }
+}
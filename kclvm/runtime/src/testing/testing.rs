@@ -1,5 +1,9 @@
 //! KCL testing system module
 //!
+//! The options set by `arguments` and `setting_file` are applied by the test runner
+//! before the test cases are executed, so the functions only check their arguments
+//! at runtime.
+//!
 //! Copyright 2021 The KCL Authors. All rights reserved.
 
 use crate::*;
@@ -7,22 +11,48 @@ use crate::*;
 #[allow(non_camel_case_types)]
 type kclvm_value_ref_t = ValueRef;
 
+// def KMANGLED_arguments(name: str, value: any) -> None:
+
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_testing_arguments(
     _ctx: *mut kclvm_context_t,
-    _args: *const kclvm_value_ref_t,
+    args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
-) {
-    // Nothing to do
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if args.args_len() != 2 {
+        panic!(
+            "arguments() takes exactly 2 arguments ({} given)",
+            args.args_len()
+        );
+    }
+    if args.arg_i_str(0, None).is_none() {
+        let ctx = Context::current_context_mut();
+        ctx.set_err_type(&ErrType::TypeError_Runtime_TYPE);
+
+        panic!("arguments() argument 'name' must be str");
+    }
+    ValueRef::none().into_raw()
 }
 
+// def KMANGLED_setting_file(filename: str) -> None:
+
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_testing_setting_file(
     _ctx: *mut kclvm_context_t,
-    _args: *const kclvm_value_ref_t,
+    args: *const kclvm_value_ref_t,
     _kwargs: *const kclvm_value_ref_t,
-) {
-    // Nothing to do
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if args.arg_i_str(0, None).is_none() {
+        let ctx = Context::current_context_mut();
+        ctx.set_err_type(&ErrType::TypeError_Runtime_TYPE);
+
+        panic!("setting_file() missing 1 required positional argument: 'filename'");
+    }
+    ValueRef::none().into_raw()
 }
@@ -195,6 +195,26 @@ impl ValueRef {
         (json_result, yaml_result)
     }
 
+    /// Plan the value to JSON and YAML strings with the encode options.
+    pub fn plan_with_options(
+        &self,
+        json_opt: &JsonEncodeOptions,
+        yaml_opt: &YamlEncodeOptions,
+    ) -> (String, String) {
+        let results = filter_results(self);
+        let yaml_result = results
+            .iter()
+            .map(|r| r.to_yaml_string_with_options(yaml_opt))
+            .collect::<Vec<String>>()
+            .join("---\n");
+        let mut list_result = ValueRef::list(None);
+        for r in results {
+            list_result.list_append(&r);
+        }
+        let json_result = list_result.to_json_string_with_option(json_opt);
+        (json_result, yaml_result)
+    }
+
     fn filter_results(&self) -> ValueRef {
         let ctx = Context::current_context();
         match &*self.rc {
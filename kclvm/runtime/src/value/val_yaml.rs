@@ -4,20 +4,167 @@ extern crate serde_json;
 extern crate serde_yaml;
 
 use crate::*;
+use serde::Deserialize;
+use yaml_rust::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust::scanner::{Marker, TScalarStyle};
+
+/// The default indent width of YAML block collections.
+pub const YAML_DEFAULT_INDENT: i64 = 2;
 
 #[derive(Debug, Default)]
 pub struct YamlEncodeOptions {
     pub sort_keys: bool,
     pub ignore_private: bool,
     pub ignore_none: bool,
+    /// Indent width of block collections, the default width 2 is used when it is not positive.
+    pub indent: i64,
+    /// Emit multiline strings as `|` literal block scalars instead of quoted strings.
+    pub block_scalars: bool,
+    /// Emit collections in the flow style e.g., `{a: 1, b: [1, 2]}` instead of the block style.
+    pub flow_style: bool,
+}
+
+impl YamlEncodeOptions {
+    /// Whether the options only filter or sort values and keep the default output style.
+    fn is_default_style(&self) -> bool {
+        !self.block_scalars
+            && !self.flow_style
+            && (self.indent <= 0 || self.indent == YAML_DEFAULT_INDENT)
+    }
+}
+
+/// The error of decoding a YAML string, the line and column of the error in
+/// the YAML string are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YamlDecodeError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl From<serde_yaml::Error> for YamlDecodeError {
+    fn from(err: serde_yaml::Error) -> Self {
+        let message = err.to_string();
+        // Some scan errors of serde_yaml have no location but only the position
+        // in the message e.g., "... at line 2 column 1".
+        let (line, column) = match err.location() {
+            Some(location) => (Some(location.line()), Some(location.column())),
+            None => match yaml_error_message_position(&message) {
+                Some((line, column)) => (Some(line), Some(column)),
+                None => (None, None),
+            },
+        };
+        YamlDecodeError {
+            message,
+            line,
+            column,
+        }
+    }
+}
+
+/// Parse the line and column of the error message ends with "at line {} column {}".
+fn yaml_error_message_position(message: &str) -> Option<(usize, usize)> {
+    let (_, position) = message.rsplit_once(" at line ")?;
+    let (line, column) = position.split_once(" column ")?;
+    Some((line.trim().parse().ok()?, column.trim().parse().ok()?))
+}
+
+impl std::fmt::Display for YamlDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) if !self.message.contains(" at line ") => {
+                write!(f, "{} at line {} column {}", self.message, line, column)
+            }
+            _ => write!(f, "{}", self.message),
+        }
+    }
 }
 
 impl ValueRef {
     pub fn from_yaml(s: &str) -> Option<Self> {
-        let json_value: serde_json::Value = serde_yaml::from_str(s).unwrap();
-        match serde_json::to_string(&json_value) {
-            Ok(s) => Self::from_json(s.as_ref()),
-            _ => None,
+        Self::try_from_yaml(s).ok()
+    }
+
+    /// Decode a YAML document, returns the error with the position in the YAML
+    /// string instead of panicking on invalid YAML.
+    pub fn try_from_yaml(s: &str) -> Result<Self, YamlDecodeError> {
+        let yaml_value: serde_yaml::Value = serde_yaml::from_str(s)?;
+        Self::from_yaml_value(&yaml_value)
+    }
+
+    /// Decode all documents of a YAML stream separated by `---` into a list value,
+    /// empty documents e.g., the document after the last `---` are ignored, while
+    /// the explicit `null` and `~` documents are decoded as `None`.
+    pub fn try_from_yaml_stream(s: &str) -> Result<Self, YamlDecodeError> {
+        let empty_documents = yaml_empty_documents(s);
+        let mut list = ValueRef::list(None);
+        for (i, document) in serde_yaml::Deserializer::from_str(s).enumerate() {
+            let yaml_value = serde_yaml::Value::deserialize(document)?;
+            if empty_documents.get(i).copied().unwrap_or_default() {
+                continue;
+            }
+            list.list_append(&Self::from_yaml_value(&yaml_value)?);
+        }
+        Ok(list)
+    }
+
+    /// Convert the YAML value to the KCL value. The aliases of anchors are resolved by
+    /// serde_yaml, and the merge keys `<<` are merged into the mapping where the keys
+    /// of the mapping take precedence. Integers out of the 64-bit signed range are
+    /// decoded as floats.
+    fn from_yaml_value(yaml_value: &serde_yaml::Value) -> Result<Self, YamlDecodeError> {
+        match yaml_value {
+            serde_yaml::Value::Null => Ok(ValueRef::none()),
+            serde_yaml::Value::Bool(v) => Ok(ValueRef::bool(*v)),
+            serde_yaml::Value::Number(v) => Ok(match v.as_i64() {
+                Some(v) => ValueRef::int(v),
+                None => ValueRef::float(v.as_f64().unwrap_or_default()),
+            }),
+            serde_yaml::Value::String(v) => Ok(ValueRef::str(v)),
+            serde_yaml::Value::Sequence(values) => {
+                let mut list = ValueRef::list(None);
+                for value in values {
+                    list.list_append(&Self::from_yaml_value(value)?);
+                }
+                Ok(list)
+            }
+            serde_yaml::Value::Mapping(mapping) => {
+                let mut dict = ValueRef::dict(None);
+                for (key, value) in mapping {
+                    if key.as_str() != Some(YAML_MERGE_KEY) {
+                        dict.dict_insert(
+                            &yaml_key_str(key)?,
+                            &Self::from_yaml_value(value)?,
+                            ConfigEntryOperationKind::Union,
+                            0,
+                        );
+                    }
+                }
+                if let Some(merged) = mapping.get(&serde_yaml::Value::from(YAML_MERGE_KEY)) {
+                    // The former mappings of the merge key sequence take precedence.
+                    let merged = match merged {
+                        serde_yaml::Value::Sequence(values) => values.iter().collect(),
+                        value => vec![value],
+                    };
+                    for value in merged {
+                        let merged = Self::from_yaml_value(value)?;
+                        if !merged.is_dict() {
+                            return Err(YamlDecodeError {
+                                message: "expected a mapping or a list of mappings for merging"
+                                    .to_string(),
+                                line: None,
+                                column: None,
+                            });
+                        }
+                        for (key, value) in merged.as_dict_ref().values.iter() {
+                            if !dict.as_dict_ref().values.contains_key(key) {
+                                dict.dict_insert(key, value, ConfigEntryOperationKind::Union, 0);
+                            }
+                        }
+                    }
+                }
+                Ok(dict)
+            }
         }
     }
 
@@ -44,7 +191,22 @@ impl ValueRef {
 
     pub fn to_yaml_string_with_options(&self, opt: &YamlEncodeOptions) -> String {
         let x = self.yaml_clone_with_filter(opt);
-        x.to_yaml_string()
+        if opt.is_default_style() {
+            x.to_yaml_string()
+        } else {
+            YamlEmitter::new(opt).emit_document(&x)
+        }
+    }
+
+    /// Encode each item of the list value as a YAML document, and the documents
+    /// are separated by `---`.
+    pub fn to_yaml_stream_string_with_options(&self, opt: &YamlEncodeOptions) -> String {
+        self.as_list_ref()
+            .values
+            .iter()
+            .map(|x| x.to_yaml_string_with_options(opt))
+            .collect::<Vec<String>>()
+            .join("---\n")
     }
 
     fn yaml_clone_with_filter(&self, opt: &YamlEncodeOptions) -> Self {
@@ -81,7 +243,7 @@ impl ValueRef {
             }
             Value::dict_value(ref v) => {
                 let mut dict = ValueRef::dict(None);
-                for (key, val) in v.values.iter() {
+                for (key, val) in yaml_dict_entries(&v.values, opt.sort_keys) {
                     if opt.ignore_private && (*key).starts_with(KCL_PRIVATE_VAR_PREFIX) {
                         continue;
                     }
@@ -117,7 +279,7 @@ impl ValueRef {
 
             Value::schema_value(ref v) => {
                 let mut dict = ValueRef::dict(None);
-                for (key, val) in v.config.values.iter() {
+                for (key, val) in yaml_dict_entries(&v.config.values, opt.sort_keys) {
                     if opt.ignore_private && (*key).starts_with(KCL_PRIVATE_VAR_PREFIX) {
                         continue;
                     }
@@ -155,6 +317,309 @@ impl ValueRef {
     }
 }
 
+/// The merge key of YAML mappings e.g., `<<: *base`.
+const YAML_MERGE_KEY: &str = "<<";
+
+/// Returns the string of the scalar mapping key.
+fn yaml_key_str(key: &serde_yaml::Value) -> Result<String, YamlDecodeError> {
+    match key {
+        serde_yaml::Value::String(v) => Ok(v.clone()),
+        serde_yaml::Value::Bool(v) => Ok(v.to_string()),
+        serde_yaml::Value::Number(v) => Ok(v.to_string()),
+        serde_yaml::Value::Null => Ok("null".to_string()),
+        _ => Err(YamlDecodeError {
+            message: "expected a scalar mapping key".to_string(),
+            line: None,
+            column: None,
+        }),
+    }
+}
+
+/// Returns whether each document of the YAML stream is empty. The parser reads both
+/// an empty document and the explicit `~` document as the plain scalar `~`, so they
+/// are distinguished by the source at the scalar position.
+fn yaml_empty_documents(s: &str) -> Vec<bool> {
+    struct EmptyDocumentReceiver<'a> {
+        source: &'a str,
+        document_start: bool,
+        empty_documents: Vec<bool>,
+    }
+
+    impl MarkedEventReceiver for EmptyDocumentReceiver<'_> {
+        fn on_event(&mut self, event: Event, mark: Marker) {
+            match event {
+                Event::DocumentStart => self.document_start = true,
+                event if self.document_start => {
+                    self.document_start = false;
+                    let is_empty = matches!(
+                        event,
+                        Event::Scalar(ref value, TScalarStyle::Plain, 0, None) if value == "~"
+                    ) && self.source.chars().nth(mark.index()) != Some('~');
+                    self.empty_documents.push(is_empty);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut receiver = EmptyDocumentReceiver {
+        source: s,
+        document_start: false,
+        empty_documents: vec![],
+    };
+    // The syntax errors are reported by serde_yaml.
+    let _ = Parser::new(s.chars()).load(&mut receiver, true);
+    receiver.empty_documents
+}
+
+/// YAML emitter for the encode options which are not supported by serde_yaml e.g.,
+/// the indent width, block scalars and the flow style. Integers and floats are
+/// emitted as they are, e.g., the float `1.0` is not emitted as the integer `1`.
+struct YamlEmitter {
+    indent: usize,
+    block_scalars: bool,
+    flow_style: bool,
+}
+
+impl YamlEmitter {
+    fn new(opt: &YamlEncodeOptions) -> Self {
+        YamlEmitter {
+            indent: if opt.indent > 0 {
+                opt.indent
+            } else {
+                YAML_DEFAULT_INDENT
+            } as usize,
+            block_scalars: opt.block_scalars,
+            flow_style: opt.flow_style,
+        }
+    }
+
+    fn emit_document(&self, value: &ValueRef) -> String {
+        let mut out = String::new();
+        if self.flow_style {
+            self.emit_flow(&mut out, value);
+            out.push('\n');
+        } else {
+            self.emit_block(&mut out, value, 0, false);
+        }
+        out
+    }
+
+    /// Emit the value in the block style at the indent column. When `inline` is
+    /// true, the first line is written after a sequence indicator `- `.
+    fn emit_block(&self, out: &mut String, value: &ValueRef, col: usize, inline: bool) {
+        match &*value.rc {
+            Value::list_value(list) if !list.values.is_empty() => {
+                for (i, item) in list.values.iter().enumerate() {
+                    if i > 0 || !inline {
+                        out.push_str(&" ".repeat(col));
+                    }
+                    out.push('-');
+                    self.emit_block_item(out, item, col);
+                }
+            }
+            Value::dict_value(dict) if !dict.values.is_empty() => {
+                for (i, (key, val)) in dict.values.iter().enumerate() {
+                    if i > 0 || !inline {
+                        out.push_str(&" ".repeat(col));
+                    }
+                    out.push_str(&yaml_scalar_str(key));
+                    out.push(':');
+                    self.emit_block_value(out, val, col);
+                }
+            }
+            _ => {
+                if let Some(block) = self.block_scalar(value, col + self.indent) {
+                    out.push_str(&block);
+                } else {
+                    self.emit_flow(out, value);
+                    out.push('\n');
+                }
+            }
+        }
+    }
+
+    /// Emit the value of a mapping entry whose key is at the indent column.
+    fn emit_block_value(&self, out: &mut String, value: &ValueRef, col: usize) {
+        if self.is_block_collection(value) {
+            out.push('\n');
+            self.emit_block(out, value, col + self.indent, false);
+        } else if let Some(block) = self.block_scalar(value, col + self.indent) {
+            out.push(' ');
+            out.push_str(&block);
+        } else {
+            out.push(' ');
+            self.emit_flow(out, value);
+            out.push('\n');
+        }
+    }
+
+    /// Emit the item of a sequence whose indicator `-` is at the indent column.
+    fn emit_block_item(&self, out: &mut String, value: &ValueRef, col: usize) {
+        if self.is_block_collection(value) {
+            // Compact nested collections e.g., `- a: 1` and `- - 1`.
+            out.push(' ');
+            self.emit_block(out, value, col + 2, true);
+        } else if let Some(block) = self.block_scalar(value, col + self.indent) {
+            out.push(' ');
+            out.push_str(&block);
+        } else {
+            out.push(' ');
+            self.emit_flow(out, value);
+            out.push('\n');
+        }
+    }
+
+    /// Emit the value in the flow style on a single line.
+    fn emit_flow(&self, out: &mut String, value: &ValueRef) {
+        match &*value.rc {
+            Value::undefined | Value::none => out.push_str("null"),
+            Value::bool_value(v) => out.push_str(if *v { "true" } else { "false" }),
+            Value::int_value(v) => out.push_str(&v.to_string()),
+            Value::float_value(v) => out.push_str(&yaml_float_str(*v)),
+            Value::unit_value(..) => out.push_str(&yaml_scalar_str(&value.to_string())),
+            Value::str_value(v) => out.push_str(&yaml_scalar_str(v)),
+            Value::list_value(list) => {
+                out.push('[');
+                for (i, item) in list.values.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    self.emit_flow(out, item);
+                }
+                out.push(']');
+            }
+            Value::dict_value(dict) => {
+                out.push('{');
+                for (i, (key, val)) in dict.values.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    out.push_str(&yaml_scalar_str(key));
+                    out.push_str(": ");
+                    self.emit_flow(out, val);
+                }
+                out.push('}');
+            }
+            Value::schema_value(schema) => self.emit_flow(
+                out,
+                &ValueRef::from(Value::dict_value(schema.config.as_ref().clone())),
+            ),
+            Value::func_value(_) => out.push_str("null"),
+        }
+    }
+
+    /// Returns the `|` literal block scalar of a multiline string, the lines of
+    /// the block are indented at the indent column.
+    fn block_scalar(&self, value: &ValueRef, col: usize) -> Option<String> {
+        let s = match &*value.rc {
+            Value::str_value(s) if self.block_scalars && s.contains('\n') => s,
+            _ => return None,
+        };
+        // Control characters except tabs and newlines can only be escaped in quoted strings.
+        if s.chars().any(|c| c.is_control() && c != '\n' && c != '\t') {
+            return None;
+        }
+        let content = s.trim_end_matches('\n');
+        if content.is_empty() {
+            return None;
+        }
+        let chomping = match s.len() - content.len() {
+            0 => "-",
+            1 => "",
+            _ => "+",
+        };
+        // The indentation indicator is required when the first non-empty line starts
+        // with spaces, otherwise the indentation is detected from that line.
+        let first_line = content.split('\n').find(|line| !line.is_empty());
+        let indicator = if first_line.map_or(false, |line| line.starts_with(' ')) {
+            self.indent.to_string()
+        } else {
+            String::new()
+        };
+        let mut block = format!("|{}{}\n", indicator, chomping);
+        for line in content.split('\n') {
+            if !line.is_empty() {
+                block.push_str(&" ".repeat(col));
+                block.push_str(line);
+            }
+            block.push('\n');
+        }
+        // Keep the extra trailing newlines of the `|+` block scalar.
+        for _ in 1..s.len() - content.len() {
+            block.push('\n');
+        }
+        Some(block)
+    }
+
+    fn is_block_collection(&self, value: &ValueRef) -> bool {
+        match &*value.rc {
+            Value::list_value(list) => !list.values.is_empty(),
+            Value::dict_value(dict) => !dict.values.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+/// Returns the entries of the dict which are sorted by the keys when `sort_keys` is true.
+fn yaml_dict_entries(
+    values: &IndexMap<String, ValueRef>,
+    sort_keys: bool,
+) -> Vec<(&String, &ValueRef)> {
+    let mut entries: Vec<(&String, &ValueRef)> = values.iter().collect();
+    if sort_keys {
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+    }
+    entries
+}
+
+/// Returns the YAML float string which is always distinguishable from integers.
+fn yaml_float_str(v: f64) -> String {
+    if v.is_nan() {
+        ".nan".to_string()
+    } else if v.is_infinite() {
+        if v > 0.0 { ".inf" } else { "-.inf" }.to_string()
+    } else {
+        format!("{:?}", v)
+    }
+}
+
+/// Returns the plain or double quoted YAML string, strings which can be read as
+/// other scalars e.g., `"true"` and `"1"`, or contain indicators are quoted.
+fn yaml_scalar_str(s: &str) -> String {
+    const RESERVED: [&str; 26] = [
+        "y", "Y", "yes", "Yes", "YES", "n", "N", "no", "No", "NO", "true", "True", "TRUE", "false",
+        "False", "FALSE", "on", "On", "ON", "off", "Off", "OFF", "null", "Null", "NULL", "~",
+    ];
+    let need_quotes = s.is_empty()
+        || s.starts_with(' ')
+        || s.ends_with(' ')
+        || s.starts_with(|c: char| "&*?|-<>=!%@.".contains(c))
+        || s.contains(|c: char| ":{}[],#`\"'\\".contains(c) || c.is_control())
+        || RESERVED.contains(&s)
+        || s.starts_with("0x")
+        || s.parse::<i64>().is_ok()
+        || s.parse::<f64>().is_ok();
+    if !need_quotes {
+        return s.to_string();
+    }
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 #[cfg(test)]
 mod test_value_yaml {
     use crate::*;
@@ -181,6 +646,31 @@ mod test_value_yaml {
         }
     }
 
+    #[test]
+    fn test_value_from_yaml_anchors_and_large_ints() {
+        let yaml_str = r#"
+base: &base
+  a: 1
+  b: 2
+derived:
+  <<: *base
+  b: 3
+list: [*base]
+large: 18446744073709551615
+"#;
+        let base = ValueRef::dict(Some(&[("a", &ValueRef::int(1)), ("b", &ValueRef::int(2))]));
+        let expected = ValueRef::dict(Some(&[
+            ("base", &base),
+            (
+                "derived",
+                &ValueRef::dict(Some(&[("b", &ValueRef::int(3)), ("a", &ValueRef::int(1))])),
+            ),
+            ("list", &ValueRef::list_value(Some(&[base.clone()]))),
+            ("large", &ValueRef::float(18446744073709551615.0)),
+        ]));
+        assert_eq!(ValueRef::try_from_yaml(yaml_str), Ok(expected));
+    }
+
     #[test]
     fn test_value_to_yaml_string() {
         let cases = [
@@ -202,4 +692,112 @@ mod test_value_yaml {
             assert_eq!(result, expected);
         }
     }
+
+    #[test]
+    fn test_value_to_yaml_string_with_options() {
+        let value = ValueRef::dict(Some(&[
+            (
+                "b",
+                &ValueRef::list_value(Some(&[
+                    ValueRef::int(1),
+                    ValueRef::dict(Some(&[
+                        ("c", &ValueRef::float(1.0)),
+                        ("d", &ValueRef::str("x\ny\n")),
+                    ])),
+                ])),
+            ),
+            ("a", &ValueRef::str("true")),
+        ]));
+        let cases = [
+            (
+                YamlEncodeOptions {
+                    sort_keys: true,
+                    indent: 4,
+                    block_scalars: true,
+                    ..Default::default()
+                },
+                "a: \"true\"\nb:\n    - 1\n    - c: 1.0\n      d: |\n          x\n          y\n",
+            ),
+            (
+                YamlEncodeOptions {
+                    flow_style: true,
+                    ..Default::default()
+                },
+                "{b: [1, {c: 1.0, d: \"x\\ny\\n\"}], a: \"true\"}\n",
+            ),
+            (
+                YamlEncodeOptions {
+                    block_scalars: true,
+                    ..Default::default()
+                },
+                "b:\n  - 1\n  - c: 1.0\n    d: |\n      x\n      y\na: \"true\"\n",
+            ),
+        ];
+        for (opt, expected) in cases {
+            assert_eq!(value.to_yaml_string_with_options(&opt), expected);
+        }
+        let opt = YamlEncodeOptions {
+            block_scalars: true,
+            ..Default::default()
+        };
+        for (s, expected) in [
+            ("x\ny", "|-\n  x\n  y\n"),
+            ("x\n\n", "|+\n  x\n\n"),
+            ("  x\ny\n", "|2\n    x\n  y\n"),
+            ("\n  x\n", "|2\n\n    x\n"),
+        ] {
+            assert_eq!(ValueRef::str(s).to_yaml_string_with_options(&opt), expected);
+            assert_eq!(ValueRef::from_yaml(expected), Some(ValueRef::str(s)));
+        }
+        // Sorting keys keeps the default style of serde_yaml.
+        let opt = YamlEncodeOptions {
+            sort_keys: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            value.to_yaml_string_with_options(&opt),
+            "a: \"true\"\nb:\n  - 1\n  - c: 1.0\n    d: \"x\\ny\\n\"\n"
+        );
+    }
+
+    #[test]
+    fn test_value_from_yaml_stream() {
+        let yaml_str = "---\na: 1\n---\nb: [1, 2]\n---\n";
+        let expected = ValueRef::list_value(Some(&[
+            ValueRef::dict(Some(&[("a", &ValueRef::int(1))])),
+            ValueRef::dict(Some(&[("b", &ValueRef::list_int(&[1, 2]))])),
+        ]));
+        assert_eq!(ValueRef::try_from_yaml_stream(yaml_str), Ok(expected));
+        // Explicit null documents are kept and empty documents are ignored.
+        let yaml_str = "a: 1\n---\nnull\n---\n# comment\n---\n~\n---\n";
+        let expected = ValueRef::list_value(Some(&[
+            ValueRef::dict(Some(&[("a", &ValueRef::int(1))])),
+            ValueRef::none(),
+            ValueRef::none(),
+        ]));
+        assert_eq!(ValueRef::try_from_yaml_stream(yaml_str), Ok(expected));
+    }
+
+    #[test]
+    fn test_value_from_invalid_yaml() {
+        for yaml_str in ["a: [1, 2\n", "a: 1\n---\nb: {\n"] {
+            let err = ValueRef::try_from_yaml_stream(yaml_str).unwrap_err();
+            assert!(err.line.is_some(), "{}", err);
+            assert!(err.column.is_some(), "{}", err);
+        }
+        assert!(ValueRef::try_from_yaml("a: [1, 2\n").is_err());
+        assert_eq!(ValueRef::from_yaml("a: [1, 2\n"), None);
+    }
+
+    #[test]
+    fn test_value_to_yaml_stream_string() {
+        let value = ValueRef::list_value(Some(&[
+            ValueRef::dict(Some(&[("a", &ValueRef::int(1))])),
+            ValueRef::dict(Some(&[("b", &ValueRef::str("s"))])),
+        ]));
+        assert_eq!(
+            value.to_yaml_stream_string_with_options(&YamlEncodeOptions::default()),
+            "a: 1\n---\nb: s\n"
+        );
+    }
 }
@@ -7,7 +7,7 @@ use crate::*;
 #[allow(non_camel_case_types)]
 type kclvm_value_ref_t = ValueRef;
 
-// def KMANGLED_encode(data, sort_keys=False, ignore_private=False, ignore_none=False):
+// def KMANGLED_encode(data, sort_keys=False, ignore_private=False, ignore_none=False, indent=2, block_scalars=False, flow_style=False):
 
 #[no_mangle]
 #[runtime_fn]
@@ -19,16 +19,7 @@ pub extern "C" fn kclvm_yaml_encode(
     let args = ptr_as_ref(args);
     let kwargs = ptr_as_ref(kwargs);
 
-    let mut opt = YamlEncodeOptions::default();
-    if let Some(sort_keys) = kwargs.kwarg_bool("sort_keys", None) {
-        opt.sort_keys = sort_keys;
-    }
-    if let Some(ignore_private) = kwargs.kwarg_bool("ignore_private", None) {
-        opt.ignore_private = ignore_private;
-    }
-    if let Some(ignore_none) = kwargs.kwarg_bool("ignore_none", None) {
-        opt.ignore_none = ignore_none;
-    }
+    let opt = yaml_encode_options(kwargs);
 
     if let Some(arg0) = args.arg_i(0) {
         let s = ValueRef::str(arg0.to_yaml_string_with_options(&opt).as_ref());
@@ -47,13 +38,64 @@ pub extern "C" fn kclvm_yaml_decode(
     let args = ptr_as_ref(args);
 
     if let Some(arg0) = args.arg_i(0) {
-        if let Some(x) = ValueRef::from_yaml(arg0.as_str().as_ref()) {
-            return x.into_raw();
+        match ValueRef::try_from_yaml(arg0.as_str().as_ref()) {
+            Ok(x) => return x.into_raw(),
+            Err(err) => yaml_decode_error(err),
         }
     }
     panic!("decode() missing 1 required positional argument: 'value'")
 }
 
+// def KMANGLED_encode_all(data, sort_keys=False, ignore_private=False, ignore_none=False, indent=2, block_scalars=False, flow_style=False):
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_yaml_encode_all(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+    let kwargs = ptr_as_ref(kwargs);
+
+    let opt = yaml_encode_options(kwargs);
+
+    if let Some(arg0) = args.arg_i(0) {
+        if !arg0.is_list() {
+            let ctx = Context::current_context_mut();
+            ctx.set_err_type(&ErrType::TypeError_Runtime_TYPE);
+
+            panic!(
+                "encode_all() argument 'data' must be a list, not '{}'",
+                arg0.type_str()
+            );
+        }
+        let s = ValueRef::str(arg0.to_yaml_stream_string_with_options(&opt).as_ref());
+        return s.into_raw();
+    }
+    panic!("encode_all() missing 1 required positional argument: 'data'")
+}
+
+// def KMANGLED_decode_all(value: str) -> [any]:
+
+#[no_mangle]
+#[runtime_fn]
+pub extern "C" fn kclvm_yaml_decode_all(
+    _ctx: *mut kclvm_context_t,
+    args: *const kclvm_value_ref_t,
+    _kwargs: *const kclvm_value_ref_t,
+) -> *const kclvm_value_ref_t {
+    let args = ptr_as_ref(args);
+
+    if let Some(arg0) = args.arg_i(0) {
+        match ValueRef::try_from_yaml_stream(arg0.as_str().as_ref()) {
+            Ok(x) => return x.into_raw(),
+            Err(err) => yaml_decode_error(err),
+        }
+    }
+    panic!("decode_all() missing 1 required positional argument: 'value'")
+}
+
 #[no_mangle]
 #[runtime_fn]
 pub extern "C" fn kclvm_yaml_dump_to_file(
@@ -73,3 +115,34 @@ pub extern "C" fn kclvm_yaml_dump_to_file(
     }
     panic!("dump_to_file() missing 2 required positional arguments: 'data' and 'filename'")
 }
+
+fn yaml_encode_options(kwargs: &ValueRef) -> YamlEncodeOptions {
+    let mut opt = YamlEncodeOptions::default();
+    if let Some(sort_keys) = kwargs.kwarg_bool("sort_keys", None) {
+        opt.sort_keys = sort_keys;
+    }
+    if let Some(ignore_private) = kwargs.kwarg_bool("ignore_private", None) {
+        opt.ignore_private = ignore_private;
+    }
+    if let Some(ignore_none) = kwargs.kwarg_bool("ignore_none", None) {
+        opt.ignore_none = ignore_none;
+    }
+    if let Some(indent) = kwargs.kwarg_int("indent", None) {
+        opt.indent = indent;
+    }
+    if let Some(block_scalars) = kwargs.kwarg_bool("block_scalars", None) {
+        opt.block_scalars = block_scalars;
+    }
+    if let Some(flow_style) = kwargs.kwarg_bool("flow_style", None) {
+        opt.flow_style = flow_style;
+    }
+    opt
+}
+
+/// Raise a KCL ValueError with the position of the invalid YAML string.
+fn yaml_decode_error(err: YamlDecodeError) -> ! {
+    let ctx = Context::current_context_mut();
+    ctx.set_err_type(&ErrType::ValueError_TYPE);
+
+    panic!("failed to decode yaml: {}", err)
+}
@@ -1,10 +1,163 @@
 // Copyright 2021 The KCL Authors. All rights reserved.
 
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+
+use crate::ty::{Parameter, Type};
+
+macro_rules! register_system_module_members {
+    ($types:ident, $($name:ident => $ty:expr)*) => (
+        // System module function type map.
+        pub const $types: Lazy<IndexMap<String, Type>> = Lazy::new(|| {
+            let mut builtin_mapping = IndexMap::default();
+            $( builtin_mapping.insert(stringify!($name).to_string(), $ty); )*
+            builtin_mapping
+        });
+    )
+}
+
 pub const BASE64: &str = "base64";
-pub const BASE64_FUNCTION_NAMES: [&str; 2] = ["encode", "decode"];
+pub const BASE64_FUNCTION_NAMES: [&str; 8] = [
+    "encode",
+    "decode",
+    "urlsafe_encode",
+    "urlsafe_decode",
+    "base32_encode",
+    "base32_decode",
+    "hex_encode",
+    "hex_decode",
+];
+
+register_system_module_members! {
+    BASE64_FUNCTION_TYPES,
+    encode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Encode the string with the standard base64 encoding."#,
+        false,
+        None,
+    )
+    decode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Decode the standard base64 encoded string."#,
+        false,
+        None,
+    )
+    urlsafe_encode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Encode the string with the URL and filename safe base64 encoding."#,
+        false,
+        None,
+    )
+    urlsafe_decode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Decode the URL and filename safe base64 encoded string."#,
+        false,
+        None,
+    )
+    base32_encode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Encode the string with the RFC 4648 base32 encoding."#,
+        false,
+        None,
+    )
+    base32_decode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Decode the RFC 4648 base32 encoded string."#,
+        false,
+        None,
+    )
+    hex_encode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Encode the string with the lowercase hex encoding."#,
+        false,
+        None,
+    )
+    hex_decode => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Decode the hex encoded string."#,
+        false,
+        None,
+    )
+}
 
 pub const NET: &str = "net";
-pub const NET_FUNCTION_NAMES: [&str; 16] = [
+pub const NET_FUNCTION_NAMES: [&str; 21] = [
     "split_host_port",
     "join_host_port",
     "fqdn",
@@ -21,8 +174,104 @@ pub const NET_FUNCTION_NAMES: [&str; 16] = [
     "is_link_local_unicast_IP",
     "is_global_unicast_IP",
     "is_unspecified_IP",
+    "parse_CIDR",
+    "CIDR_contains",
+    "CIDR_overlaps",
+    "subnets",
+    "host_count",
 ];
 
+register_system_module_members! {
+    NET_FUNCTION_TYPES,
+    parse_CIDR => Type::function(
+        None,
+        Type::dict_ref(Rc::new(Type::STR), Rc::new(Type::ANY)),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Parse the IPv4 or IPv6 CIDR e.g., "10.0.0.0/16" and return the dict with the keys "ip", "network",
+        "prefix", "netmask", "first", "last" and "version"."#,
+        false,
+        None,
+    )
+    CIDR_contains => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "ip".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Return True if the IP address or the CIDR subnet `ip` is in the CIDR."#,
+        false,
+        None,
+    )
+    CIDR_overlaps => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "a".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "b".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Return True if the CIDRs `a` and `b` have any common IP address."#,
+        false,
+        None,
+    )
+    subnets => Type::function(
+        None,
+        Type::list_ref(Rc::new(Type::STR)),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "new_prefix".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: false,
+            },
+        ],
+        r#"Split the CIDR into the subnets with the prefix length `new_prefix`, at most 65536 subnets are returned."#,
+        false,
+        None,
+    )
+    host_count => Type::function(
+        None,
+        Rc::new(Type::INT),
+        &[
+            Parameter {
+                name: "cidr".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Return the number of the host addresses in the CIDR, the network and broadcast addresses
+        of IPv4 CIDRs with the prefix length less than 31 are excluded."#,
+        false,
+        None,
+    )
+}
+
 pub const MATH: &str = "math";
 pub const MATH_FUNCTION_NAMES: [&str; 16] = [
     "ceil",
@@ -44,21 +293,367 @@ pub const MATH_FUNCTION_NAMES: [&str; 16] = [
 ];
 
 pub const DATETIME: &str = "datetime";
-pub const DATETIME_FUNCTION_NAMES: [&str; 4] = ["today", "now", "ticks", "date"];
+pub const DATETIME_FUNCTION_NAMES: [&str; 9] = [
+    "today", "now", "ticks", "date", "parse", "format", "validate", "duration", "add",
+];
+
+register_system_module_members! {
+    DATETIME_FUNCTION_TYPES,
+    today => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[],
+        r#"Return the local date string of today."#,
+        false,
+        None,
+    )
+    now => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[],
+        r#"Return the local datetime string of now."#,
+        false,
+        None,
+    )
+    ticks => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[],
+        r#"Return the current time in seconds since the Epoch."#,
+        false,
+        None,
+    )
+    date => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[],
+        r#"Return the local date string of today."#,
+        false,
+        None,
+    )
+    parse => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "s".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "fmt".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+            Parameter {
+                name: "tz".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Parse the datetime string with the strftime format and return the time in seconds since the Epoch.
+        The datetime without the offset is in the timezone `tz`, the default timezone is UTC."#,
+        false,
+        None,
+    )
+    format => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "ts".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+            Parameter {
+                name: "fmt".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+            Parameter {
+                name: "tz".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Format the time in seconds since the Epoch with the strftime format in the timezone `tz`,
+        the default timezone is UTC."#,
+        false,
+        None,
+    )
+    validate => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "s".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "fmt".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return True if the datetime string matches the strftime format."#,
+        false,
+        None,
+    )
+    duration => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "s".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Parse the duration string e.g., "1h30m" and return the duration in seconds.
+        Valid units are "ns", "us", "ms", "s", "m", "h", "d" and "w"."#,
+        false,
+        None,
+    )
+    add => Type::function(
+        None,
+        Rc::new(Type::FLOAT),
+        &[
+            Parameter {
+                name: "ts".to_string(),
+                ty: Type::number(),
+                has_default: false,
+            },
+            Parameter {
+                name: "duration".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::STR), Type::number()]),
+                has_default: false,
+            },
+        ],
+        r#"Add the duration string or the duration in seconds to the time in seconds since the Epoch."#,
+        false,
+        None,
+    )
+}
 
 pub const REGEX: &str = "regex";
-pub const REGEX_FUNCTION_NAMES: [&str; 6] =
-    ["replace", "match", "compile", "findall", "search", "split"];
+pub const REGEX_FUNCTION_NAMES: [&str; 8] = [
+    "replace",
+    "match",
+    "compile",
+    "findall",
+    "search",
+    "split",
+    "groups",
+    "named_groups",
+];
 
 pub const YAML: &str = "yaml";
-pub const YAML_FUNCTION_NAMES: [&str; 3] = ["encode", "decode", "dump_to_file"];
+pub const YAML_FUNCTION_NAMES: [&str; 5] = [
+    "encode",
+    "decode",
+    "encode_all",
+    "decode_all",
+    "dump_to_file",
+];
 
 pub const JSON: &str = "json";
 pub const JSON_FUNCTION_NAMES: [&str; 3] = ["encode", "decode", "dump_to_file"];
 
 pub const CRYPTO: &str = "crypto";
-pub const CRYPTO_FUNCTION_NAMES: [&str; 6] =
-    ["md5", "sha1", "sha224", "sha256", "sha384", "sha512"];
+pub const CRYPTO_FUNCTION_NAMES: [&str; 9] = [
+    "md5",
+    "sha1",
+    "sha224",
+    "sha256",
+    "sha384",
+    "sha512",
+    "uuid",
+    "hmac_sha256",
+    "blake3",
+];
+
+register_system_module_members! {
+    CRYPTO_FUNCTION_TYPES,
+    md5 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return the MD5 hex digest of the string."#,
+        false,
+        None,
+    )
+    sha1 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return the SHA-1 hex digest of the string."#,
+        false,
+        None,
+    )
+    sha224 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return the SHA-224 hex digest of the string."#,
+        false,
+        None,
+    )
+    sha256 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return the SHA-256 hex digest of the string."#,
+        false,
+        None,
+    )
+    sha384 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return the SHA-384 hex digest of the string."#,
+        false,
+        None,
+    )
+    sha512 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "encoding".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Return the SHA-512 hex digest of the string."#,
+        false,
+        None,
+    )
+    uuid => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "version".to_string(),
+                ty: Rc::new(Type::INT),
+                has_default: true,
+            },
+            Parameter {
+                name: "name".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+            Parameter {
+                name: "namespace".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+            Parameter {
+                name: "seed".to_string(),
+                ty: Type::union_ref(&[Rc::new(Type::INT), Rc::new(Type::STR)]),
+                has_default: true,
+            },
+        ],
+        r#"Generate a UUID string. The version 4 UUID is random, or deterministic when `seed` is given.
+        The version 5 UUID is generated from the SHA-1 hash of `name` in `namespace`, the namespace
+        is "dns", "url", "oid", "x500" or a UUID string and the default namespace is "dns"."#,
+        false,
+        None,
+    )
+    hmac_sha256 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "key".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Return the HMAC-SHA256 hex digest of the string with the key."#,
+        false,
+        None,
+    )
+    blake3 => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "value".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Return the BLAKE3 hex digest of the string."#,
+        false,
+        None,
+    )
+}
 
 pub const TESTING: &str = "testing";
 pub const TESTING_FUNCTION_NAMES: [&str; 2] = ["arguments", "setting_file"];
@@ -90,11 +685,144 @@ pub const UNITS_FIELD_NAMES: [&str; 15] = [
 pub const COLLECTION: &str = "collection";
 pub const COLLECTION_FUNCTION_NAMES: [&str; 1] = ["union_all"];
 
-pub const STANDARD_SYSTEM_MODULES: [&str; 11] = [
-    COLLECTION, NET, MATH, DATETIME, REGEX, YAML, JSON, CRYPTO, BASE64, TESTING, UNITS,
+pub const FILE: &str = "file";
+pub const FILE_FUNCTION_NAMES: [&str; 5] = ["read", "glob", "exists", "modpath", "workdir"];
+
+pub const TEMPLATE: &str = "template";
+pub const TEMPLATE_FUNCTION_NAMES: [&str; 2] = ["execute", "html_escape"];
+
+register_system_module_members! {
+    TEMPLATE_FUNCTION_TYPES,
+    execute => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "template".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "data".to_string(),
+                ty: Type::dict_ref(Rc::new(Type::STR), Rc::new(Type::ANY)),
+                has_default: true,
+            },
+            Parameter {
+                name: "escape".to_string(),
+                ty: Rc::new(Type::BOOL),
+                has_default: true,
+            },
+        ],
+        r#"Render the Handlebars template with the data. The values are not HTML-escaped unless `escape` is True,
+        and referencing a missing value is an error."#,
+        false,
+        None,
+    )
+    html_escape => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "data".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Escape the HTML special characters of the string."#,
+        false,
+        None,
+    )
+}
+
+pub const SEMVER: &str = "semver";
+pub const SEMVER_FUNCTION_NAMES: [&str; 4] = ["parse", "compare", "satisfies", "bump"];
+
+register_system_module_members! {
+    SEMVER_FUNCTION_TYPES,
+    parse => Type::function(
+        None,
+        Type::dict_ref(Rc::new(Type::STR), Rc::new(Type::ANY)),
+        &[
+            Parameter {
+                name: "version".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Parse the semantic version e.g., "v1.2.3-rc.1+build.5" and return the dict with the keys
+        "major", "minor", "patch", "pre" and "build"."#,
+        false,
+        None,
+    )
+    compare => Type::function(
+        None,
+        Rc::new(Type::INT),
+        &[
+            Parameter {
+                name: "v1".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "v2".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Compare the semantic versions and return -1, 0 or 1 when `v1` is less than, equal to
+        or greater than `v2`. The build metadata is ignored."#,
+        false,
+        None,
+    )
+    satisfies => Type::function(
+        None,
+        Rc::new(Type::BOOL),
+        &[
+            Parameter {
+                name: "version".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "constraint".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+        ],
+        r#"Return True if the semantic version satisfies the constraint e.g., "^1.2", "~1.2.3",
+        ">=1.0, <2.0" or "1.*". The "||" separated constraints are satisfied when any of them is satisfied."#,
+        false,
+        None,
+    )
+    bump => Type::function(
+        None,
+        Rc::new(Type::STR),
+        &[
+            Parameter {
+                name: "version".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: false,
+            },
+            Parameter {
+                name: "part".to_string(),
+                ty: Rc::new(Type::STR),
+                has_default: true,
+            },
+        ],
+        r#"Increment the "major", "minor" or "patch" part of the semantic version and reset the lower parts,
+        the default part is "patch". A prerelease version is promoted to its release version when the lower
+        parts are 0 e.g., bumping the patch of "1.2.3-rc.1" returns "1.2.3"."#,
+        false,
+        None,
+    )
+}
+
+pub const STANDARD_SYSTEM_MODULES: [&str; 14] = [
+    COLLECTION, NET, MATH, DATETIME, REGEX, YAML, JSON, CRYPTO, BASE64, TESTING, UNITS, FILE,
+    TEMPLATE, SEMVER,
 ];
 
-pub const STANDARD_SYSTEM_MODULE_NAMES_WITH_AT: [&str; 11] = [
+pub const STANDARD_SYSTEM_MODULE_NAMES_WITH_AT: [&str; 14] = [
     "@collection",
     "@net",
     "@math",
@@ -106,6 +834,9 @@ pub const STANDARD_SYSTEM_MODULE_NAMES_WITH_AT: [&str; 11] = [
     "@base64",
     "@testing",
     "@units",
+    "@file",
+    "@template",
+    "@semver",
 ];
 
 /// Get the system module members
@@ -126,6 +857,24 @@ pub fn get_system_module_members(name: &str) -> Vec<&str> {
             members
         }
         COLLECTION => COLLECTION_FUNCTION_NAMES.to_vec(),
+        FILE => FILE_FUNCTION_NAMES.to_vec(),
+        TEMPLATE => TEMPLATE_FUNCTION_NAMES.to_vec(),
+        SEMVER => SEMVER_FUNCTION_NAMES.to_vec(),
         _ => bug!("invalid system module name '{}'", name),
     }
 }
+
+/// Get the function type of the system module member, the type of members
+/// without the signature is `any`.
+pub fn get_system_module_member_ty(name: &str, member: &str) -> Rc<Type> {
+    let ty = match name {
+        BASE64 => BASE64_FUNCTION_TYPES.get(member).cloned(),
+        NET => NET_FUNCTION_TYPES.get(member).cloned(),
+        DATETIME => DATETIME_FUNCTION_TYPES.get(member).cloned(),
+        CRYPTO => CRYPTO_FUNCTION_TYPES.get(member).cloned(),
+        TEMPLATE => TEMPLATE_FUNCTION_TYPES.get(member).cloned(),
+        SEMVER => SEMVER_FUNCTION_TYPES.get(member).cloned(),
+        _ => None,
+    };
+    Rc::new(ty.unwrap_or(Type::ANY))
+}
@@ -1,8 +1,14 @@
 use crate::lint::lint::{LintArray, LintContext};
 use crate::lint::lintpass::LintPass;
+use crate::lint::lints_def::ConstantCheck;
 use crate::lint::lints_def::ImportPosition;
+use crate::lint::lints_def::IncompatibleAttrType;
+use crate::lint::lints_def::NoneComparison;
 use crate::lint::lints_def::ReImport;
+use crate::lint::lints_def::Shadowing;
+use crate::lint::lints_def::UnusedArgument;
 use crate::lint::lints_def::UnusedImport;
+use crate::lint::lints_def::UnusedVariable;
 use crate::lint_methods;
 use crate::resolver::scope::Scope;
 use kclvm_ast::ast;
@@ -112,6 +118,12 @@ macro_rules! default_lint_passes {
                 ImportPosition: ImportPosition,
                 UnusedImport: UnusedImport,
                 ReImport: ReImport,
+                UnusedVariable: UnusedVariable,
+                UnusedArgument: UnusedArgument,
+                Shadowing: Shadowing,
+                IncompatibleAttrType: IncompatibleAttrType,
+                ConstantCheck: ConstantCheck,
+                NoneComparison: NoneComparison,
             ]
         );
     };
@@ -0,0 +1,230 @@
+use crate::lint::combinedlintpass::CombinedLintPass;
+use crate::lint::lint::Lint;
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::ast;
+use kclvm_error::{Diagnostic, DiagnosticId, Level};
+
+/// The prefix of the inline lint suppression comments e.g.,
+/// `# kcl-lint: disable=W0411` and `# kcl-lint: disable-file=unused_import`.
+pub const LINT_COMMENT_PREFIX: &str = "kcl-lint:";
+
+/// The configured level of a lint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    /// The lint is disabled.
+    Allow,
+    /// The lint is reported as a warning.
+    Warn,
+    /// The lint is reported as an error.
+    Deny,
+}
+
+/// Lint configuration e.g., the `[lint]` section of the kcl.mod file. Each item is a lint
+/// name (case insensitive) e.g., `unused_import` or a lint code e.g., `W0411`. When a lint
+/// appears in more than one list, `deny` takes precedence over `warn` and `warn` takes
+/// precedence over `allow`.
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    pub allow: Vec<String>,
+    pub warn: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl LintConfig {
+    /// Get the configured level of the lint, returns `None` if the lint is not configured.
+    pub fn level(&self, lint: &Lint) -> Option<LintLevel> {
+        self.rule_level(&LintRule::Builtin(lint))
+    }
+
+    fn rule_level(&self, rule: &LintRule) -> Option<LintLevel> {
+        if self.deny.iter().any(|item| rule.matches(item)) {
+            Some(LintLevel::Deny)
+        } else if self.warn.iter().any(|item| rule.matches(item)) {
+            Some(LintLevel::Warn)
+        } else if self.allow.iter().any(|item| rule.matches(item)) {
+            Some(LintLevel::Allow)
+        } else {
+            None
+        }
+    }
+
+    /// Apply the config on the lint diagnostics including the diagnostics of the lint
+    /// plugins: allowed lints are removed and denied lints are raised to errors. Other
+    /// diagnostics are kept as they are.
+    pub fn apply(&self, diagnostics: &mut IndexSet<Diagnostic>) {
+        let lints = CombinedLintPass::get_lints();
+        let mut result = IndexSet::new();
+        for mut diag in std::mem::take(diagnostics) {
+            if let Some(rule) = diagnostic_rule(&lints, &diag) {
+                match self.rule_level(&rule) {
+                    Some(LintLevel::Allow) => continue,
+                    Some(LintLevel::Deny) => diag.level = Level::Error,
+                    Some(LintLevel::Warn) | None => {}
+                }
+            }
+            result.insert(diag);
+        }
+        *diagnostics = result;
+    }
+}
+
+/// Inline lint suppressions collected from the comments of the modules. A comment
+/// `# kcl-lint: disable=W0411,reimport` at the end of a line suppresses the lints on
+/// that line, and the comment on its own line suppresses the lints on the next line. A
+/// comment `# kcl-lint: disable-file=W0411` suppresses the lints in the whole file.
+/// When no lint is given e.g., `# kcl-lint: disable`, all lints are suppressed.
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    /// Suppressed lints of the whole file with the filename.
+    files: IndexMap<String, Vec<String>>,
+    /// Suppressed lints of the lines with the filename and the line.
+    lines: IndexMap<(String, u64), Vec<String>>,
+}
+
+impl Suppressions {
+    /// Collect the inline suppressions from the comments of all modules in the program.
+    pub fn from_program(program: &ast::Program) -> Self {
+        let mut suppressions = Suppressions::default();
+        for modules in program.pkgs.values() {
+            for module in modules {
+                suppressions.add_module(module);
+            }
+        }
+        suppressions
+    }
+
+    /// Collect the inline suppressions from the comments of the module.
+    pub fn add_module(&mut self, module: &ast::Module) {
+        let mut stmt_columns = IndexMap::new();
+        collect_stmt_columns(&module.body, &mut stmt_columns);
+        for comment in &module.comments {
+            let text = comment.node.text.trim_start_matches('#').trim();
+            let directive = match text.strip_prefix(LINT_COMMENT_PREFIX) {
+                Some(directive) => directive.trim(),
+                None => continue,
+            };
+            let (command, items) = match directive.split_once('=') {
+                Some((command, items)) => (
+                    command.trim(),
+                    items
+                        .split(',')
+                        .map(|item| item.trim().to_string())
+                        .filter(|item| !item.is_empty())
+                        .collect(),
+                ),
+                None => (directive, vec![]),
+            };
+            let items = if items.is_empty() {
+                vec!["all".to_string()]
+            } else {
+                items
+            };
+            match command {
+                "disable" => {
+                    // A trailing comment follows a statement on the same line.
+                    let is_trailing = stmt_columns
+                        .get(&comment.line)
+                        .map_or(false, |column| *column < comment.column);
+                    let line = if is_trailing {
+                        comment.line
+                    } else {
+                        comment.line + 1
+                    };
+                    self.lines
+                        .entry((module.filename.clone(), line))
+                        .or_default()
+                        .extend(items);
+                }
+                "disable-file" => self
+                    .files
+                    .entry(module.filename.clone())
+                    .or_default()
+                    .extend(items),
+                _ => {}
+            }
+        }
+    }
+
+    /// Whether the lint at the position of the diagnostic is suppressed.
+    pub fn is_suppressed(&self, lint: &Lint, diag: &Diagnostic) -> bool {
+        self.is_rule_suppressed(&LintRule::Builtin(lint), diag)
+    }
+
+    fn is_rule_suppressed(&self, rule: &LintRule, diag: &Diagnostic) -> bool {
+        let pos = match diag.messages.first() {
+            Some(msg) => &msg.pos,
+            None => return false,
+        };
+        let matches = |items: &Vec<String>| {
+            items
+                .iter()
+                .any(|item| item.eq_ignore_ascii_case("all") || rule.matches(item))
+        };
+        self.files.get(&pos.filename).map_or(false, matches)
+            || self
+                .lines
+                .get(&(pos.filename.clone(), pos.line))
+                .map_or(false, matches)
+    }
+
+    /// Remove the suppressed lint diagnostics including the diagnostics of the lint plugins.
+    pub fn apply(&self, diagnostics: &mut IndexSet<Diagnostic>) {
+        let lints = CombinedLintPass::get_lints();
+        diagnostics.retain(|diag| match diagnostic_rule(&lints, diag) {
+            Some(rule) => !self.is_rule_suppressed(&rule, diag),
+            None => true,
+        });
+    }
+}
+
+/// The rule which reports a lint diagnostic, a builtin lint or a rule of the lint plugins.
+enum LintRule<'a> {
+    Builtin(&'a Lint),
+    /// The rule code of the lint plugin e.g., `HOUSE001`.
+    Plugin(&'a str),
+}
+
+impl LintRule<'_> {
+    /// Whether the config item is the lint name or the lint code.
+    fn matches(&self, item: &str) -> bool {
+        match self {
+            LintRule::Builtin(lint) => {
+                lint.code.eq_ignore_ascii_case(item)
+                    || lint.name.trim_matches('"').eq_ignore_ascii_case(item)
+            }
+            LintRule::Plugin(code) => code.eq_ignore_ascii_case(item),
+        }
+    }
+}
+
+/// Collect the first statement column of each line, including the statements nested
+/// in the schema and if statements.
+fn collect_stmt_columns(body: &[ast::NodeRef<ast::Stmt>], columns: &mut IndexMap<u64, u64>) {
+    for stmt in body {
+        let column = columns.entry(stmt.line).or_insert(stmt.column);
+        *column = (*column).min(stmt.column);
+        match &stmt.node {
+            ast::Stmt::Schema(schema_stmt) => collect_stmt_columns(&schema_stmt.body, columns),
+            ast::Stmt::If(if_stmt) => {
+                collect_stmt_columns(&if_stmt.body, columns);
+                collect_stmt_columns(&if_stmt.orelse, columns);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Get the rule which reports the diagnostic with the warning code or the plugin rule code.
+fn diagnostic_rule<'a>(lints: &[&'a Lint], diag: &'a Diagnostic) -> Option<LintRule<'a>> {
+    match &diag.code {
+        Some(DiagnosticId::Warning(kind)) => {
+            let code = kind.code()?;
+            lints
+                .iter()
+                .find(|lint| lint.code == code)
+                .map(|lint| LintRule::Builtin(*lint))
+        }
+        Some(DiagnosticId::Plugin(code)) => Some(LintRule::Plugin(code)),
+        _ => None,
+    }
+}
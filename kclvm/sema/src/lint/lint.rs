@@ -6,6 +6,8 @@ use kclvm_error::{Level, Position};
 pub struct LintContext {
     /// What source file are we in.
     pub filename: String,
+    /// What package are we in.
+    pub pkgpath: String,
     /// Are we resolving the ast node start position.
     pub start_pos: Position,
     /// Are we resolving the ast node end position.
@@ -56,10 +56,10 @@ macro_rules! lint_methods {
             // fn check_schema_expr(schema_expr: &ast::SchemaExpr);
             // fn check_config_expr(config_expr: &ast::ConfigExpr);
             // fn check_check_expr(check_expr: &ast::CheckExpr);
-            // fn check_lambda_expr(lambda_expr: &ast::LambdaExpr);
+            fn check_lambda_expr(_lambda_expr: &ast::LambdaExpr);
             // fn check_keyword(keyword: &ast::Keyword);
             // fn check_arguments(arguments: &ast::Arguments);
-            // fn check_compare(compare: &ast::Compare);
+            fn check_compare(_compare: &ast::Compare);
             // fn check_identifier(id: &ast::Identifier);
             // fn check_number_lit(number_lit: &ast::NumberLit);
             // fn check_string_lit(string_lit: &ast::StringLit);
@@ -1,10 +1,14 @@
+use crate::builtin::BUILTIN_FUNCTION_NAMES;
 use crate::lint::lint::{Lint, LintArray, LintContext};
 use crate::lint::lintpass::LintPass;
+use crate::resolver::pos::GetPos;
 use crate::resolver::scope::Scope;
+use crate::ty::{is_upper_bound, TypeKind};
 use crate::{declare_lint_pass, resolver::scope::ScopeObjectKind};
-use indexmap::IndexSet;
-use kclvm_ast::ast;
-use kclvm_error::{Handler, Level, Message, Position, Style, WarningKind};
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::{ast, MAIN_PKG};
+use kclvm_ast::walker::MutSelfWalker;
+use kclvm_error::{Handler, Level, Message, Position, Style, Suggestion, WarningKind};
 
 /// The 'import_position' lint detects import statements that are not declared at the top of file.
 /// ### Example
@@ -43,9 +47,18 @@ impl LintPass for ImportPosition {
             }
         }
         for stmt in &module.body {
-            if let ast::Stmt::Import(_import_stmt) = &stmt.node {
+            if let ast::Stmt::Import(import_stmt) = &stmt.node {
                 if stmt.line > first_non_importstmt {
-                    handler.add_warning(
+                    // Move the import statement before the first non-import statement.
+                    let suggestions = [
+                        Suggestion::insert_before_line(
+                            &ctx.filename,
+                            first_non_importstmt,
+                            &format!("{}\n", import_stmt_text(import_stmt)),
+                        ),
+                        Suggestion::delete_lines(&ctx.filename, stmt.line, stmt.end_line),
+                    ];
+                    handler.add_warning_with_suggestions(
                         WarningKind::ImportPositionWarning,
                         &[Message {
                             pos: Position {
@@ -61,6 +74,7 @@ impl LintPass for ImportPosition {
                                 "Consider moving tihs statement to the top of the file".to_string(),
                             ),
                         }],
+                        &suggestions,
                     );
                 }
             }
@@ -68,6 +82,14 @@ impl LintPass for ImportPosition {
     }
 }
 
+/// Get the source text of the import statement e.g., `import pkg.a as b`.
+fn import_stmt_text(import_stmt: &ast::ImportStmt) -> String {
+    match &import_stmt.asname {
+        Some(asname) => format!("import {} as {}", import_stmt.rawpath, asname),
+        None => format!("import {}", import_stmt.rawpath),
+    }
+}
+
 /// The 'unused_import' lint detects import statements that are declared but not used.
 ///
 /// ### Example
@@ -98,7 +120,7 @@ impl LintPass for UnusedImport {
         for (_, scope_obj) in scope_objs {
             let scope_obj = scope_obj.borrow();
             if scope_obj.kind == ScopeObjectKind::Module && scope_obj.used == false {
-                handler.add_warning(
+                handler.add_warning_with_suggestions(
                     WarningKind::UnusedImportWarning,
                     &[Message {
                         pos: Position {
@@ -110,6 +132,11 @@ impl LintPass for UnusedImport {
                         message: format!("Module '{}' imported but unused", scope_obj.name),
                         note: Some("Consider removing this statement".to_string()),
                     }],
+                    &[Suggestion::delete_lines(
+                        &scope_obj.start.filename,
+                        scope_obj.start.line,
+                        scope_obj.end.line,
+                    )],
                 );
             }
         }
@@ -146,7 +173,7 @@ impl LintPass for ReImport {
         for stmt in &module.body {
             if let ast::Stmt::Import(import_stmt) = &stmt.node {
                 if import_names.contains(&import_stmt.path) {
-                    handler.add_warning(
+                    handler.add_warning_with_suggestions(
                         WarningKind::ReimportWarning,
                         &[Message {
                             pos: Position {
@@ -161,6 +188,11 @@ impl LintPass for ReImport {
                             ),
                             note: Some("Consider removing this statement".to_string()),
                         }],
+                        &[Suggestion::delete_lines(
+                            &ctx.filename,
+                            stmt.line,
+                            stmt.end_line,
+                        )],
                     );
                 } else {
                     import_names.insert(import_stmt.path.clone());
@@ -169,3 +201,501 @@ impl LintPass for ReImport {
         }
     }
 }
+
+/// The 'unused_variable' lint detects private variables that are assigned but never used.
+///
+/// ### Example
+///
+/// ```kcl
+/// _name = "Alice"
+/// age = 18
+///
+/// ```
+/// ### Explanation
+///
+/// Private variables are not output and can only be referenced in the module, an unused private
+/// variable is usually a mistake or dead code. Only the modules of the main package are checked.
+pub static UNUSED_VARIABLE: &Lint = &Lint {
+    name: stringify!("UNUSED_VARIABLE"),
+    level: Level::Warning,
+    desc: "Check for unused private variables",
+    code: "W0612",
+    note: Some("Consider removing this variable"),
+};
+
+declare_lint_pass!(UnusedVariable => [UNUSED_VARIABLE]);
+
+impl LintPass for UnusedVariable {
+    fn check_module(
+        &mut self,
+        handler: &mut Handler,
+        ctx: &mut LintContext,
+        module: &ast::Module,
+    ) {
+        if ctx.pkgpath != MAIN_PKG {
+            return;
+        }
+        let mut loaded = LoadedNames::default();
+        loaded.walk_module(module);
+        check_unused_private_variables(handler, &module.body, &loaded.names);
+    }
+
+    fn check_lambda_expr(
+        &mut self,
+        handler: &mut Handler,
+        ctx: &mut LintContext,
+        lambda_expr: &ast::LambdaExpr,
+    ) {
+        if ctx.pkgpath != MAIN_PKG {
+            return;
+        }
+        let mut loaded = LoadedNames::default();
+        for stmt in &lambda_expr.body {
+            loaded.walk_stmt(&stmt.node);
+        }
+        // The last statement is the return value of the lambda expression.
+        let stmts = &lambda_expr.body[..lambda_expr.body.len().saturating_sub(1)];
+        check_unused_private_variables(handler, stmts, &loaded.names);
+    }
+}
+
+fn check_unused_private_variables(
+    handler: &mut Handler,
+    stmts: &[ast::NodeRef<ast::Stmt>],
+    loaded: &IndexSet<String>,
+) {
+    let mut targets = vec![];
+    private_assign_targets(stmts, &mut targets);
+    let mut reported = IndexSet::<String>::new();
+    for target in targets {
+        let name = &target.node.names[0];
+        if loaded.contains(name) || !reported.insert(name.clone()) {
+            continue;
+        }
+        handler.add_warning(
+            WarningKind::UnusedVariableWarning,
+            &[Message {
+                pos: target.get_pos(),
+                style: Style::LineAndColumn,
+                message: format!("Private variable '{}' is assigned but never used", name),
+                note: Some("Consider removing this variable".to_string()),
+            }],
+        );
+    }
+}
+
+/// Collect the private variable targets of the assign statements, including the
+/// statements in the if statement.
+fn private_assign_targets<'a>(
+    stmts: &'a [ast::NodeRef<ast::Stmt>],
+    targets: &mut Vec<&'a ast::NodeRef<ast::Identifier>>,
+) {
+    for stmt in stmts {
+        match &stmt.node {
+            ast::Stmt::Assign(assign_stmt) => {
+                for target in &assign_stmt.targets {
+                    let names = &target.node.names;
+                    if names.len() == 1 && names[0].starts_with('_') && names[0] != "_" {
+                        targets.push(target);
+                    }
+                }
+            }
+            ast::Stmt::If(if_stmt) => {
+                private_assign_targets(&if_stmt.body, targets);
+                private_assign_targets(&if_stmt.orelse, targets);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The 'unused_argument' lint detects lambda parameters that are never used in the lambda body.
+///
+/// ### Example
+///
+/// ```kcl
+/// add = lambda x: int, y: int {
+///     x + 1
+/// }
+///
+/// ```
+/// ### Explanation
+///
+/// An unused lambda parameter is usually a mistake, the parameter can be prefixed with `_`
+/// to indicate that it is unused intentionally.
+pub static UNUSED_ARGUMENT: &Lint = &Lint {
+    name: stringify!("UNUSED_ARGUMENT"),
+    level: Level::Warning,
+    desc: "Check for unused lambda parameters",
+    code: "W0613",
+    note: Some("Consider removing this parameter or renaming it with the prefix '_'"),
+};
+
+declare_lint_pass!(UnusedArgument => [UNUSED_ARGUMENT]);
+
+impl LintPass for UnusedArgument {
+    fn check_lambda_expr(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        lambda_expr: &ast::LambdaExpr,
+    ) {
+        if let Some(args) = &lambda_expr.args {
+            let mut loaded = LoadedNames::default();
+            for stmt in &lambda_expr.body {
+                loaded.walk_stmt(&stmt.node);
+            }
+            for arg in &args.node.args {
+                let name = arg.node.get_name();
+                if name.starts_with('_') || loaded.names.contains(&name) {
+                    continue;
+                }
+                handler.add_warning(
+                    WarningKind::UnusedArgumentWarning,
+                    &[Message {
+                        pos: arg.get_pos(),
+                        style: Style::LineAndColumn,
+                        message: format!("Lambda parameter '{}' is never used", name),
+                        note: Some(
+                            "Consider removing this parameter or renaming it with the prefix '_'"
+                                .to_string(),
+                        ),
+                    }],
+                );
+            }
+        }
+    }
+}
+
+/// The 'shadowing' lint detects names that shadow builtin functions or imported modules.
+///
+/// ### Example
+///
+/// ```kcl
+/// import math
+///
+/// len = 1
+/// math = 2
+///
+/// ```
+/// ### Explanation
+///
+/// A name shadowing a builtin function or an imported module makes the builtin function or
+/// the module inaccessible and confusing to readers.
+pub static SHADOWING: &Lint = &Lint {
+    name: stringify!("SHADOWING"),
+    level: Level::Warning,
+    desc: "Check for names shadowing builtin functions or imported modules",
+    code: "W0622",
+    note: Some("Consider renaming this variable"),
+};
+
+declare_lint_pass!(Shadowing => [SHADOWING]);
+
+impl LintPass for Shadowing {
+    fn check_module(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        module: &ast::Module,
+    ) {
+        let mut import_names = IndexSet::<String>::new();
+        for stmt in &module.body {
+            if let ast::Stmt::Import(import_stmt) = &stmt.node {
+                import_names.insert(import_stmt.name.clone());
+            }
+        }
+        let mut defined = DefinedNames::default();
+        defined.walk_module(module);
+        for (name, pos) in defined.names {
+            let shadowed = if import_names.contains(&name) {
+                "imported module"
+            } else if BUILTIN_FUNCTION_NAMES.contains(&name.as_str()) {
+                "builtin function"
+            } else {
+                continue;
+            };
+            handler.add_warning(
+                WarningKind::ShadowingWarning,
+                &[Message {
+                    pos,
+                    style: Style::LineAndColumn,
+                    message: format!("Variable '{}' shadows the {} '{}'", name, shadowed, name),
+                    note: Some("Consider renaming this variable".to_string()),
+                }],
+            );
+        }
+    }
+}
+
+/// The 'incompatible_attr_type' lint detects schema attributes that are redefined with a type
+/// incompatible with the type defined in the mixin schemas.
+///
+/// ### Example
+///
+/// ```kcl
+/// schema NameMixin:
+///     name: int
+///
+/// schema Person:
+///     mixin [NameMixin]
+///     name: str
+///
+/// ```
+/// ### Explanation
+///
+/// The type checker checks attributes redefined in a subclass against its base schema, but
+/// attributes of mixins are not checked, so the mixin may get a value of an unexpected type.
+pub static INCOMPATIBLE_ATTR_TYPE: &Lint = &Lint {
+    name: stringify!("INCOMPATIBLE_ATTR_TYPE"),
+    level: Level::Warning,
+    desc: "Check for schema attributes redefined with incompatible types",
+    code: "W0236",
+    note: Some("Consider using the same type as the mixin attribute"),
+};
+
+declare_lint_pass!(IncompatibleAttrType => [INCOMPATIBLE_ATTR_TYPE]);
+
+impl LintPass for IncompatibleAttrType {
+    fn check_scope(&mut self, handler: &mut Handler, _ctx: &mut LintContext, scope: &Scope) {
+        for (_, scope_obj) in &scope.elems {
+            let scope_obj = scope_obj.borrow();
+            if scope_obj.kind != ScopeObjectKind::Definition {
+                continue;
+            }
+            let schema_ty = match &scope_obj.ty.kind {
+                TypeKind::Schema(schema_ty) => schema_ty,
+                _ => continue,
+            };
+            for mixin in &schema_ty.mixins {
+                for (name, attr) in &schema_ty.attrs {
+                    if name == kclvm::SCHEMA_SETTINGS_ATTR_NAME || attr.ty.is_any() {
+                        continue;
+                    }
+                    let mixin_attr = match mixin.get_obj_of_attr(name) {
+                        Some(mixin_attr) if !mixin_attr.ty.is_any() => mixin_attr,
+                        _ => continue,
+                    };
+                    if !is_upper_bound(mixin_attr.ty.clone(), attr.ty.clone()) {
+                        handler.add_warning(
+                            WarningKind::IncompatibleAttrTypeWarning,
+                            &[Message {
+                                pos: attr.pos.clone(),
+                                style: Style::LineAndColumn,
+                                message: format!(
+                                    "Schema attribute '{}' of type '{}' is incompatible with type '{}' defined in '{}'",
+                                    name,
+                                    attr.ty.ty_str(),
+                                    mixin_attr.ty.ty_str(),
+                                    mixin.name
+                                ),
+                                note: Some(
+                                    "Consider using the same type as the mixin attribute"
+                                        .to_string(),
+                                ),
+                            }],
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The 'constant_check' lint detects expressions in the schema check block that are constants.
+///
+/// ### Example
+///
+/// ```kcl
+/// schema Person:
+///     age: int
+///
+///     check:
+///         True
+///
+/// ```
+/// ### Explanation
+///
+/// A constant check expression is always true or always false, which is usually a mistake.
+pub static CONSTANT_CHECK: &Lint = &Lint {
+    name: stringify!("CONSTANT_CHECK"),
+    level: Level::Warning,
+    desc: "Check for constant check expressions",
+    code: "W0125",
+    note: Some("Consider checking the schema attributes"),
+};
+
+declare_lint_pass!(ConstantCheck => [CONSTANT_CHECK]);
+
+impl LintPass for ConstantCheck {
+    fn check_module(
+        &mut self,
+        handler: &mut Handler,
+        _ctx: &mut LintContext,
+        module: &ast::Module,
+    ) {
+        for stmt in &module.body {
+            if let ast::Stmt::Schema(schema_stmt) = &stmt.node {
+                for check_expr in &schema_stmt.checks {
+                    let test = &check_expr.node.test;
+                    if is_constant_expr(&test.node) {
+                        handler.add_warning(
+                            WarningKind::ConstantCheckWarning,
+                            &[Message {
+                                pos: test.get_pos(),
+                                style: Style::LineAndColumn,
+                                message: "Check expression is a constant".to_string(),
+                                note: Some("Consider checking the schema attributes".to_string()),
+                            }],
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_constant_expr(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::NumberLit(_) | ast::Expr::StringLit(_) | ast::Expr::NameConstantLit(_) => true,
+        ast::Expr::Unary(unary_expr) => is_constant_expr(&unary_expr.operand.node),
+        ast::Expr::Paren(paren_expr) => is_constant_expr(&paren_expr.expr.node),
+        _ => false,
+    }
+}
+
+/// The 'none_comparison' lint detects comparisons to `None` with `==` or `!=`.
+///
+/// ### Example
+///
+/// ```kcl
+/// a = None
+/// b = a == None
+///
+/// ```
+/// ### Explanation
+///
+/// Comparisons to `None` should use the identity operators `is None` or `is not None`.
+pub static NONE_COMPARISON: &Lint = &Lint {
+    name: stringify!("NONE_COMPARISON"),
+    level: Level::Warning,
+    desc: "Check for comparisons to None with '==' or '!='",
+    code: "W0121",
+    note: Some("Consider using 'is None' or 'is not None' instead"),
+};
+
+declare_lint_pass!(NoneComparison => [NONE_COMPARISON]);
+
+impl LintPass for NoneComparison {
+    fn check_compare(
+        &mut self,
+        handler: &mut Handler,
+        ctx: &mut LintContext,
+        compare: &ast::Compare,
+    ) {
+        let mut left = &compare.left.node;
+        for (op, comparator) in compare.ops.iter().zip(compare.comparators.iter()) {
+            let right = &comparator.node;
+            let expected = match op {
+                ast::CmpOp::Eq => "is None",
+                ast::CmpOp::NotEq => "is not None",
+                _ => "",
+            };
+            if !expected.is_empty() && (is_none_expr(left) || is_none_expr(right)) {
+                handler.add_warning(
+                    WarningKind::NoneComparisonWarning,
+                    &[Message {
+                        pos: ctx.start_pos.clone(),
+                        style: Style::LineAndColumn,
+                        message: format!("Comparison to None should be '{}'", expected),
+                        note: Some("Consider using 'is None' or 'is not None' instead".to_string()),
+                    }],
+                );
+            }
+            left = right;
+        }
+    }
+}
+
+fn is_none_expr(expr: &ast::Expr) -> bool {
+    matches!(
+        expr,
+        ast::Expr::NameConstantLit(ast::NameConstantLit {
+            value: ast::NameConstant::None
+        })
+    )
+}
+
+/// Collect the names of the loaded identifiers.
+#[derive(Default)]
+struct LoadedNames {
+    names: IndexSet<String>,
+}
+
+impl MutSelfWalker for LoadedNames {
+    fn walk_identifier(&mut self, identifier: &ast::Identifier) {
+        if matches!(identifier.ctx, ast::ExprContext::Load) {
+            if let Some(name) = identifier.names.first() {
+                self.names.insert(name.clone());
+            }
+        }
+    }
+}
+
+/// Collect the names and positions of the defined variables, lambda parameters and
+/// comprehension variables. The attributes in the schema body are not variables and
+/// skipped.
+#[derive(Default)]
+struct DefinedNames {
+    names: IndexMap<String, Position>,
+}
+
+impl DefinedNames {
+    fn define(&mut self, identifier: &ast::NodeRef<ast::Identifier>) {
+        if identifier.node.names.len() == 1 && !self.names.contains_key(&identifier.node.names[0]) {
+            self.names
+                .insert(identifier.node.names[0].clone(), identifier.get_pos());
+        }
+    }
+}
+
+impl MutSelfWalker for DefinedNames {
+    fn walk_assign_stmt(&mut self, assign_stmt: &ast::AssignStmt) {
+        for target in &assign_stmt.targets {
+            self.define(target);
+        }
+        self.walk_expr(&assign_stmt.value.node);
+    }
+    fn walk_schema_stmt(&mut self, _schema_stmt: &ast::SchemaStmt) {
+        // Nothing to do.
+    }
+    fn walk_lambda_expr(&mut self, lambda_expr: &ast::LambdaExpr) {
+        if let Some(args) = &lambda_expr.args {
+            for arg in &args.node.args {
+                self.define(arg);
+            }
+        }
+        for stmt in &lambda_expr.body {
+            self.walk_stmt(&stmt.node);
+        }
+    }
+    fn walk_comp_clause(&mut self, comp_clause: &ast::CompClause) {
+        for target in &comp_clause.targets {
+            self.define(target);
+        }
+        self.walk_expr(&comp_clause.iter.node);
+        for expr in &comp_clause.ifs {
+            self.walk_expr(&expr.node);
+        }
+    }
+    fn walk_quant_expr(&mut self, quant_expr: &ast::QuantExpr) {
+        self.walk_expr(&quant_expr.target.node);
+        for variable in &quant_expr.variables {
+            self.define(variable);
+        }
+        self.walk_expr(&quant_expr.test.node);
+        if let Some(if_cond) = &quant_expr.if_cond {
+            self.walk_expr(&if_cond.node);
+        }
+    }
+}
@@ -10,6 +10,7 @@
 //! lints_def.rs: Defined the various lints and the corresponding lintpasses implementation
 //! lintpass.rs: Definition of `Lintpass`
 //! lint.rs: Definition of `Lint`
+//! config.rs: Definition of `LintConfig` and the inline lint suppression comments
 //!               
 //! Steps to define a new lint:
 //! 1. Define a static instance of the `Lint` structure in lints_def.rs，e.g.,
@@ -76,25 +77,33 @@ use crate::resolver::pos::GetPos;
 use crate::resolver::{scope::Scope, Resolver};
 use kclvm_error::{Handler, Position};
 mod combinedlintpass;
+mod config;
 mod lint;
 mod lintpass;
 mod lints_def;
 use kclvm_ast::ast;
 use kclvm_ast::walker::MutSelfWalker;
 
-pub use self::{combinedlintpass::CombinedLintPass, lint::LintContext, lintpass::LintPass};
+pub use self::{
+    combinedlintpass::CombinedLintPass,
+    config::{LintConfig, LintLevel, Suppressions, LINT_COMMENT_PREFIX},
+    lint::LintContext,
+    lintpass::LintPass,
+};
 
 /// The struct `Linter` is used to traverse the AST and call the `check_*` method defined in `CombinedLintPass`.
 pub struct Linter<T: LintPass> {
     pub pass: T,
     pub handler: Handler,
     pub ctx: LintContext,
+    pub config: LintConfig,
 }
 
 impl LintContext {
     pub fn dummy_ctx() -> Self {
         LintContext {
             filename: "".to_string(),
+            pkgpath: "".to_string(),
             start_pos: Position::dummy_pos(),
             end_pos: Position::dummy_pos(),
         }
@@ -107,18 +116,26 @@ impl Linter<CombinedLintPass> {
             pass: CombinedLintPass::new(),
             handler: Handler::default(),
             ctx: LintContext::dummy_ctx(),
+            config: LintConfig::default(),
         }
     }
     pub fn walk_scope(&mut self, scope: &Scope) {
         self.pass
             .check_scope(&mut self.handler, &mut self.ctx, scope);
     }
+    /// Remove the lint diagnostics suppressed by the inline comments of the program and
+    /// apply the lint config on the remaining lint diagnostics.
+    pub fn apply_config(&mut self, program: &ast::Program) {
+        Suppressions::from_program(program).apply(&mut self.handler.diagnostics);
+        self.config.apply(&mut self.handler.diagnostics);
+    }
 }
 
 impl Resolver<'_> {
     /// Iterate the module and run lint checks, generating diagnostics and save them in `lint.handler`
     pub fn lint_check_module(&mut self, module: &ast::Module) {
         self.linter.ctx.filename = module.filename.clone();
+        self.linter.ctx.pkgpath = module.pkg.clone();
         self.linter.walk_module(module);
     }
     /// Recursively iterate the scope and its child scope, run lint checks, generating diagnostics and save them in `lint.handler`
@@ -365,6 +382,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         walk_set_if!(self, walk_expr, check_expr.msg);
     }
     fn walk_lambda_expr(&mut self, lambda_expr: &ast::LambdaExpr) {
+        self.pass
+            .check_lambda_expr(&mut self.handler, &mut self.ctx, lambda_expr);
         walk_set_if!(self, walk_arguments, lambda_expr.args);
         walk_set_list!(self, walk_stmt, lambda_expr.body);
     }
@@ -386,6 +405,8 @@ impl MutSelfWalker for Linter<CombinedLintPass> {
         }
     }
     fn walk_compare(&mut self, compare: &ast::Compare) {
+        self.pass
+            .check_compare(&mut self.handler, &mut self.ctx, compare);
         set_pos!(self, &compare.left);
         self.walk_expr(&compare.left.node);
         walk_set_list!(self, walk_expr, compare.comparators);
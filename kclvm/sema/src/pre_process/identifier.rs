@@ -270,10 +270,12 @@ impl<'ctx> MutSelfMutWalker<'ctx> for RawIdentifierTransformer {
 /// import path.to.pkg as pkgname
 ///
 /// x = pkgname.Name
+///
+/// Returns the diagnostics e.g., unique key errors of the global names.
 pub fn fix_qualified_identifier<'ctx>(
     module: &'ctx mut ast::Module,
     import_names: &mut IndexMap<String, String>,
-) {
+) -> IndexSet<Diagnostic> {
     // 0. init import names.
     for stmt in &module.body {
         if let ast::Stmt::Import(import_stmt) = &stmt.node {
@@ -286,7 +288,7 @@ pub fn fix_qualified_identifier<'ctx>(
         ..Default::default()
     };
     global_names_walker.walk_module(module);
-    global_names_walker.handler.abort_if_any_errors();
+    global_names_walker.handler.diagnostics
 }
 
 /// Fix AST raw identifier prefix `$`, e.g., $filter -> filter
@@ -2,8 +2,9 @@ mod config;
 mod identifier;
 mod multi_assign;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use kclvm_ast::ast;
+use kclvm_error::Diagnostic;
 
 #[cfg(test)]
 mod tests;
@@ -12,8 +13,9 @@ pub use config::{fix_config_expr_nest_attr, merge_program};
 pub use identifier::{fix_qualified_identifier, fix_raw_identifier_prefix};
 pub use multi_assign::transform_multi_assign;
 
-/// Pre-process AST program.
-pub fn pre_process_program(program: &mut ast::Program) {
+/// Pre-process AST program and return the diagnostics found in pre-processing.
+pub fn pre_process_program(program: &mut ast::Program) -> IndexSet<Diagnostic> {
+    let mut diagnostics = IndexSet::new();
     for (pkgpath, modules) in program.pkgs.iter_mut() {
         let mut import_names = IndexMap::default();
         if pkgpath == kclvm_ast::MAIN_PKG {
@@ -31,9 +33,10 @@ pub fn pre_process_program(program: &mut ast::Program) {
             }
             // First we should transform the raw identifier to avoid raw identifier that happens to be a package path.
             fix_raw_identifier_prefix(module);
-            fix_qualified_identifier(module, &mut import_names);
+            diagnostics.extend(fix_qualified_identifier(module, &mut import_names));
             fix_config_expr_nest_attr(module);
         }
     }
     merge_program(program);
+    diagnostics
 }
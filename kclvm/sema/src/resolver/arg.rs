@@ -31,8 +31,25 @@ impl<'ctx> Resolver<'ctx> {
         }
         if !params.is_empty() {
             for (i, ty) in arg_types.iter().enumerate() {
-                let expected_ty = params[i].ty.clone();
-                self.must_assignable_to(ty.clone(), expected_ty, args[i].get_pos(), None)
+                match params.get(i) {
+                    Some(param) => self.must_assignable_to(
+                        ty.clone(),
+                        param.ty.clone(),
+                        args[i].get_pos(),
+                        None,
+                    ),
+                    None => {
+                        self.handler.add_compile_error(
+                            &format!(
+                                "expected {} positional arguments, found {}",
+                                params.len(),
+                                arg_types.len()
+                            ),
+                            args[i].get_pos(),
+                        );
+                        break;
+                    }
+                }
             }
             for (i, (arg_name, kwarg_ty)) in kwarg_types.iter().enumerate() {
                 if !params
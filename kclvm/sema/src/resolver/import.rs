@@ -131,10 +131,16 @@ impl<'ctx> Resolver<'ctx> {
                                                 ));
                                                 matches!(module_ty.kind, ModuleKind::User)
                                             }
-                                            _ => bug!(
-                                            "invalid module type in the import check function {}",
-                                            scope_obj.borrow().ty.ty_str()
-                                        ),
+                                            _ => {
+                                                self.handler.add_compile_error(
+                                                    &format!(
+                                                        "the name '{}' is not a module and can not be imported",
+                                                        import_stmt.path
+                                                    ),
+                                                    stmt.get_pos(),
+                                                );
+                                                false
+                                            }
                                         }
                                     }
                                     None => {
@@ -18,10 +18,10 @@ mod var;
 #[cfg(test)]
 mod tests;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use std::{cell::RefCell, rc::Rc};
 
-use crate::lint::{CombinedLintPass, Linter};
+use crate::lint::{CombinedLintPass, LintConfig, Linter};
 use crate::pre_process::pre_process_program;
 use crate::resolver::scope::ScopeObject;
 use crate::resolver::ty_alias::process_program_type_alias;
@@ -51,6 +51,8 @@ impl<'ctx> Resolver<'ctx> {
     pub fn new(program: &'ctx Program, options: Options) -> Self {
         let builtin_scope = Rc::new(RefCell::new(builtin_scope()));
         let scope = Rc::clone(&builtin_scope);
+        let mut linter = Linter::<CombinedLintPass>::new();
+        linter.config = options.lint_config.clone();
         Resolver {
             program,
             scope_map: IndexMap::default(),
@@ -59,7 +61,7 @@ impl<'ctx> Resolver<'ctx> {
             ctx: Context::default(),
             options,
             handler: Handler::default(),
-            linter: Linter::<CombinedLintPass>::new(),
+            linter,
         }
     }
 
@@ -90,7 +92,11 @@ impl<'ctx> Resolver<'ctx> {
 
     pub(crate) fn check_and_lint(&mut self, pkgpath: &str) -> ProgramScope {
         let mut scope = self.check(pkgpath);
+        if !self.options.lint_check {
+            return scope;
+        }
         self.lint_check_scope_map();
+        self.linter.apply_config(self.program);
         for diag in &self.linter.handler.diagnostics {
             scope.diagnostics.insert(diag.clone());
         }
@@ -132,25 +138,91 @@ pub struct Context {
 /// Resolve options
 #[derive(Clone, Debug, Default)]
 pub struct Options {
+    /// Raise the compile errors after resolving instead of only returning them
+    /// in the diagnostics of the program scope.
     pub raise_err: bool,
+    /// Fix the config expressions automatically.
     pub config_auto_fix: bool,
+    /// Run the lint passes and report the lint diagnostics.
     pub lint_check: bool,
+    /// The lint config e.g., the `[lint]` section of the kcl.mod file.
+    pub lint_config: LintConfig,
 }
 
-/// Resolve program
+/// Resolve program, all diagnostics including the lint diagnostics are
+/// returned in the program scope and no error is raised.
 pub fn resolve_program(program: &mut Program) -> ProgramScope {
-    pre_process_program(program);
-    let mut resolver = Resolver::new(
+    resolve_program_with_options(
         program,
         Options {
-            raise_err: true,
+            raise_err: false,
             config_auto_fix: false,
             lint_check: true,
+            lint_config: LintConfig::default(),
         },
-    );
+    )
+}
+
+/// Resolve program with the resolve options. The resolver continues after type errors
+/// with the `any` type, so all diagnostics are returned sorted by position. Only the
+/// broken internal invariants e.g., of the builtin scope still panic.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_parser::load_program;
+/// use kclvm_sema::resolver::{resolve_program_with_options, Options};
+///
+/// let mut program = load_program(&["./src/resolver/test_data/lint.k"], None).unwrap();
+/// // Only type check the program without the lint passes.
+/// let scope = resolve_program_with_options(
+///     &mut program,
+///     Options {
+///         lint_check: false,
+///         ..Default::default()
+///     },
+/// );
+/// ```
+pub fn resolve_program_with_options(program: &mut Program, opts: Options) -> ProgramScope {
+    let diagnostics = pre_process_program(program);
+    let raise_err = opts.raise_err;
+    let mut resolver = Resolver::new(program, opts);
+    resolver.handler.diagnostics.extend(diagnostics);
     resolver.resolve_import();
-    let scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
+    let mut scope = resolver.check_and_lint(kclvm_ast::MAIN_PKG);
     let type_alias_mapping = resolver.ctx.type_alias_mapping.clone();
     process_program_type_alias(program, type_alias_mapping);
+    scope.diagnostics = sort_diagnostics(scope.diagnostics);
+    if raise_err {
+        scope.check_scope_diagnostics();
+    }
     scope
 }
+
+/// Sort the diagnostics by position and remove the cascading errors, which are the
+/// errors reported at the same position as a previous error.
+fn sort_diagnostics(diagnostics: IndexSet<Diagnostic>) -> IndexSet<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = diagnostics.into_iter().collect();
+    // The sort is stable, so diagnostics at the same position keep the reported order.
+    diagnostics.sort_by(|a, b| diagnostic_pos_key(a).cmp(&diagnostic_pos_key(b)));
+    let mut error_positions = IndexSet::new();
+    diagnostics
+        .into_iter()
+        .filter(|diag| match diag.messages.first() {
+            Some(msg) if diag.is_error() => error_positions.insert(msg.pos.clone()),
+            _ => true,
+        })
+        .collect()
+}
+
+#[inline]
+fn diagnostic_pos_key(diag: &Diagnostic) -> (String, u64, u64) {
+    match diag.messages.first() {
+        Some(msg) => (
+            msg.pos.filename.clone(),
+            msg.pos.line,
+            msg.pos.column.unwrap_or_default(),
+        ),
+        None => (String::new(), 0, 0),
+    }
+}
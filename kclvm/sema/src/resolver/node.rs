@@ -39,7 +39,7 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
             }
             ty
         } else {
-            bug!("invalid expr statement exprs");
+            self.any_ty()
         }
     }
 
@@ -175,7 +175,11 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
         let right_ty = self.expr(&aug_assign_stmt.value);
         let op = match aug_assign_stmt.op.clone().try_into() {
             Ok(op) => op,
-            Err(msg) => bug!("{}", msg),
+            Err(msg) => {
+                self.handler
+                    .add_compile_error(msg, aug_assign_stmt.target.get_pos());
+                return self.any_ty();
+            }
         };
         let new_target_ty = self.binary(left_ty, right_ty, &op, aug_assign_stmt.target.get_pos());
         self.ctx.l_value = true;
@@ -321,7 +325,10 @@ impl<'ctx> MutSelfTypedResultWalker<'ctx> for Resolver<'ctx> {
                     // Assign
                     _ => self.must_assignable_to(value_ty, expected_ty, pos, None),
                 },
-                None => bug!("invalid ast schema attr op kind"),
+                None => {
+                    self.handler
+                        .add_compile_error("invalid schema attribute operator", pos);
+                }
             }
         }
         self.any_ty()
@@ -1,9 +1,10 @@
 use super::Options;
 use super::Resolver;
 use crate::builtin::BUILTIN_FUNCTION_NAMES;
+use crate::lint::LintConfig;
 use crate::pre_process::pre_process_program;
-use crate::resolver::resolve_program;
 use crate::resolver::scope::*;
+use crate::resolver::{resolve_program, resolve_program_with_options};
 use crate::ty::Type;
 use kclvm_ast::ast;
 use kclvm_error::*;
@@ -101,8 +102,53 @@ fn test_resolve_program_cycle_reference_fail() {
         "Module 'file1' imported but unused",
     ];
     assert_eq!(scope.diagnostics.len(), err_messages.len());
-    for (diag, msg) in scope.diagnostics.iter().zip(err_messages.iter()) {
-        assert_eq!(diag.messages[0].message, msg.to_string(),);
+    // Diagnostics are sorted by position, so only check the reported messages here.
+    let messages: Vec<&str> = scope
+        .diagnostics
+        .iter()
+        .map(|diag| diag.messages[0].message.as_str())
+        .collect();
+    for msg in err_messages {
+        assert!(
+            messages.contains(&msg),
+            "{} not found in {:?}",
+            msg,
+            messages
+        );
+    }
+}
+
+#[test]
+fn test_resolve_program_report_all_errors() {
+    let mut program =
+        load_program(&["./src/resolver/test_fail_data/multi_errors.k"], None).unwrap();
+    let scope = resolve_program(&mut program);
+    let errors: Vec<(u64, &str)> = scope
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.is_error())
+        .map(|diag| (diag.messages[0].pos.line, diag.messages[0].message.as_str()))
+        .collect();
+    // The resolver continues after the errors and returns all of them sorted by position.
+    let lines: Vec<u64> = errors.iter().map(|(line, _)| *line).collect();
+    let mut sorted_lines = lines.clone();
+    sorted_lines.sort();
+    assert_eq!(lines, sorted_lines);
+    sorted_lines.dedup();
+    assert_eq!(sorted_lines, [2, 3, 4, 5]);
+    let expected = [
+        (2, "Unique key error name 'a'"),
+        (3, "expect int"),
+        (4, "name 'd' is not defined"),
+        (5, "bad operand type for unary -"),
+    ];
+    for (line, msg) in expected {
+        assert!(
+            errors.iter().any(|(l, m)| *l == line && m.starts_with(msg)),
+            "{} not found in {:?}",
+            msg,
+            errors
+        );
     }
 }
 
@@ -174,6 +220,7 @@ fn test_lint() {
             raise_err: true,
             config_auto_fix: false,
             lint_check: true,
+            ..Default::default()
         },
     );
     resolver.resolve_import();
@@ -182,7 +229,7 @@ fn test_lint() {
     let root = &program.root.clone();
     let filename = root.clone() + "/lint.k";
     let mut handler = Handler::default();
-    handler.add_warning(
+    handler.add_warning_with_suggestions(
         WarningKind::ImportPositionWarning,
         &[Message {
             pos: Position {
@@ -194,8 +241,12 @@ fn test_lint() {
             message: format!("Importstmt should be placed at the top of the module"),
             note: Some("Consider moving tihs statement to the top of the file".to_string()),
         }],
+        &[
+            Suggestion::insert_before_line(&filename, 4, "import import_test.b\n"),
+            Suggestion::delete_lines(&filename, 10, 10),
+        ],
     );
-    handler.add_warning(
+    handler.add_warning_with_suggestions(
         WarningKind::ReimportWarning,
         &[Message {
             pos: Position {
@@ -207,8 +258,9 @@ fn test_lint() {
             message: format!("Module 'a' is reimported multiple times"),
             note: Some("Consider removing this statement".to_string()),
         }],
+        &[Suggestion::delete_lines(&filename, 2, 2)],
     );
-    handler.add_warning(
+    handler.add_warning_with_suggestions(
         WarningKind::UnusedImportWarning,
         &[Message {
             pos: Position {
@@ -220,6 +272,7 @@ fn test_lint() {
             message: format!("Module 'import_test.a' imported but unused"),
             note: Some("Consider removing this statement".to_string()),
         }],
+        &[Suggestion::delete_lines(&filename, 1, 1)],
     );
     for (d1, d2) in resolver
         .linter
@@ -231,3 +284,134 @@ fn test_lint() {
         assert_eq!(d1, d2);
     }
 }
+
+#[test]
+fn test_lint_rules() {
+    let mut program = load_program(&["./src/resolver/test_data/lint_rules.k"], None).unwrap();
+    let scope = resolve_program(&mut program);
+    let warnings = scope
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.level == Level::Warning)
+        .map(|diag| {
+            (
+                diag.code.clone().unwrap(),
+                diag.messages[0].pos.line,
+                diag.messages[0].message.clone(),
+            )
+        })
+        .collect::<Vec<(DiagnosticId, u64, String)>>();
+    let expected = [
+        (
+            WarningKind::UnusedVariableWarning,
+            3,
+            "Private variable '_unused' is assigned but never used",
+        ),
+        (
+            WarningKind::ShadowingWarning,
+            6,
+            "Variable 'len' shadows the builtin function 'len'",
+        ),
+        (
+            WarningKind::ShadowingWarning,
+            7,
+            "Variable 'math' shadows the imported module 'math'",
+        ),
+        (
+            WarningKind::UnusedArgumentWarning,
+            9,
+            "Lambda parameter 'y' is never used",
+        ),
+        (
+            WarningKind::IncompatibleAttrTypeWarning,
+            18,
+            "Schema attribute 'name' of type 'str' is incompatible with type 'int' defined in 'NameMixin'",
+        ),
+        (
+            WarningKind::ConstantCheckWarning,
+            21,
+            "Check expression is a constant",
+        ),
+        (
+            WarningKind::NoneComparisonWarning,
+            23,
+            "Comparison to None should be 'is None'",
+        ),
+    ];
+    for (kind, line, msg) in expected {
+        assert!(
+            warnings.contains(&(DiagnosticId::Warning(kind), line, msg.to_string())),
+            "{} not found in {:?}",
+            msg,
+            warnings
+        );
+    }
+    assert!(!warnings
+        .iter()
+        .any(|(_, _, msg)| msg.contains("'_used'") || msg.contains("'x'")));
+}
+
+#[test]
+fn test_resolve_invalid_aug_assign_op() {
+    let mut program = parse_program("./src/resolver/test_data/aug_assign.k").unwrap();
+    // The parser never produces the assign operator in the aug assign statement,
+    // make it on the AST to check the resolver continues with the `any` type.
+    for module in program.pkgs.get_mut(kclvm_ast::MAIN_PKG).unwrap() {
+        for stmt in &mut module.body {
+            if let ast::Stmt::AugAssign(aug_assign_stmt) = &mut stmt.node {
+                aug_assign_stmt.op = ast::AugOp::Assign;
+            }
+        }
+    }
+    let scope = resolve_program(&mut program);
+    let errors: Vec<&str> = scope
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.is_error())
+        .map(|diag| diag.messages[0].message.as_str())
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            "aug assign op can not into bin op",
+            "expect int, got str(s)"
+        ]
+    );
+}
+
+#[test]
+fn test_resolve_program_with_options() {
+    let mut program = load_program(&["./src/resolver/test_data/lint.k"], None).unwrap();
+    let scope = resolve_program_with_options(
+        &mut program.clone(),
+        Options {
+            lint_check: false,
+            ..Default::default()
+        },
+    );
+    assert!(!scope
+        .diagnostics
+        .iter()
+        .any(|diag| diag.level == Level::Warning));
+    let scope = resolve_program_with_options(
+        &mut program,
+        Options {
+            lint_check: true,
+            lint_config: LintConfig {
+                allow: vec!["W0411".to_string(), "reimport".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let warnings: Vec<&str> = scope
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.level == Level::Warning)
+        .map(|diag| diag.messages[0].message.as_str())
+        .collect();
+    assert_eq!(
+        warnings,
+        ["Importstmt should be placed at the top of the module"]
+    );
+}
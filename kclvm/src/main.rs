@@ -4,14 +4,23 @@
 extern crate clap;
 
 use clap::ArgMatches;
-use kclvm_error::Handler;
-use kclvm_runner::{execute, ExecProgramArgs};
+use kclvm_error::{DiagnosticFormat, Handler};
+use kclvm_runner::testing::{
+    format_test_results, load_test_suites, run_test_suites, to_junit_xml, TestOptions,
+};
+use kclvm_runner::{execute, execute_resolved, ExecProgramArgs};
 use std::io::Write;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
 
-use kclvm::ValueRef;
+use kclvm::{PanicInfo, ValueRef};
+use kclvm_ast::ast::Program;
 use kclvm_config::settings::{load_file, merge_settings, SettingsFile};
-use kclvm_parser::load_program;
-use kclvm_tools::lint::lint_files;
+use kclvm_parser::{try_load_program, LoadProgramOptions};
+use kclvm_sema::resolver::{resolve_program_with_options, Options};
+use kclvm_tools::format::{diff_file, format, FormatOptions};
+use kclvm_tools::lint::{fix_files, lint_files};
+use kclvm_tools::query::{get, get_value};
 
 fn main() {
     let matches = clap_app!(kcl =>
@@ -26,6 +35,7 @@ fn main() {
             (@arg debug: -d --debug "Run in debug mode (for developers only)")
             (@arg sort_key: -k --sort "Sort result keys")
             (@arg ARGUMENT: ... -D --argument "Specify the top-level argument")
+            (@arg FORMAT: --format +takes_value "Sets the diagnostic output format, expect (human, json, sarif)")
         )
         (@subcommand lint =>
             (@arg INPUT: ... "Sets the input file to use")
@@ -39,6 +49,26 @@ fn main() {
             (@arg sort_key: -k --sort "Sort result keys")
             (@arg ARGUMENT: ... -D --argument "Specify the top-level argument")
             (@arg EMIT_WARNING: --emit_warning "Emit warning message")
+            (@arg fix: --fix "Apply the suggested fixes of lint diagnostics and re-print the fixed files")
+            (@arg lint_plugins: --lint_plugins "Load and run the lint plugins configured in the kcl.mod file")
+            (@arg FORMAT: --format +takes_value "Sets the diagnostic output format, expect (human, json, sarif)")
+        )
+        (@subcommand fmt =>
+            (@arg INPUT: ... "Sets the input file or directory to format, default is the current directory")
+            (@arg check: --check "Check whether files are formatted without writing them, exit non-zero when some are not")
+            (@arg diff: --diff "Print the unified diffs of files that are not formatted without writing them")
+            (@arg recursive: -R --recursive "Format all KCL files in the directory recursively")
+        )
+        (@subcommand query =>
+            (@arg INPUT: ... +required "Sets the input file to use")
+            (@arg PATH: -P --path +takes_value +required "Sets the config path to query, e.g., pkg:app.spec.replicas")
+            (@arg value: --value "Print the evaluated value at the path by executing the program")
+        )
+        (@subcommand test =>
+            (@arg INPUT: ... "Sets the test files or package directories, e.g., ./... for all packages, default is the current directory")
+            (@arg RUN: --run +takes_value "Only run the test cases whose names match the regular expression")
+            (@arg JUNIT: --junit +takes_value "Write the test results in the JUnit XML format to the file")
+            (@arg verbose: -v --verbose "Print the passed test cases")
         )
     )
     .get_matches();
@@ -62,11 +92,33 @@ fn main() {
                 } else {
                     args.get_files()
                 };
+                let format = diagnostic_format(&matches);
                 // Parse AST program.
-                let program = load_program(&files, Some(args.get_load_program_options())).unwrap();
-                // Resolve AST program, generate libs, link libs and execute.
+                let mut program =
+                    load_program_or_exit(&files, args.get_load_program_options(), format);
+                let exec_args = ExecProgramArgs::default();
+                // Resolve AST program once and report all compile errors in the diagnostic
+                // format before executing, the lint diagnostics are not reported.
+                let scope = resolve_program_with_options(
+                    &mut program,
+                    Options {
+                        raise_err: false,
+                        ..exec_args.get_resolve_options()
+                    },
+                );
+                let mut handler = Handler::with_format(format);
+                handler.diagnostics = scope
+                    .diagnostics
+                    .iter()
+                    .filter(|diag| diag.is_error())
+                    .cloned()
+                    .collect();
+                if handler.emit() {
+                    std::process::exit(1)
+                }
+                // Generate libs, link libs and execute.
                 // TODO: The argument "plugin_agent" need to be read from python3.
-                let result = execute(program, 1, &ExecProgramArgs::default()).unwrap();
+                let result = execute_resolved(program, scope, 1, &exec_args).unwrap();
                 print!(
                     "{}",
                     ValueRef::from_yaml(&result)
@@ -96,21 +148,207 @@ fn main() {
                 } else {
                     args.get_files()
                 };
-                let (mut err_handler, mut warning_handler) =
-                    (Handler::default(), Handler::default());
-                (err_handler.diagnostics, warning_handler.diagnostics) =
-                    lint_files(&files, Some(args.get_load_program_options()));
-                err_handler.emit();
+                if matches.occurrences_of("fix") > 0 {
+                    match fix_files(&files, Some(args.get_load_program_options())) {
+                        Ok(fixed_files) => {
+                            for file in &fixed_files {
+                                eprintln!("fixed {}", file);
+                            }
+                        }
+                        Err(err) => {
+                            eprintln!("{}", err);
+                            std::process::exit(1)
+                        }
+                    }
+                }
+                let mut handler = Handler::with_format(diagnostic_format(&matches));
+                let (errors, warnings) = without_compile_error_messages(|| {
+                    lint_files(
+                        &files,
+                        Some(args.get_load_program_options()),
+                        matches.occurrences_of("lint_plugins") > 0,
+                    )
+                });
+                handler.diagnostics = errors;
                 if matches.occurrences_of("EMIT_WARNING") > 0 {
-                    warning_handler.emit();
+                    handler.diagnostics.extend(warnings);
+                }
+                if handler.emit() {
+                    std::process::exit(1)
+                }
+            }
+        }
+    } else if let Some(matches) = matches.subcommand_matches("fmt") {
+        let paths: Vec<&str> = match matches.values_of("INPUT") {
+            Some(paths) => paths.into_iter().collect::<Vec<&str>>(),
+            None => vec!["."],
+        };
+        let opts = FormatOptions {
+            recursively: matches.occurrences_of("recursive") > 0,
+            check: matches.occurrences_of("check") > 0,
+            diff: matches.occurrences_of("diff") > 0,
+            ..Default::default()
+        };
+        let mut changed_paths: Vec<String> = vec![];
+        for path in paths {
+            match format(path, &opts) {
+                Ok(paths) => changed_paths.extend(paths),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    std::process::exit(1)
                 }
             }
         }
+        if opts.diff {
+            for path in &changed_paths {
+                match diff_file(path) {
+                    Ok(Some(diff)) => print!("{}", diff),
+                    Ok(None) => {}
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        std::process::exit(1)
+                    }
+                }
+            }
+        }
+        if opts.check {
+            for path in &changed_paths {
+                eprintln!("{} is not formatted", path);
+            }
+            if !changed_paths.is_empty() {
+                std::process::exit(1)
+            }
+        } else if !opts.diff {
+            for path in &changed_paths {
+                println!("{}", path);
+            }
+        }
+        std::io::stdout().flush().unwrap();
+    } else if let Some(matches) = matches.subcommand_matches("query") {
+        let files: Vec<&str> = matches.values_of("INPUT").unwrap().collect();
+        let path = matches.value_of("PATH").unwrap();
+        let program = load_program_or_exit(
+            &files,
+            LoadProgramOptions::default(),
+            DiagnosticFormat::Human,
+        );
+        let results = get(&program, path).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1)
+        });
+        if results.is_empty() {
+            eprintln!("{} not found", path);
+            std::process::exit(1)
+        }
+        for result in &results {
+            println!(
+                "{}:{}:{}: {}",
+                result.filename,
+                result.line,
+                result.column + 1,
+                result.expr.trim_end()
+            );
+        }
+        if matches.occurrences_of("value") > 0 {
+            let json_result = execute(program, 1, &ExecProgramArgs::default()).unwrap();
+            match get_value(&json_result, path) {
+                Ok(Some(value)) => println!("value: {}", value),
+                Ok(None) => println!("value: None"),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    std::process::exit(1)
+                }
+            }
+        }
+        std::io::stdout().flush().unwrap();
+    } else if let Some(matches) = matches.subcommand_matches("test") {
+        let paths: Vec<&str> = match matches.values_of("INPUT") {
+            Some(paths) => paths.into_iter().collect::<Vec<&str>>(),
+            None => vec!["."],
+        };
+        let suites = load_test_suites(&paths).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1)
+        });
+        let opts = TestOptions {
+            run_regexp: matches.value_of("RUN").unwrap_or_default().to_string(),
+            // TODO: The argument "plugin_agent" need to be read from python3.
+            plugin_agent: 1,
+            ..Default::default()
+        };
+        let results = run_test_suites(&suites, &opts).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1)
+        });
+        print!(
+            "{}",
+            format_test_results(&results, matches.occurrences_of("verbose") > 0)
+        );
+        std::io::stdout().flush().unwrap();
+        if let Some(junit_file) = matches.value_of("JUNIT") {
+            if let Err(err) = std::fs::write(junit_file, to_junit_xml(&results)) {
+                eprintln!("{}", err);
+                std::process::exit(1)
+            }
+        }
+        if results.iter().any(|r| r.failures() > 0) {
+            std::process::exit(1)
+        }
     } else {
         println!("{}", matches.usage());
     }
 }
 
+/// Get the diagnostic output format from arg matches, default is the human readable format.
+fn diagnostic_format(matches: &ArgMatches) -> DiagnosticFormat {
+    match matches.value_of("FORMAT") {
+        Some(format) => format.parse().unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(2)
+        }),
+        None => DiagnosticFormat::default(),
+    }
+}
+
+/// Load the program, the syntax errors are emitted in the diagnostic format and the
+/// process exits with the code 1.
+fn load_program_or_exit(
+    files: &[&str],
+    opts: LoadProgramOptions,
+    format: DiagnosticFormat,
+) -> Program {
+    match without_compile_error_messages(|| try_load_program(files, Some(opts))) {
+        Ok(program) => program,
+        Err(err) => {
+            let mut handler = Handler::with_format(format);
+            handler.diagnostics.insert(err);
+            handler.emit();
+            std::process::exit(1)
+        }
+    }
+}
+
+/// Call the function without printing the panic messages of the KCL compile errors,
+/// which are caught and reported as diagnostics by the function. The panic messages
+/// of other panics e.g., compiler bugs are still printed.
+fn without_compile_error_messages<T>(f: impl FnOnce() -> T) -> T {
+    let prev_hook: Arc<dyn Fn(&std::panic::PanicInfo) + Send + Sync> =
+        std::panic::take_hook().into();
+    let hook = prev_hook.clone();
+    std::panic::set_hook(Box::new(move |info| {
+        let is_compile_error = info
+            .payload()
+            .downcast_ref::<String>()
+            .map_or(false, |msg| serde_json::from_str::<PanicInfo>(msg).is_ok());
+        if !is_compile_error {
+            hook(info)
+        }
+    }));
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    std::panic::set_hook(Box::new(move |info| prev_hook(info)));
+    result.unwrap_or_else(|err| std::panic::resume_unwind(err))
+}
+
 /// Build settings from arg matches.
 fn build_settings(matches: &ArgMatches) -> SettingsFile {
     let debug_mode = matches.occurrences_of("debug") > 0;
@@ -9,6 +9,7 @@ pub fn criterion_benchmark_override(c: &mut Criterion) {
                 "./benches/test_data/simple.k",
                 &["config.image=\"image/image:v1\"".to_string()],
                 &["pkg.to.path".to_string()],
+                false,
             )
             .unwrap();
         })
@@ -6,25 +6,50 @@
 //! AST Module, and then use the AST printer [kclvm_tools::printer::print_ast_module]
 //! to print it as source code string.
 use anyhow::{anyhow, Result};
+use similar::TextDiff;
 use std::path::Path;
 
-use crate::{printer::print_ast_module, util::get_kcl_files};
+use crate::{
+    printer::{print_ast_module_with_config, Config},
+    util::get_kcl_files,
+};
+use kclvm_config::modfile::{get_pkg_root, load_mod_file, KCLModFileFormatSection};
 use kclvm_parser::parse_file;
 
+mod range;
 #[cfg(test)]
 mod tests;
 
-/// FormatOptions contains two options:
+pub use range::{
+    apply_text_edits, format_on_type, format_on_type_with_config, format_range,
+    format_range_with_config, TextEdit, TextPosition, TextRange,
+};
+
+/// FormatOptions contains four options:
 /// - is_stdout: whether to output the formatted result to stdout.
 /// - recursively: whether to recursively traverse a folder and format all KCL files in it.
+/// - check: whether to only check whether files are formatted without writing them.
+/// - diff: whether to only collect files need to be formatted for diffs without writing them.
 #[derive(Debug, Default)]
 pub struct FormatOptions {
     pub is_stdout: bool,
     pub recursively: bool,
+    pub check: bool,
+    pub diff: bool,
+}
+
+impl FormatOptions {
+    /// Whether the formatted result needs to be written back to the file.
+    #[inline]
+    fn write_back(&self) -> bool {
+        !self.is_stdout && !self.check && !self.diff
+    }
 }
 
 /// Formats kcl file or directory path contains kcl files and
-/// returns the changed file paths.
+/// returns the changed file paths. In the check or diff mode,
+/// files are not written and the returned paths are files that
+/// are not formatted.
 ///
 /// # Examples
 ///
@@ -51,40 +76,115 @@ pub fn format<P: AsRef<Path>>(path: P, opts: &FormatOptions) -> Result<Vec<Strin
             changed_paths.push(file)
         }
     }
-    if !opts.is_stdout {
-        let n = changed_paths.len();
-        println!(
-            "KCL format done and {} {} formatted:",
-            n,
-            if n <= 1 { "file was" } else { "files were" }
-        );
-        for p in &changed_paths {
-            println!("{}", p);
-        }
-    }
     Ok(changed_paths)
 }
 
 /// Formats a file and returns whether the file has been formatted and modified.
 fn format_file(file: &str, opts: &FormatOptions) -> Result<bool> {
     let src = std::fs::read_to_string(file)?;
-    let (source, is_formatted) = format_source(&src)?;
+    let (source, is_formatted) = format_source_with_config(&src, load_format_config(file)?)?;
     if opts.is_stdout {
         println!("{}", source);
-    } else {
+    } else if opts.write_back() && is_formatted {
         std::fs::write(file, &source)?
     }
     Ok(is_formatted)
 }
 
+/// Formats a file and returns the unified diff between the original
+/// source and the formatted source, returns `None` when the file is
+/// already formatted.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_tools::format::diff_file;
+///
+/// if let Some(diff) = diff_file("path_to_a_single_file.k").unwrap() {
+///     print!("{}", diff);
+/// }
+/// ```
+pub fn diff_file(file: &str) -> Result<Option<String>> {
+    let src = std::fs::read_to_string(file)?;
+    let (source, is_formatted) = format_source_with_config(&src, load_format_config(file)?)?;
+    if !is_formatted {
+        return Ok(None);
+    }
+    Ok(Some(
+        TextDiff::from_lines(&src, &source)
+            .unified_diff()
+            .header(file, file)
+            .to_string(),
+    ))
+}
+
 /// Formats a code source and returns the formatted source and
 /// whether the source is changed.
-fn format_source(src: &str) -> Result<(String, bool)> {
+pub fn format_source(src: &str) -> Result<(String, bool)> {
+    format_source_with_config(src, Config::default())
+}
+
+/// Formats a code source with the printer config and returns the
+/// formatted source and whether the source is changed.
+pub fn format_source_with_config(src: &str, cfg: Config) -> Result<(String, bool)> {
     let module = match parse_file("", Some(src.to_string())) {
         Ok(module) => module,
         Err(err) => return Err(anyhow!("{}", err)),
     };
-    let formatted_src = print_ast_module(&module);
+    let formatted_src = print_ast_module_with_config(&module, cfg);
     let is_formatted = src != formatted_src;
     Ok((formatted_src, is_formatted))
 }
+
+/// Loads the printer config from the `[format]` section of the kcl.mod file
+/// of the package the file belongs to, returns the default config when there
+/// is no kcl.mod file or format section.
+///
+/// - kcl.mod
+///
+/// ```toml
+/// [format]
+/// indent_width = 2
+/// max_line_width = 100
+/// quote_style = "double"
+/// trailing_comma = "always"
+/// blank_lines_between_schemas = 2
+/// ```
+///
+/// Note that `max_line_width` is not enforced on every line: only the list and
+/// config expressions that exceed it are wrapped into multiple lines, and the
+/// other expressions e.g., call arguments are always kept in one line.
+pub fn load_format_config(file: &str) -> Result<Config> {
+    match get_pkg_root(file) {
+        Some(root) => match &load_mod_file(&root).format {
+            Some(format) => format_config_from_section(format),
+            None => Ok(Config::default()),
+        },
+        None => Ok(Config::default()),
+    }
+}
+
+/// Merges the kcl.mod format section into the default printer config.
+fn format_config_from_section(format: &KCLModFileFormatSection) -> Result<Config> {
+    let mut cfg = Config::default();
+    if let Some(indent_width) = format.indent_width {
+        cfg.indent_len = indent_width;
+        cfg.tab_len = indent_width;
+    }
+    if let Some(use_tabs) = format.use_tabs {
+        cfg.use_spaces = !use_tabs;
+    }
+    if format.max_line_width.is_some() {
+        cfg.max_line_width = format.max_line_width;
+    }
+    if let Some(quote_style) = &format.quote_style {
+        cfg.quote_style = quote_style.parse().map_err(|err: String| anyhow!(err))?;
+    }
+    if let Some(trailing_comma) = &format.trailing_comma {
+        cfg.trailing_comma = trailing_comma.parse().map_err(|err: String| anyhow!(err))?;
+    }
+    if let Some(blank_lines) = format.blank_lines_between_schemas {
+        cfg.blank_lines_between_schemas = blank_lines;
+    }
+    Ok(cfg)
+}
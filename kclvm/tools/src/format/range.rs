@@ -0,0 +1,327 @@
+//! Range and on-type formatting for editors. Instead of formatting the whole
+//! source, only the top-level statements that cover the selected lines are
+//! printed, and the result is returned as minimal line based text edits.
+use anyhow::{anyhow, Result};
+use similar::{DiffTag, TextDiff};
+use std::ops::RangeInclusive;
+
+use crate::printer::{Config, NoHook, Printer};
+use kclvm_ast::ast;
+use kclvm_parser::parse_file;
+
+/// A position in the source, the line is 1-based and the column is 0-based
+/// which is consistent with the AST node position.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TextPosition {
+    pub line: u64,
+    pub column: u64,
+}
+
+/// A half-open range `[start, end)` in the source.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextRange {
+    pub start: TextPosition,
+    pub end: TextPosition,
+}
+
+/// A text edit replaces the text in `range` with `new_text`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
+/// Formats the top-level statements covering the range of the source and
+/// returns the text edits.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_tools::format::{format_range, TextPosition, TextRange};
+///
+/// let src = "a = 1\nb   =   2\n";
+/// let range = TextRange {
+///     start: TextPosition { line: 2, column: 0 },
+///     end: TextPosition { line: 2, column: 9 },
+/// };
+/// let edits = format_range(src, range).unwrap();
+/// assert_eq!(edits.len(), 1);
+/// assert_eq!(edits[0].new_text, "b = 2\n");
+/// ```
+pub fn format_range(src: &str, range: TextRange) -> Result<Vec<TextEdit>> {
+    format_range_with_config(src, range, Config::default())
+}
+
+/// Formats the top-level statements covering the range of the source with
+/// the printer config and returns the text edits.
+pub fn format_range_with_config(src: &str, range: TextRange, cfg: Config) -> Result<Vec<TextEdit>> {
+    let module = match parse_file("", Some(src.to_string())) {
+        Ok(module) => module,
+        Err(err) => return Err(anyhow!("{}", err)),
+    };
+    let lines: Vec<&str> = src.split_inclusive('\n').collect();
+    let start_line = range.start.line;
+    // An end position at the start of a line does not select the line.
+    let end_line = if range.end.column == 0 && range.end.line > range.start.line {
+        range.end.line - 1
+    } else {
+        range.end.line
+    };
+    let (first, last) = match covering_stmts(&module.body, &lines, start_line, end_line) {
+        Some(covering) => covering,
+        None => return Ok(vec![]),
+    };
+    let seg_start = stmt_start_line(&module.body[first]);
+    let seg_end = stmt_end_line(&module.body, &lines, last);
+    let stmts = &module.body[first..=last];
+    let mut printer = Printer::new(cfg.clone(), &NoHook);
+    // Trailing comments of one line statements are kept at the end of the
+    // printed statements, other comments are written by the printer.
+    let mut inline_comments = vec![];
+    for comment in &module.comments {
+        if comment.line < seg_start || comment.line > seg_end {
+            continue;
+        }
+        match trailing_comment_stmt(&module.body, first..=last, &lines, comment)
+            .and_then(|stmt| print_one_line_stmt(stmt, &cfg))
+        {
+            Some(stmt_text) => inline_comments.push((stmt_text, comment.node.text.clone())),
+            None => printer.comments.push_back(comment.clone()),
+        }
+    }
+    printer.stmts(stmts);
+    while let Some(comment) = printer.comments.pop_front() {
+        printer.writeln(&comment.node.text);
+    }
+    let formatted = format!(
+        "{}\n",
+        append_inline_comments(printer.out.trim_end(), &inline_comments)
+    );
+    let original = lines[(seg_start - 1) as usize..seg_end as usize].concat();
+    Ok(diff_edits(&original, &formatted, seg_start))
+}
+
+/// Formats the top-level statement enclosing the position e.g., the cursor
+/// position after typing a newline, and returns the text edits.
+pub fn format_on_type(src: &str, pos: TextPosition) -> Result<Vec<TextEdit>> {
+    format_on_type_with_config(src, pos, Config::default())
+}
+
+/// Formats the top-level statement enclosing the position with the printer
+/// config and returns the text edits.
+pub fn format_on_type_with_config(
+    src: &str,
+    pos: TextPosition,
+    cfg: Config,
+) -> Result<Vec<TextEdit>> {
+    format_range_with_config(
+        src,
+        TextRange {
+            start: pos,
+            end: pos,
+        },
+        cfg,
+    )
+}
+
+/// Returns the one line statement which the comment trails e.g., `a = 1  # comment`.
+/// When several statements share the line, the last one is returned.
+fn trailing_comment_stmt<'a>(
+    body: &'a [ast::NodeRef<ast::Stmt>],
+    indices: RangeInclusive<usize>,
+    lines: &[&str],
+    comment: &ast::NodeRef<ast::Comment>,
+) -> Option<&'a ast::NodeRef<ast::Stmt>> {
+    let text = lines.get((comment.line - 1) as usize)?;
+    let code: String = text.chars().take(comment.column as usize).collect();
+    if code.trim().is_empty() {
+        return None;
+    }
+    indices
+        .rev()
+        .find(|i| body[*i].line == comment.line && stmt_end_line(body, lines, *i) == comment.line)
+        .map(|i| &body[i])
+}
+
+/// Prints the statement without comments and returns the text when it is
+/// printed in one line.
+fn print_one_line_stmt(stmt: &ast::NodeRef<ast::Stmt>, cfg: &Config) -> Option<String> {
+    let mut printer = Printer::new(cfg.clone(), &NoHook);
+    printer.stmt(stmt);
+    let text = printer.out.trim_end();
+    if text.contains('\n') {
+        None
+    } else {
+        Some(text.to_string())
+    }
+}
+
+/// Appends the comments to the printed statement lines in order.
+fn append_inline_comments(out: &str, inline_comments: &[(String, String)]) -> String {
+    let mut lines: Vec<String> = out.lines().map(|line| line.to_string()).collect();
+    let mut index = 0;
+    for (stmt_text, comment) in inline_comments {
+        if let Some(offset) = lines[index..].iter().position(|line| line == stmt_text) {
+            index += offset;
+            lines[index] = format!("{}  {}", lines[index], comment);
+            index += 1;
+        }
+    }
+    lines.join("\n")
+}
+
+/// Returns the first and last index of the top-level statements whose lines
+/// intersect with the selected lines.
+fn covering_stmts(
+    body: &[ast::NodeRef<ast::Stmt>],
+    lines: &[&str],
+    start_line: u64,
+    end_line: u64,
+) -> Option<(usize, usize)> {
+    let mut covering: Option<(usize, usize)> = None;
+    for (i, stmt) in body.iter().enumerate() {
+        if stmt_start_line(stmt) <= end_line && stmt_end_line(body, lines, i) >= start_line {
+            covering = Some(match covering {
+                Some((first, _)) => (first, i),
+                None => (i, i),
+            });
+        }
+    }
+    // Statements sharing the first line e.g., `a = 1; b = 2` are replaced
+    // together, so they must be printed together.
+    covering.map(|(mut first, last)| {
+        while first > 0 && body[first - 1].line == body[first].line {
+            first -= 1;
+        }
+        (first, last)
+    })
+}
+
+/// Returns the first line of a top-level statement, which is the line of the
+/// first decorator for decorated schema and rule statements.
+fn stmt_start_line(stmt: &ast::NodeRef<ast::Stmt>) -> u64 {
+    let decorators = match &stmt.node {
+        ast::Stmt::Schema(schema_stmt) => &schema_stmt.decorators,
+        ast::Stmt::Rule(rule_stmt) => &rule_stmt.decorators,
+        _ => return stmt.line,
+    };
+    decorators
+        .iter()
+        .map(|decorator| decorator.line)
+        .fold(stmt.line, u64::min)
+}
+
+/// Returns the last line of a top-level statement, which is the last line
+/// before the next statement except trailing blank and comment lines.
+fn stmt_end_line(body: &[ast::NodeRef<ast::Stmt>], lines: &[&str], index: usize) -> u64 {
+    let line = body[index].line;
+    let next_line = match body.get(index + 1) {
+        Some(next) => stmt_start_line(next),
+        None => lines.len() as u64 + 1,
+    };
+    if next_line <= line {
+        return line;
+    }
+    let mut end = next_line - 1;
+    while end > line {
+        let text = lines[(end - 1) as usize].trim();
+        if text.is_empty() || text.starts_with('#') {
+            end -= 1;
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+/// Computes the line based text edits from the original text to the
+/// formatted text, the original text starts at `start_line` of the source.
+fn diff_edits(original: &str, formatted: &str, start_line: u64) -> Vec<TextEdit> {
+    let diff = TextDiff::from_lines(original, formatted);
+    let old_lines = diff.old_slices();
+    let new_lines = diff.new_slices();
+    let mut edits = vec![];
+    for op in diff.ops() {
+        if op.tag() == DiffTag::Equal {
+            continue;
+        }
+        let old_range = op.old_range();
+        let start = TextPosition {
+            line: start_line + old_range.start as u64,
+            column: 0,
+        };
+        let end = if old_range.is_empty() {
+            start
+        } else {
+            let last = old_lines[old_range.end - 1];
+            let line = start_line + old_range.end as u64 - 1;
+            if last.ends_with('\n') {
+                TextPosition {
+                    line: line + 1,
+                    column: 0,
+                }
+            } else {
+                TextPosition {
+                    line,
+                    column: last.chars().count() as u64,
+                }
+            }
+        };
+        edits.push(TextEdit {
+            range: TextRange { start, end },
+            new_text: new_lines[op.new_range()].concat(),
+        });
+    }
+    edits
+}
+
+/// Applies the text edits on the source and returns the new source. The
+/// edits must not overlap with each other, and edits at the same position
+/// are applied in the order of the input.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_tools::format::{apply_text_edits, TextEdit, TextPosition, TextRange};
+///
+/// let edit = TextEdit {
+///     range: TextRange {
+///         start: TextPosition { line: 1, column: 4 },
+///         end: TextPosition { line: 1, column: 5 },
+///     },
+///     new_text: "2".to_string(),
+/// };
+/// assert_eq!(apply_text_edits("a = 1\n", &[edit]), "a = 2\n");
+/// ```
+pub fn apply_text_edits(src: &str, edits: &[TextEdit]) -> String {
+    let mut edits = edits.to_vec();
+    edits.sort_by(|a, b| a.range.start.cmp(&b.range.start));
+    let mut result = src.to_string();
+    // Apply the edits from the end of the source, so the offsets of the
+    // previous edits are still valid.
+    for edit in edits.iter().rev() {
+        let start = position_offset(src, edit.range.start);
+        let end = position_offset(src, edit.range.end).max(start);
+        result.replace_range(start..end, &edit.new_text);
+    }
+    result
+}
+
+/// Returns the byte offset of the position in the source, the offset is
+/// clamped to the end of the line or the end of the source.
+fn position_offset(src: &str, pos: TextPosition) -> usize {
+    let mut offset = 0;
+    for (i, line) in src.split_inclusive('\n').enumerate() {
+        if i as u64 + 1 == pos.line {
+            let content = line.strip_suffix('\n').unwrap_or(line);
+            return offset
+                + content
+                    .char_indices()
+                    .nth(pos.column as usize)
+                    .map(|(i, _)| i)
+                    .unwrap_or(content.len());
+        }
+        offset += line.len();
+    }
+    src.len()
+}
@@ -0,0 +1,4 @@
+[format]
+indent_width = 2
+quote_style = "single"
+trailing_comma = "always"
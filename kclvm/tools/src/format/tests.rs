@@ -1,4 +1,5 @@
 use super::*;
+use crate::printer::{QuoteStyle, TrailingComma};
 use pretty_assertions::assert_eq;
 
 const FILE_INPUT_SUFFIX: &str = ".input";
@@ -72,6 +73,7 @@ fn test_format_with_stdout_option() {
     let opts = FormatOptions {
         is_stdout: true,
         recursively: false,
+        ..Default::default()
     };
     let changed_files = format("./src/format/test_data/format_path_data/if.k", &opts).unwrap();
     assert_eq!(changed_files.len(), 1);
@@ -80,7 +82,149 @@ fn test_format_with_stdout_option() {
     let opts = FormatOptions {
         is_stdout: true,
         recursively: true,
+        ..Default::default()
     };
     let changed_files = format("./src/format/test_data/format_path_data/", &opts).unwrap();
     assert_eq!(changed_files.len(), 2);
 }
+
+#[test]
+fn test_format_with_check_option() {
+    let file = "./src/format/test_data/format_path_data/if.k";
+    let src = std::fs::read_to_string(file).unwrap();
+    let opts = FormatOptions {
+        check: true,
+        ..Default::default()
+    };
+    let changed_files = format(file, &opts).unwrap();
+    assert_eq!(changed_files.len(), 1);
+    // The check mode must not modify the file.
+    assert_eq!(std::fs::read_to_string(file).unwrap(), src);
+    let opts = FormatOptions {
+        check: true,
+        recursively: true,
+        ..Default::default()
+    };
+    let changed_files = format("./src/format/test_data/format_path_data/", &opts).unwrap();
+    assert_eq!(changed_files.len(), 2);
+}
+
+#[test]
+fn test_diff_file() {
+    let file = "./src/format/test_data/format_path_data/if.k";
+    let diff = diff_file(file).unwrap().unwrap();
+    assert!(diff.starts_with(&format!("--- {}\n+++ {}\n", file, file)));
+    assert!(diff.contains("+    a = 1\n"));
+    assert!(diff.contains("- a = 1\n"));
+    let changed_files = format(
+        file,
+        &FormatOptions {
+            diff: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(changed_files.len(), 1);
+}
+
+#[test]
+fn test_format_with_mod_file_config() {
+    let file = "./src/format/test_data/format_config_data/main.k";
+    let cfg = load_format_config(file).unwrap();
+    assert_eq!(cfg.indent_len, 2);
+    assert_eq!(cfg.quote_style, QuoteStyle::Single);
+    assert_eq!(cfg.trailing_comma, TrailingComma::Always);
+    assert_eq!(cfg.blank_lines_between_schemas, 1);
+    let src = std::fs::read_to_string(file).unwrap();
+    let (_, is_formatted) = format_source_with_config(&src, cfg).unwrap();
+    assert!(!is_formatted);
+    // The file is not formatted with the default config.
+    let (_, is_formatted) = format_source(&src).unwrap();
+    assert!(is_formatted);
+}
+
+#[test]
+fn test_format_range() {
+    let src = "a   =   1\nschema A:\n    name:str\n\nb   =   2\n";
+    let range = TextRange {
+        start: TextPosition { line: 3, column: 0 },
+        end: TextPosition { line: 3, column: 4 },
+    };
+    assert_eq!(
+        format_range(src, range).unwrap(),
+        vec![TextEdit {
+            range: TextRange {
+                start: TextPosition { line: 3, column: 0 },
+                end: TextPosition { line: 4, column: 0 },
+            },
+            new_text: "    name: str\n".to_string(),
+        }]
+    );
+    let range = TextRange {
+        start: TextPosition { line: 1, column: 0 },
+        end: TextPosition { line: 5, column: 0 },
+    };
+    let edits = format_range(src, range).unwrap();
+    assert_eq!(edits.len(), 2);
+    assert_eq!(edits[0].new_text, "a = 1\n");
+    assert_eq!(edits[1].new_text, "    name: str\n");
+}
+
+#[test]
+fn test_format_range_decorated_schema() {
+    let src = "a   =   1\n@deprecated()\nschema A:\n    name:str\n\nb = 2\n";
+    let range = TextRange {
+        start: TextPosition { line: 1, column: 0 },
+        end: TextPosition {
+            line: 4,
+            column: 12,
+        },
+    };
+    let edits = format_range(src, range).unwrap();
+    assert_eq!(edits.len(), 2);
+    assert_eq!(edits[0].new_text, "a = 1\n");
+    assert_eq!(edits[1].new_text, "    name: str\n");
+    // The decorator line belongs to the schema instead of the previous statement.
+    let range = TextRange {
+        start: TextPosition { line: 2, column: 0 },
+        end: TextPosition {
+            line: 2,
+            column: 13,
+        },
+    };
+    assert_eq!(
+        format_range(src, range).unwrap(),
+        vec![TextEdit {
+            range: TextRange {
+                start: TextPosition { line: 4, column: 0 },
+                end: TextPosition { line: 5, column: 0 },
+            },
+            new_text: "    name: str\n".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_format_on_type() {
+    let src = "a = 1\nb = {x   =   1}  # comment\nc   =   3\n";
+    let edits = format_on_type(src, TextPosition { line: 2, column: 5 }).unwrap();
+    assert_eq!(
+        edits,
+        vec![TextEdit {
+            range: TextRange {
+                start: TextPosition { line: 2, column: 0 },
+                end: TextPosition { line: 3, column: 0 },
+            },
+            new_text: "b = {x = 1}  # comment\n".to_string(),
+        }]
+    );
+    let edits = format_on_type("a = 1\n", TextPosition { line: 1, column: 5 }).unwrap();
+    assert!(edits.is_empty());
+    let edits = format_on_type("a = 1  # comment\n", TextPosition { line: 1, column: 5 }).unwrap();
+    assert!(edits.is_empty());
+    let src = "# leading\nb  =  2   # trailing\n# next\nc = 3\n";
+    let edits = format_on_type(src, TextPosition { line: 2, column: 3 }).unwrap();
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].range.start, TextPosition { line: 2, column: 0 });
+    assert_eq!(edits[0].new_text, "b = 2  # trailing\n");
+}
@@ -1,11 +1,30 @@
-use indexmap::IndexSet;
-use kclvm_error::{Diagnostic, Level};
-use kclvm_parser::{load_program, LoadProgramOptions};
-use kclvm_sema::resolver::resolve_program;
+use anyhow::Result;
+use indexmap::{IndexMap, IndexSet};
+use kclvm_ast::ast;
+use kclvm_config::modfile::{get_pkg_root, load_mod_file};
+use kclvm_error::{Diagnostic, Level, Position, Suggestion};
+use kclvm_parser::{try_load_program, LoadProgramOptions};
+use kclvm_sema::lint::{LintConfig, Suppressions};
+use kclvm_sema::resolver::{resolve_program_with_options, Options};
+
+use crate::format::{apply_text_edits, TextEdit, TextPosition, TextRange};
+use std::path::{Path, PathBuf};
+
+pub mod plugin;
 #[cfg(test)]
 mod tests;
 
-/// KCL Lint tools API, check a set of files, skips execute, divides and returns diagnostics into error and warning
+/// The max number of rounds to lint and fix the files. Fixes are applied round
+/// by round because the overlapping fixes of a round are deferred to the next
+/// round and the fixes may produce new diagnostics.
+const MAX_FIX_ROUNDS: usize = 10;
+
+/// KCL Lint tools API, check a set of files, skips execute, divides and returns diagnostics into error and warning.
+/// The lints are configured by the `[lint]` section of the kcl.mod file (see [load_lint_config]) and can be
+/// suppressed by inline comments e.g., `# kcl-lint: disable=W0411`. When `load_plugins` is set, the user-defined
+/// lint rules of the plugins in the `[lint]` section are checked on the main package (see [plugin]). Plugins are
+/// native dynamic libraries, so they are never loaded unless the caller opts in. The syntax errors of the files
+/// are returned as the error diagnostics instead of aborting the process.
 ///
 /// # Parameters
 ///
@@ -15,6 +34,9 @@ mod tests;
 /// `opts`: Option<LoadProgramOptions>
 ///     The compilation parameters of KCL, same as the compilation process
 ///
+/// `load_plugins`: [bool]
+///     Whether to load and run the lint plugins configured in the kcl.mod file
+///
 /// # Returns
 ///
 /// result: (IndexSet<Diagnostic>, IndexSet<Diagnostic>)
@@ -24,7 +46,7 @@ mod tests;
 ///
 /// ```no_run
 /// use kclvm_tools::lint::lint_files;
-/// let (error, warning) = lint_files(&["test.k"], None);
+/// let (error, warning) = lint_files(&["test.k"], None, false);
 /// ```
 ///
 /// - test.k
@@ -56,12 +78,33 @@ mod tests;
 pub fn lint_files(
     files: &[&str],
     opts: Option<LoadProgramOptions>,
+    load_plugins: bool,
 ) -> (IndexSet<Diagnostic>, IndexSet<Diagnostic>) {
-    // Parse AST program.
-    let mut program = load_program(&files, opts).unwrap();
-    let scope = resolve_program(&mut program);
+    // Parse AST program, the syntax error is reported as the error diagnostic.
+    let mut program = match try_load_program(files, opts) {
+        Ok(program) => program,
+        Err(err) => return ([err].into_iter().collect(), IndexSet::new()),
+    };
+    // Collect all diagnostics without raising the compile errors.
+    let lint_config = match files.first() {
+        Some(file) => load_lint_config(file),
+        None => LintConfig::default(),
+    };
+    let mut diagnostics = resolve_program_with_options(
+        &mut program,
+        Options {
+            raise_err: false,
+            config_auto_fix: false,
+            lint_check: true,
+            lint_config: lint_config.clone(),
+        },
+    )
+    .diagnostics;
+    if let (Some(file), true) = (files.first(), load_plugins) {
+        diagnostics.extend(check_plugins(file, &program, &lint_config));
+    }
     let (mut errs, mut warnings) = (IndexSet::new(), IndexSet::new());
-    for diag in &scope.diagnostics {
+    for diag in &diagnostics {
         if diag.level == Level::Error {
             errs.insert(diag.clone());
         } else if diag.level == Level::Warning {
@@ -72,3 +115,149 @@ pub fn lint_files(
     }
     (errs, warnings)
 }
+
+/// Checks the modules of the main package with the lint plugins of the kcl.mod
+/// file, the failures of loading and running plugins are reported as errors.
+/// The plugin rules are configured and suppressed by their codes in the same
+/// way as the builtin lints.
+fn check_plugins(file: &str, program: &ast::Program, lint_config: &LintConfig) -> Vec<Diagnostic> {
+    let error = |err: anyhow::Error| {
+        Diagnostic::new(
+            Level::Error,
+            &err.to_string(),
+            Position {
+                filename: file.to_string(),
+                line: 1,
+                column: None,
+            },
+        )
+    };
+    let plugins = match plugin::load_lint_plugins(file) {
+        Ok(plugins) => plugins,
+        Err(err) => return vec![error(err)],
+    };
+    let mut diagnostics = IndexSet::new();
+    for plugin in &plugins {
+        for module in program.pkgs.get(&program.main).into_iter().flatten() {
+            match plugin.check_module(module) {
+                Ok(diags) => diagnostics.extend(diags),
+                Err(err) => {
+                    diagnostics.insert(error(err));
+                }
+            }
+        }
+    }
+    lint_config.apply(&mut diagnostics);
+    Suppressions::from_program(program).apply(&mut diagnostics);
+    diagnostics.into_iter().collect()
+}
+
+/// Loads the lint config from the `[lint]` section of the kcl.mod file of the
+/// package the file belongs to, returns the default config when there is no
+/// kcl.mod file or lint section. Allowed lints are not reported and denied
+/// lints are reported as errors.
+///
+/// - kcl.mod
+///
+/// ```toml
+/// [lint]
+/// allow = ["W0413"]
+/// warn = ["unused_argument"]
+/// deny = ["unused_import", "W0404"]
+/// ```
+pub fn load_lint_config(file: &str) -> LintConfig {
+    match get_pkg_root(file) {
+        Some(root) => match load_mod_file(&root).lint {
+            Some(lint) => LintConfig {
+                allow: lint.allow.unwrap_or_default(),
+                warn: lint.warn.unwrap_or_default(),
+                deny: lint.deny.unwrap_or_default(),
+            },
+            None => LintConfig::default(),
+        },
+        None => LintConfig::default(),
+    }
+}
+
+/// Applies the suggested fixes of the lint diagnostics e.g., removing unused imports
+/// on the files as minimal text edits and returns the fixed filenames, only the input
+/// files and the files in the input directories are written. The files are linted
+/// again after fixing until there are no more fixes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_tools::lint::fix_files;
+/// let fixed_files = fix_files(&["test.k"], None).unwrap();
+/// ```
+pub fn fix_files(files: &[&str], opts: Option<LoadProgramOptions>) -> Result<Vec<String>> {
+    let input_paths = files
+        .iter()
+        .map(|file| Path::new(file).canonicalize())
+        .collect::<std::io::Result<Vec<PathBuf>>>()?;
+    let is_input_file = |filename: &str| match Path::new(filename).canonicalize() {
+        Ok(path) => input_paths.iter().any(|input| path.starts_with(input)),
+        Err(_) => false,
+    };
+    let mut fixed_files = IndexSet::new();
+    for _ in 0..MAX_FIX_ROUNDS {
+        // Plugin rules have no suggested fixes, so the plugins are not loaded.
+        let (errors, warnings) = lint_files(files, opts.clone(), false);
+        // The suggestions of each diagnostic grouped by the filename.
+        let mut suggestions: IndexMap<String, Vec<&[Suggestion]>> = IndexMap::new();
+        for diag in errors.iter().chain(warnings.iter()) {
+            match diag.suggestions.first() {
+                Some(suggestion) if is_input_file(&suggestion.filename) => suggestions
+                    .entry(suggestion.filename.clone())
+                    .or_default()
+                    .push(&diag.suggestions),
+                _ => {}
+            }
+        }
+        if suggestions.is_empty() {
+            break;
+        }
+        for (filename, suggestions) in suggestions {
+            let src = std::fs::read_to_string(&filename)?;
+            let src = apply_text_edits(&src, &non_overlapping_edits(&suggestions));
+            std::fs::write(&filename, src)?;
+            fixed_files.insert(filename);
+        }
+    }
+    Ok(fixed_files.into_iter().collect())
+}
+
+/// Convert the suggestions of diagnostics to text edits. The suggestions of a
+/// diagnostic are applied together, and they are skipped when any of them
+/// overlaps with the edits of the previous diagnostics.
+fn non_overlapping_edits(suggestions: &[&[Suggestion]]) -> Vec<TextEdit> {
+    let mut edits: Vec<TextEdit> = vec![];
+    for diag_suggestions in suggestions {
+        let diag_edits = diag_suggestions
+            .iter()
+            .map(|suggestion| TextEdit {
+                range: TextRange {
+                    start: TextPosition {
+                        line: suggestion.start_line,
+                        column: suggestion.start_column,
+                    },
+                    end: TextPosition {
+                        line: suggestion.end_line,
+                        column: suggestion.end_column,
+                    },
+                },
+                new_text: suggestion.replacement.clone(),
+            })
+            .collect::<Vec<TextEdit>>();
+        let overlapped = diag_edits.iter().any(|edit| {
+            edits.iter().any(|other| {
+                edit.range.start == other.range.start
+                    || (edit.range.start < other.range.end && other.range.start < edit.range.end)
+            })
+        });
+        if !overlapped {
+            edits.extend(diag_edits);
+        }
+    }
+    edits
+}
@@ -0,0 +1,156 @@
+//! User-defined lint rules loaded from dynamic libraries. The lint plugins are
+//! configured in the `[lint]` section of the kcl.mod file, the plugin paths are
+//! relative to the directory of the kcl.mod file.
+//!
+//! ```toml
+//! [lint]
+//! plugins = ["./lint/libhouse_rules.so"]
+//! ```
+//!
+//! A lint plugin is a dynamic library exporting the C ABI functions:
+//!
+//! ```c
+//! // Check the JSON AST module and return the JSON array of the lint results.
+//! const char* kcl_lint_check(const char* module_json);
+//! // Free the result returned by `kcl_lint_check`.
+//! void kcl_lint_free(const char* result);
+//! ```
+//!
+//! The module JSON is the serialized [kclvm_ast::ast::Module], and each lint result
+//! is an object such as:
+//!
+//! ```json
+//! {"code": "HOUSE001", "message": "Schema name 'person' should be in PascalCase", "line": 1, "column": 0, "note": null, "level": "warning"}
+//! ```
+//!
+//! where the line is 1-based, the column is 0-based, and `column`, `note` and
+//! `level` (`warning` or `error`, default is `warning`) can be omitted. The rule
+//! code can be used in the `allow`, `warn` and `deny` lists of the `[lint]` section
+//! and the `# kcl-lint: disable=HOUSE001` comments like the builtin lint codes.
+//!
+//! Plugins run native code in the linter process, so they are only loaded when
+//! the user opts in e.g., with the `--lint_plugins` flag of `kcl lint`.
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::path::Path;
+
+use kclvm_ast::ast;
+use kclvm_config::modfile::{get_pkg_root, load_mod_file};
+use kclvm_error::{Diagnostic, DiagnosticId, Level, Message, Position, Style};
+
+const LINT_CHECK_SYMBOL: &[u8] = b"kcl_lint_check";
+const LINT_FREE_SYMBOL: &[u8] = b"kcl_lint_free";
+
+/// A lint result reported by the lint plugin.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginLintResult {
+    pub code: String,
+    pub message: String,
+    pub line: u64,
+    #[serde(default)]
+    pub column: Option<u64>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub level: Option<String>,
+}
+
+/// A lint plugin loaded from the dynamic library.
+pub struct LintPlugin {
+    pub path: String,
+    lib: libloading::Library,
+}
+
+impl LintPlugin {
+    /// Load the lint plugin from the dynamic library path.
+    pub fn load(path: &str) -> Result<Self> {
+        let lib = unsafe { libloading::Library::new(path) }
+            .map_err(|err| anyhow!("Failed to load lint plugin {}: {}", path, err))?;
+        for symbol in [LINT_CHECK_SYMBOL, LINT_FREE_SYMBOL] {
+            if unsafe { lib.get::<*const ()>(symbol) }.is_err() {
+                return Err(anyhow!(
+                    "Invalid lint plugin {}: symbol {} not found",
+                    path,
+                    String::from_utf8_lossy(symbol)
+                ));
+            }
+        }
+        Ok(LintPlugin {
+            path: path.to_string(),
+            lib,
+        })
+    }
+
+    /// Check the module with the lint plugin and return the diagnostics.
+    pub fn check_module(&self, module: &ast::Module) -> Result<Vec<Diagnostic>> {
+        let module_json = CString::new(serde_json::to_string(module)?)?;
+        let result_json = unsafe {
+            let check: libloading::Symbol<unsafe extern "C" fn(*const c_char) -> *const c_char> =
+                self.lib.get(LINT_CHECK_SYMBOL)?;
+            let free: libloading::Symbol<unsafe extern "C" fn(*const c_char)> =
+                self.lib.get(LINT_FREE_SYMBOL)?;
+            let result = check(module_json.as_ptr());
+            if result.is_null() {
+                return Err(anyhow!("Lint plugin {} returns a null result", self.path));
+            }
+            let result_json = CStr::from_ptr(result).to_string_lossy().to_string();
+            free(result);
+            result_json
+        };
+        plugin_results_to_diagnostics(&module.filename, &result_json)
+            .map_err(|err| anyhow!("Invalid result of lint plugin {}: {}", self.path, err))
+    }
+}
+
+/// Load the lint plugins from the `[lint]` section of the kcl.mod file of the
+/// package the file belongs to.
+pub fn load_lint_plugins(file: &str) -> Result<Vec<LintPlugin>> {
+    let root = match get_pkg_root(file) {
+        Some(root) => root,
+        None => return Ok(vec![]),
+    };
+    let plugins = match load_mod_file(&root).lint.and_then(|lint| lint.plugins) {
+        Some(plugins) => plugins,
+        None => return Ok(vec![]),
+    };
+    plugins
+        .iter()
+        .map(|plugin| LintPlugin::load(&Path::new(&root).join(plugin).to_string_lossy()))
+        .collect()
+}
+
+/// Convert the JSON lint results of the plugin to diagnostics with the rule codes.
+pub fn plugin_results_to_diagnostics(filename: &str, result_json: &str) -> Result<Vec<Diagnostic>> {
+    let results: Vec<PluginLintResult> = serde_json::from_str(result_json)?;
+    results
+        .into_iter()
+        .map(|result| {
+            let level = match result.level.as_deref() {
+                None | Some("warning") => Level::Warning,
+                Some("error") => Level::Error,
+                Some(level) => return Err(anyhow!("Invalid lint level {}", level)),
+            };
+            Ok(Diagnostic {
+                level,
+                messages: vec![Message {
+                    pos: Position {
+                        filename: filename.to_string(),
+                        line: result.line,
+                        column: result.column,
+                    },
+                    style: if result.column.is_some() {
+                        Style::LineAndColumn
+                    } else {
+                        Style::Line
+                    },
+                    message: result.message,
+                    note: result.note,
+                }],
+                code: Some(DiagnosticId::Plugin(result.code)),
+                suggestions: vec![],
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,3 @@
+[lint]
+allow = ["reimport"]
+deny = ["W0411"]
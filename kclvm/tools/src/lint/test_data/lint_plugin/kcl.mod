@@ -0,0 +1,2 @@
+[lint]
+plugins = ["./not_exist_plugin.so"]
@@ -1,14 +1,180 @@
-use super::lint_files;
+use super::{fix_files, lint_files, plugin};
+use indexmap::IndexSet;
+use kclvm_error::{Diagnostic, DiagnosticId, Level};
+use kclvm_parser::parse_file;
+use kclvm_sema::lint::{LintConfig, Suppressions};
+use tempfile::tempdir;
 
 #[test]
 fn test_lint() {
-    let (_, warnings) = lint_files(&vec!["./src/lint/test_data/lint.k"], None);
+    let (_, warnings) = lint_files(&vec!["./src/lint/test_data/lint.k"], None, false);
     let msgs = [
-        "Importstmt should be placed at the top of the module",
-        "Module 'a' is reimported multiple times",
         "Module 'import_test.a' imported but unused",
+        "Module 'a' is reimported multiple times",
+        "Importstmt should be placed at the top of the module",
     ];
     for (diag, m) in warnings.iter().zip(msgs.iter()) {
         assert_eq!(diag.messages[0].message, m.to_string());
     }
 }
+
+#[test]
+fn test_lint_syntax_error() {
+    let temp_dir = tempdir().unwrap();
+    let file = temp_dir.path().join("main.k");
+    let file = file.to_str().unwrap();
+    std::fs::write(file, "a = 1\nb = 1 +\n").unwrap();
+    let (errors, warnings) = lint_files(&[file], None, false);
+    assert_eq!(errors.len(), 1);
+    assert!(warnings.is_empty());
+    let pos = &errors[0].messages[0].pos;
+    assert_eq!(pos.filename, file);
+    assert_eq!(pos.line, 2);
+}
+
+#[test]
+fn test_lint_config() {
+    let (errors, warnings) = lint_files(&["./src/lint/test_data/lint_config/main.k"], None, false);
+    let errors = errors
+        .iter()
+        .map(|diag| diag.messages[0].message.clone())
+        .collect::<Vec<String>>();
+    let warnings = warnings
+        .iter()
+        .map(|diag| diag.messages[0].message.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(errors, ["Module 'math' imported but unused"]);
+    // The trailing suppression comment of `_x` does not suppress the next line.
+    assert_eq!(
+        warnings,
+        [
+            "Private variable '_z' is assigned but never used",
+            "Comparison to None should be 'is None'"
+        ]
+    );
+}
+
+#[test]
+fn test_fix_files() {
+    let temp_dir = tempdir().unwrap();
+    let file = temp_dir.path().join("fix.k");
+    let file = file.to_str().unwrap();
+    std::fs::write(
+        file,
+        r#"import math
+import math
+import net
+
+a = net.is_IP("1.1.1.1")
+import base64
+b = base64.encode("1")
+"#,
+    )
+    .unwrap();
+    let fixed_files = fix_files(&[file], None).unwrap();
+    assert_eq!(fixed_files.len(), 1);
+    // Only the suggested edits are applied, the rest of the file is kept as is.
+    assert_eq!(
+        std::fs::read_to_string(file).unwrap(),
+        r#"import net
+
+import base64
+a = net.is_IP("1.1.1.1")
+b = base64.encode("1")
+"#
+    );
+    let (_, warnings) = lint_files(&[file], None, false);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_fix_files_only_writes_input_files() {
+    let temp_dir = tempdir().unwrap();
+    std::fs::write(temp_dir.path().join("kcl.mod"), "").unwrap();
+    std::fs::create_dir(temp_dir.path().join("pkg")).unwrap();
+    let pkg_file = temp_dir.path().join("pkg").join("pkg.k");
+    let pkg_src = "import math\nb = 1\n";
+    std::fs::write(&pkg_file, pkg_src).unwrap();
+    let file = temp_dir.path().join("main.k");
+    let file = file.to_str().unwrap();
+    std::fs::write(file, "import math\nimport pkg\na = pkg.b\n").unwrap();
+    let fixed_files = fix_files(&[file], None).unwrap();
+    assert_eq!(fixed_files.len(), 1);
+    assert_eq!(
+        std::fs::read_to_string(file).unwrap(),
+        "import pkg\na = pkg.b\n"
+    );
+    assert_eq!(std::fs::read_to_string(&pkg_file).unwrap(), pkg_src);
+}
+
+#[test]
+fn test_plugin_results_to_diagnostics() {
+    let diags = plugin::plugin_results_to_diagnostics(
+        "main.k",
+        r#"[{"code": "HOUSE001", "message": "Schema name 'person' should be in PascalCase", "line": 1, "column": 7},
+            {"code": "HOUSE002", "message": "Missing docstring", "line": 2, "level": "error"}]"#,
+    )
+    .unwrap();
+    assert_eq!(diags.len(), 2);
+    assert_eq!(diags[0].level, Level::Warning);
+    assert_eq!(
+        diags[0].code,
+        Some(DiagnosticId::Plugin("HOUSE001".to_string()))
+    );
+    assert_eq!(
+        diags[0].messages[0].message,
+        "Schema name 'person' should be in PascalCase"
+    );
+    assert_eq!(diags[0].messages[0].pos.column, Some(7));
+    assert_eq!(diags[1].level, Level::Error);
+    assert_eq!(diags[1].messages[0].pos.line, 2);
+    assert!(plugin::plugin_results_to_diagnostics("main.k", r#"[{"code": "HOUSE001"}]"#).is_err());
+}
+
+#[test]
+fn test_plugin_diagnostics_config_and_suppressions() {
+    let module = parse_file(
+        "main.k",
+        Some("a = 1  # kcl-lint: disable=HOUSE003\nb = 2\nc = 3\n".to_string()),
+    )
+    .unwrap();
+    let mut suppressions = Suppressions::default();
+    suppressions.add_module(&module);
+    let mut diags: IndexSet<Diagnostic> = plugin::plugin_results_to_diagnostics(
+        "main.k",
+        r#"[{"code": "HOUSE001", "message": "Allowed", "line": 1},
+            {"code": "HOUSE002", "message": "Denied", "line": 2},
+            {"code": "HOUSE003", "message": "Suppressed", "line": 1},
+            {"code": "HOUSE003", "message": "Not suppressed", "line": 2}]"#,
+    )
+    .unwrap()
+    .into_iter()
+    .collect();
+    let config = LintConfig {
+        allow: vec!["HOUSE001".to_string()],
+        warn: vec![],
+        deny: vec!["house002".to_string()],
+    };
+    config.apply(&mut diags);
+    suppressions.apply(&mut diags);
+    let diags = diags
+        .iter()
+        .map(|diag| (diag.level, diag.messages[0].message.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        diags,
+        [(Level::Error, "Denied"), (Level::Warning, "Not suppressed")]
+    );
+}
+
+#[test]
+fn test_lint_plugin_load_failed() {
+    // Plugins are not loaded without opting in.
+    let (errors, _) = lint_files(&["./src/lint/test_data/lint_plugin/main.k"], None, false);
+    assert!(errors.is_empty());
+    let (errors, _) = lint_files(&["./src/lint/test_data/lint_plugin/main.k"], None, true);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].messages[0]
+        .message
+        .starts_with("Failed to load lint plugin"));
+}
@@ -1,5 +1,6 @@
 use indexmap::IndexMap;
 use std::collections::VecDeque;
+use std::str::FromStr;
 
 use kclvm_ast::{ast, token::TokenKind, walker::MutSelfTypedResultWalker};
 
@@ -22,13 +23,73 @@ pub enum Indentation {
     Fill = 5,
 }
 
+/// Quote style of string literals.
+/// - `Preserve` keeps the original quotes.
+/// - `Double` prefers double quotes e.g., "abc".
+/// - `Single` prefers single quotes e.g., 'abc'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    Preserve,
+    Double,
+    Single,
+}
+
+impl FromStr for QuoteStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(QuoteStyle::Preserve),
+            "double" => Ok(QuoteStyle::Double),
+            "single" => Ok(QuoteStyle::Single),
+            _ => Err(format!(
+                "invalid quote style '{}', expected one of preserve, double and single",
+                s
+            )),
+        }
+    }
+}
+
+/// Trailing comma policy of multi-line list and config expressions.
+/// - `Never` separates elements only with newlines.
+/// - `Always` writes a comma after each element including the last one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingComma {
+    Never,
+    Always,
+}
+
+impl FromStr for TrailingComma {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" => Ok(TrailingComma::Never),
+            "always" => Ok(TrailingComma::Always),
+            _ => Err(format!(
+                "invalid trailing comma policy '{}', expected one of never and always",
+                s
+            )),
+        }
+    }
+}
+
 /// Printer config
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Config {
     pub tab_len: usize,
     pub indent_len: usize,
     pub use_spaces: bool,
     pub write_comments: bool,
+    /// The max line width, list and config expressions written in one line
+    /// that exceed it are wrapped into multiple lines. Note that call arguments
+    /// are always kept in one line because newlines are not allowed between them.
+    pub max_line_width: Option<usize>,
+    pub quote_style: QuoteStyle,
+    pub trailing_comma: TrailingComma,
+    /// The number of blank lines between a schema statement and the next statement,
+    /// at least one blank line is written.
+    pub blank_lines_between_schemas: usize,
 }
 
 impl Default for Config {
@@ -38,6 +99,10 @@ impl Default for Config {
             indent_len: 4,
             use_spaces: true,
             write_comments: true,
+            max_line_width: None,
+            quote_style: QuoteStyle::Preserve,
+            trailing_comma: TrailingComma::Never,
+            blank_lines_between_schemas: 1,
         }
     }
 }
@@ -166,6 +231,39 @@ impl<'p> Printer<'p> {
         self.write_string(NEWLINE);
     }
 
+    /// Returns the (0-based) column of the output end.
+    #[inline]
+    pub fn current_column(&self) -> usize {
+        match self.out.rfind(NEWLINE) {
+            Some(pos) => self.out[pos + 1..].chars().count(),
+            None => self.out.chars().count(),
+        }
+    }
+
+    /// Returns whether the one line output written by `write` fits in the
+    /// max line width from the current column.
+    pub fn fits_in_line<F>(&self, write: F) -> bool
+    where
+        F: FnOnce(&mut Printer<'_>),
+    {
+        match self.cfg.max_line_width {
+            Some(max_line_width) => {
+                let mut printer = Printer::new(
+                    Config {
+                        write_comments: false,
+                        max_line_width: None,
+                        ..self.cfg.clone()
+                    },
+                    &NoHook,
+                );
+                write(&mut printer);
+                !printer.out.contains(NEWLINE)
+                    && self.current_column() + printer.out.chars().count() <= max_line_width
+            }
+            None => true,
+        }
+    }
+
     /// Print value
     #[inline]
     pub fn write_value<T: std::fmt::Display>(&mut self, value: T) {
@@ -250,6 +348,13 @@ pub fn print_ast_module(module: &ast::Module) -> String {
     printer.out
 }
 
+/// Print AST to string with the printer config.
+pub fn print_ast_module_with_config(module: &ast::Module, cfg: Config) -> String {
+    let mut printer = Printer::new(cfg, &NoHook);
+    printer.write_module(module);
+    printer.out
+}
+
 /// Print AST to string
 pub fn print_ast_node(node: ASTNode) -> String {
     let mut printer = Printer::default();
@@ -4,7 +4,7 @@ use kclvm_ast::ast::{self, CallExpr};
 use kclvm_ast::token::{DelimToken, TokenKind};
 use kclvm_ast::walker::MutSelfTypedResultWalker;
 
-use super::{Indentation, Printer};
+use super::{Indentation, Printer, QuoteStyle, TrailingComma};
 
 type ParameterType<'a> = (
     (
@@ -399,6 +399,8 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
     fn walk_call_expr(&mut self, call_expr: &'ctx ast::CallExpr) -> Self::Result {
         self.expr(&call_expr.func);
         self.write("(");
+        // The call arguments are never wrapped by the max line width, because the
+        // parser does not allow newlines between them.
         self.write_args_and_kwargs(&call_expr.args, &call_expr.keywords);
         self.write(")");
     }
@@ -445,6 +447,13 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
                 in_one_line = false;
             }
         }
+        // Wrap the list expression that exceeds the max line width.
+        if in_one_line
+            && !list_expr.elts.is_empty()
+            && !self.fits_in_line(|printer| printer.walk_list_expr(list_expr))
+        {
+            in_one_line = false;
+        }
         self.write_token(TokenKind::OpenDelim(DelimToken::Bracket));
         if !in_one_line {
             self.write_indentation(Indentation::IndentWithNewline);
@@ -455,7 +464,12 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
             } else {
                 self.write_newline();
             },
-            |elt| self.expr(elt),
+            |elt: &ast::NodeRef<ast::Expr>| {
+                self.expr(elt);
+                if !in_one_line && !matches!(elt.node, ast::Expr::ListIfItem(_)) {
+                    self.write_trailing_comma();
+                }
+            },
             list_expr.elts
         );
         if !in_one_line {
@@ -599,6 +613,13 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
                 in_one_line = false;
             }
         }
+        // Wrap the config expression that exceeds the max line width.
+        if in_one_line
+            && !config_expr.items.is_empty()
+            && !self.fits_in_line(|printer| printer.walk_config_expr(config_expr))
+        {
+            in_one_line = false;
+        }
         self.write_token(TokenKind::OpenDelim(DelimToken::Brace));
         if !config_expr.items.is_empty() {
             if !in_one_line {
@@ -610,7 +631,13 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
                 } else {
                     self.write_newline();
                 },
-                |entry: &ast::NodeRef<ast::ConfigEntry>| self.write_entry(entry),
+                |entry: &ast::NodeRef<ast::ConfigEntry>| {
+                    self.write_entry(entry);
+                    if !in_one_line && !matches!(entry.node.value.node, ast::Expr::ConfigIfEntry(_))
+                    {
+                        self.write_trailing_comma();
+                    }
+                },
                 config_expr.items
             );
             if !in_one_line {
@@ -713,7 +740,17 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
 
     fn walk_string_lit(&mut self, string_lit: &'ctx ast::StringLit) -> Self::Result {
         if !string_lit.raw_value.is_empty() {
-            self.write(&string_lit.raw_value)
+            let quote = match self.cfg.quote_style {
+                QuoteStyle::Preserve => None,
+                QuoteStyle::Double => Some('"'),
+                QuoteStyle::Single => Some('\''),
+            };
+            match quote.and_then(|quote| requote_string(&string_lit.raw_value, quote)) {
+                Some(raw_value) => self.write(&raw_value),
+                None => self.write(&string_lit.raw_value),
+            }
+        } else if let QuoteStyle::Single = self.cfg.quote_style {
+            self.write(&format!("'{}'", string_lit.value.replace('\'', "\\'")));
         } else {
             self.write(&format!("\"{}\"", string_lit.value.replace('\"', "\\\"")));
         }
@@ -755,7 +792,79 @@ impl<'p, 'ctx> MutSelfTypedResultWalker<'ctx> for Printer<'p> {
     }
 }
 
+/// Rewrite a quoted string literal with the quote character, returns `None`
+/// for long strings, raw strings and strings already quoted with the quote.
+/// The string interpolation spans `${...}` are kept as is, and the string is
+/// not rewritten when the spans contain the quote character.
+pub(crate) fn requote_string(raw_value: &str, quote: char) -> Option<String> {
+    let origin_quote = match raw_value.chars().next() {
+        Some(c) if c == '"' || c == '\'' => c,
+        // Raw strings and prefixed strings are kept as is.
+        _ => return None,
+    };
+    if origin_quote == quote
+        || raw_value.len() < 2
+        || !raw_value.ends_with(origin_quote)
+        || raw_value.starts_with(&origin_quote.to_string().repeat(3))
+    {
+        return None;
+    }
+    let content = &raw_value[1..raw_value.len() - 1];
+    let mut result = String::with_capacity(raw_value.len());
+    result.push(quote);
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                // The original quote needn't be escaped in the new quote.
+                Some(next) if next == origin_quote => result.push(next),
+                Some(next) => {
+                    result.push(c);
+                    result.push(next);
+                }
+                None => result.push(c),
+            }
+        } else if c == '$' && chars.peek() == Some(&'{') {
+            // Copy the interpolation span until the matching brace.
+            let mut depth = 0;
+            result.push(c);
+            for c in chars.by_ref() {
+                if c == quote {
+                    return None;
+                }
+                result.push(c);
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        } else if c == quote {
+            result.push('\\');
+            result.push(c);
+        } else {
+            result.push(c);
+        }
+    }
+    result.push(quote);
+    Some(result)
+}
+
 impl<'p> Printer<'p> {
+    /// Write a comma after the element of multi-line list and config expressions
+    /// when the trailing comma policy is `Always`.
+    #[inline]
+    fn write_trailing_comma(&mut self) {
+        if let TrailingComma::Always = self.cfg.trailing_comma {
+            self.write_token(TokenKind::Comma);
+        }
+    }
+
     pub fn write_args_and_kwargs(
         &mut self,
         args: &[ast::NodeRef<ast::Expr>],
@@ -857,8 +966,15 @@ impl<'p> Printer<'p> {
     }
 
     pub fn stmts(&mut self, stmts: &[ast::NodeRef<ast::Stmt>]) {
-        for stmt in stmts {
+        for (i, stmt) in stmts.iter().enumerate() {
             self.stmt(stmt);
+            // A schema statement always ends with one blank line, write the
+            // rest blank lines when it is followed by other statements.
+            if matches!(stmt.node, ast::Stmt::Schema(_)) && i + 1 < stmts.len() {
+                for _ in 1..self.cfg.blank_lines_between_schemas {
+                    self.write_newline_without_fill();
+                }
+            }
         }
     }
 }
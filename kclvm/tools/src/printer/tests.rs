@@ -1,3 +1,4 @@
+use super::node::requote_string;
 use super::*;
 use kclvm_parser::parse_file;
 use pretty_assertions::assert_eq;
@@ -45,3 +46,72 @@ fn test_ast_printer() {
         assert_eq!(data_input, data_output, "Test failed on {}", case);
     }
 }
+
+#[test]
+fn test_ast_printer_with_config() {
+    let src = r#"schema A:
+    a: str = "a"
+schema B:
+    b: str = 'b'
+config = {name = "a long name value", labels = {app = "app", env = "prod"}, items = [1, 2, 3]}
+"#;
+    let module = parse_file("", Some(src.to_string())).unwrap();
+    let cfg = Config {
+        max_line_width: Some(50),
+        quote_style: QuoteStyle::Single,
+        trailing_comma: TrailingComma::Always,
+        blank_lines_between_schemas: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        print_ast_module_with_config(&module, cfg),
+        r#"schema A:
+    a: str = 'a'
+
+
+schema B:
+    b: str = 'b'
+
+
+config = {
+    name = 'a long name value',
+    labels = {app = 'app', env = 'prod'},
+    items = [1, 2, 3],
+}
+"#
+    );
+}
+
+#[test]
+fn test_requote_string_with_config() {
+    let src = r#"a = 'it\'s "ok"'
+b = r"raw"
+c = """long"""
+"#;
+    let module = parse_file("", Some(src.to_string())).unwrap();
+    let cfg = Config {
+        quote_style: QuoteStyle::Double,
+        ..Default::default()
+    };
+    assert_eq!(
+        print_ast_module_with_config(&module, cfg),
+        r#"a = "it's \"ok\""
+b = r"raw"
+c = """long"""
+"#
+    );
+}
+
+#[test]
+fn test_requote_string_with_interpolation() {
+    assert_eq!(requote_string(r#""${a['b']}""#, '\''), None);
+    assert_eq!(
+        requote_string(r#""${a.b} it's ${c}""#, '\''),
+        Some(r#"'${a.b} it\'s ${c}'"#.to_string())
+    );
+    assert_eq!(requote_string(r#"'${a["b"]}'"#, '"'), None);
+    assert_eq!(
+        requote_string(r#"'${a.b} "c"'"#, '"'),
+        Some(r#""${a.b} \"c\"""#.to_string())
+    );
+}
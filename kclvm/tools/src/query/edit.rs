@@ -0,0 +1,624 @@
+//! Source-preserving overrides, which compute the override of a spec as minimal
+//! text edits against the original source using the AST node positions instead
+//! of re-printing the whole module, so comments and untouched code are kept.
+use kclvm_ast::ast;
+
+use crate::format::{apply_text_edits, TextEdit, TextPosition, TextRange};
+use crate::printer::{print_ast_node, ASTNode};
+
+use super::r#override::{build_override_value, is_selected_element};
+use super::util::{FieldPathPart, ListSelector};
+
+/// The result of computing the text edits of an override spec.
+#[derive(Debug)]
+pub(crate) enum SourceEdits {
+    /// The spec matches the source and is applied by the edits.
+    Edits(Vec<TextEdit>),
+    /// The spec matches nothing in the source.
+    NotFound,
+    /// The spec is invalid with the reason e.g., the value is not an expression.
+    Invalid(String),
+    /// The spec can't be applied on the source with the reason e.g., on
+    /// the dotted config key `spec.replicas` of the field path `spec`.
+    Unsupported(String),
+}
+
+/// Compute the text edits of the override spec on the source of the module, a new
+/// top-level assignment is inserted for the missing target only when `create_missing`
+/// is true.
+pub(crate) fn override_source_edits(
+    m: &ast::Module,
+    src: &str,
+    parts: &[FieldPathPart],
+    o: &ast::OverrideSpec,
+    create_missing: bool,
+) -> SourceEdits {
+    let value_text = match &o.action {
+        ast::OverrideAction::CreateOrUpdate => match build_override_value(&o.field_value) {
+            Ok(value) => print_ast_node(ASTNode::Expr(&value)).trim_end().to_string(),
+            Err(err) => return SourceEdits::Invalid(err.to_string()),
+        },
+        ast::OverrideAction::Delete => "".to_string(),
+    };
+    let mut editor = SourceEditor {
+        lines: src.split_inclusive('\n').collect(),
+        value_text,
+        action: o.action.clone(),
+        create_missing,
+        edits: vec![],
+        unsupported: None,
+    };
+    let target = &parts[0];
+    let found = editor.edit_module(m, target, &parts[1..]);
+    if let Some(reason) = editor.unsupported {
+        SourceEdits::Unsupported(reason)
+    } else if found {
+        SourceEdits::Edits(editor.edits)
+    } else {
+        SourceEdits::NotFound
+    }
+}
+
+/// Compute the text edits that insert the missing import paths at the top of the source.
+pub(crate) fn import_source_edits(m: &ast::Module, import_paths: &[String]) -> Vec<TextEdit> {
+    let exist_imports = m
+        .body
+        .iter()
+        .filter_map(|stmt| match &stmt.node {
+            ast::Stmt::Import(import_stmt) => Some(match &import_stmt.asname {
+                Some(asname) => format!("{} as {}", import_stmt.path, asname),
+                None => import_stmt.path.to_string(),
+            }),
+            _ => None,
+        })
+        .collect::<Vec<String>>();
+    let new_text = import_paths
+        .iter()
+        .filter(|path| !exist_imports.contains(path))
+        .map(|path| format!("import {}\n", path))
+        .collect::<String>();
+    if new_text.is_empty() {
+        return vec![];
+    }
+    let start = TextPosition { line: 1, column: 0 };
+    vec![TextEdit {
+        range: TextRange { start, end: start },
+        new_text,
+    }]
+}
+
+struct SourceEditor<'a> {
+    lines: Vec<&'a str>,
+    /// The printed override value.
+    value_text: String,
+    action: ast::OverrideAction,
+    /// Whether to insert a new top-level assignment when the target does not exist.
+    create_missing: bool,
+    edits: Vec<TextEdit>,
+    /// The reason why the spec can't be applied on the source.
+    unsupported: Option<String>,
+}
+
+impl<'a> SourceEditor<'a> {
+    /// Edit the top-level statements of the target with the rest field path parts,
+    /// and returns whether the path is found. When the path is not found in all
+    /// statements of the target, the new entry is only appended to the last one.
+    fn edit_module(
+        &mut self,
+        m: &ast::Module,
+        target: &FieldPathPart,
+        parts: &[FieldPathPart],
+    ) -> bool {
+        let mut found = false;
+        let mut has_target = false;
+        // The last config of the target which the missing field path can be appended to.
+        let mut append_config: Option<(&ast::ConfigExpr, &ast::NodeRef<ast::Expr>)> = None;
+        // The multi-target assignments of the target e.g., `a = b = {}`.
+        let mut multi_assigns = vec![];
+        for stmt in &m.body {
+            let value = match &stmt.node {
+                ast::Stmt::Assign(assign_stmt)
+                    if assign_stmt
+                        .targets
+                        .iter()
+                        .any(|t| is_name(&t.node.names, &target.name)) =>
+                {
+                    has_target = true;
+                    if assign_stmt.targets.len() > 1 {
+                        multi_assigns.push((stmt, assign_stmt));
+                    }
+                    &assign_stmt.value
+                }
+                ast::Stmt::Unification(unification_stmt)
+                    if is_name(&unification_stmt.target.node.names, &target.name) =>
+                {
+                    has_target = true;
+                    // The value of a unification statement must be a schema expression.
+                    if parts.is_empty() || target.selector.is_some() {
+                        self.unsupported = Some(format!(
+                            "the unification statement of '{}' can only be overridden by its attributes",
+                            target.name
+                        ));
+                        return false;
+                    }
+                    if let ast::Expr::Config(config_expr) = &unification_stmt.value.node.config.node
+                    {
+                        found |= self.edit_config(config_expr, parts);
+                        append_config = Some((config_expr, &unification_stmt.value.node.config));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            match (&target.selector, parts.is_empty()) {
+                // Top-level scalar e.g., `replicas=3`.
+                (None, true) => {
+                    found = true;
+                    match self.action {
+                        ast::OverrideAction::CreateOrUpdate => self.replace(value),
+                        ast::OverrideAction::Delete => self.delete_lines(stmt.line, value.end_line),
+                    }
+                }
+                (Some(selector), _) => {
+                    if let ast::Expr::List(list_expr) = &value.node {
+                        found |= self.edit_list(list_expr, selector, parts);
+                    }
+                }
+                (None, false) => match &value.node {
+                    ast::Expr::Schema(schema_expr) => {
+                        if let ast::Expr::Config(config_expr) = &schema_expr.config.node {
+                            found |= self.edit_config(config_expr, parts);
+                            append_config = Some((config_expr, &schema_expr.config));
+                        }
+                    }
+                    ast::Expr::Config(config_expr) => {
+                        found |= self.edit_config(config_expr, parts);
+                        append_config = Some((config_expr, value));
+                    }
+                    _ => {}
+                },
+            }
+            if self.unsupported.is_some() {
+                return false;
+            }
+        }
+        // Append a new entry when the path is not found and the action is CREATE_OR_UPDATE,
+        // the list elements selected by the path can't be created.
+        if let (false, Some((config_expr, config_node))) = (found, append_config) {
+            if matches!(self.action, ast::OverrideAction::CreateOrUpdate)
+                && parts.iter().all(|p| p.selector.is_none())
+            {
+                let entry = format!("{} = {}", join_names(parts), self.value_text);
+                self.append_entry(config_expr, config_node, &entry);
+                found = true;
+            }
+        }
+        for (stmt, assign_stmt) in multi_assigns {
+            self.split_multi_assign(stmt, assign_stmt, &target.name, parts.is_empty());
+        }
+        // Insert a new top-level assignment when the target does not exist.
+        if self.create_missing
+            && !has_target
+            && matches!(self.action, ast::OverrideAction::CreateOrUpdate)
+            && target.selector.is_none()
+            && parts.iter().all(|p| p.selector.is_none())
+        {
+            let value = if parts.is_empty() {
+                self.value_text.clone()
+            } else {
+                format!("{{{} = {}}}", join_names(parts), self.value_text)
+            };
+            self.insert_at_end(&format!("{} = {}\n", target.name, value));
+            return true;
+        }
+        found
+    }
+
+    /// Split the edited multi-target assignment e.g., `a = b = {}` into single target
+    /// assignments in the same way as `transform_multi_assign`, so the edits of the
+    /// value are only applied on the assignment of the target. The assignment of the
+    /// target is removed when the target itself is deleted.
+    fn split_multi_assign(
+        &mut self,
+        stmt: &ast::NodeRef<ast::Stmt>,
+        assign_stmt: &ast::AssignStmt,
+        name: &str,
+        is_top_level: bool,
+    ) {
+        let value = &assign_stmt.value;
+        let (start, end) = (start_of(stmt), end_of(value));
+        let delete_target = is_top_level && matches!(self.action, ast::OverrideAction::Delete);
+        // The edits of the value, the whole statement is deleted when the target is deleted.
+        let (value_edits, edits): (Vec<TextEdit>, Vec<TextEdit>) = std::mem::take(&mut self.edits)
+            .into_iter()
+            .partition(|edit| {
+                edit.range.start >= start
+                    && (edit.range.end <= end || delete_target && edit.range.start == start)
+            });
+        self.edits = edits;
+        if value_edits.is_empty() {
+            return;
+        }
+        let value_start = start_of(value);
+        let original = self.text_between(value_start, end);
+        let edited = if delete_target {
+            None
+        } else {
+            // Move the edits of the value to the positions in the value text.
+            let value_edits = value_edits
+                .into_iter()
+                .map(|edit| TextEdit {
+                    range: TextRange {
+                        start: relative_position(value_start, edit.range.start),
+                        end: relative_position(value_start, edit.range.end),
+                    },
+                    new_text: edit.new_text,
+                })
+                .collect::<Vec<TextEdit>>();
+            Some(apply_text_edits(&original, &value_edits))
+        };
+        let type_annotation = match &assign_stmt.type_annotation {
+            Some(ty) => format!(": {}", ty.node),
+            None => "".to_string(),
+        };
+        let new_text = assign_stmt
+            .targets
+            .iter()
+            .filter_map(|t| {
+                let value = if is_name(&t.node.names, name) {
+                    edited.as_ref()?
+                } else {
+                    &original
+                };
+                Some(format!(
+                    "{}{} = {}",
+                    t.node.names.join("."),
+                    type_annotation,
+                    value
+                ))
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        self.push(start, end, new_text);
+    }
+
+    /// Edit the config entries matched with the field path parts, and returns
+    /// whether the whole path is matched. A dotted key e.g., `spec.replicas`
+    /// matches multiple parts.
+    fn edit_config(&mut self, config_expr: &ast::ConfigExpr, parts: &[FieldPathPart]) -> bool {
+        let mut found = false;
+        let mut delete_indices = vec![];
+        for (i, item) in config_expr.items.iter().enumerate() {
+            let names = key_names(&item.node.key);
+            if names.is_empty() || !is_prefix_matched(&names, parts) {
+                continue;
+            }
+            // The dotted key e.g., `spec.replicas` is longer than the path `spec`.
+            if names.len() > parts.len() {
+                self.unsupported = Some(format!(
+                    "the config key '{}' is longer than the field path",
+                    names.join(".")
+                ));
+                return false;
+            }
+            let last = &parts[names.len() - 1];
+            let rest = &parts[names.len()..];
+            match &last.selector {
+                Some(selector) => {
+                    if let ast::Expr::List(list_expr) = &item.node.value.node {
+                        found |= self.edit_list(list_expr, selector, rest);
+                    }
+                }
+                None if rest.is_empty() => {
+                    found = true;
+                    match self.action {
+                        ast::OverrideAction::CreateOrUpdate => self.replace(&item.node.value),
+                        ast::OverrideAction::Delete => delete_indices.push(i),
+                    }
+                }
+                None => match &item.node.value.node {
+                    ast::Expr::Schema(schema_expr) => {
+                        if let ast::Expr::Config(config_expr) = &schema_expr.config.node {
+                            found |= self.edit_config(config_expr, rest);
+                        }
+                    }
+                    ast::Expr::Config(config_expr) => {
+                        found |= self.edit_config(config_expr, rest);
+                    }
+                    _ => {}
+                },
+            }
+        }
+        self.delete_elements(&config_expr.items, &delete_indices);
+        found
+    }
+
+    /// Edit the list elements selected by the selector with the rest field path parts.
+    fn edit_list(
+        &mut self,
+        list_expr: &ast::ListExpr,
+        selector: &ListSelector,
+        parts: &[FieldPathPart],
+    ) -> bool {
+        let indices = list_expr
+            .elts
+            .iter()
+            .enumerate()
+            .filter(|(i, elt)| is_selected_element(*i, elt, selector))
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        if indices.is_empty() {
+            return false;
+        }
+        if parts.is_empty() {
+            match self.action {
+                ast::OverrideAction::CreateOrUpdate => {
+                    for i in indices {
+                        self.replace(&list_expr.elts[i]);
+                    }
+                }
+                ast::OverrideAction::Delete => self.delete_elements(&list_expr.elts, &indices),
+            }
+            return true;
+        }
+        let mut found = false;
+        for i in indices {
+            let config_expr = match &list_expr.elts[i].node {
+                ast::Expr::Schema(schema_expr) => match &schema_expr.config.node {
+                    ast::Expr::Config(config_expr) => config_expr,
+                    _ => continue,
+                },
+                ast::Expr::Config(config_expr) => config_expr,
+                _ => continue,
+            };
+            found |= self.edit_config(config_expr, parts);
+        }
+        found
+    }
+
+    /// Replace the source of the node with the override value.
+    fn replace<T>(&mut self, node: &ast::NodeRef<T>) {
+        // Indent the continuation lines of a multi-line value with the node line indent.
+        let indent = self.indent_of(node.line);
+        let new_text = self
+            .value_text
+            .split('\n')
+            .collect::<Vec<&str>>()
+            .join(&format!("\n{}", indent));
+        self.push(start_of(node), end_of(node), new_text);
+    }
+
+    /// Delete the elements e.g., config entries or list elements at the indices.
+    /// Consecutive elements are deleted together with the separators between them.
+    fn delete_elements<T>(&mut self, elts: &[ast::NodeRef<T>], indices: &[usize]) {
+        let mut runs: Vec<(usize, usize)> = vec![];
+        for &i in indices {
+            match runs.last_mut() {
+                Some((_, end)) if *end + 1 == i => *end = i,
+                _ => runs.push((i, i)),
+            }
+        }
+        for (first, last) in runs {
+            if (first..=last).all(|i| self.occupies_lines(&elts[i])) {
+                self.delete_lines(elts[first].line, elts[last].end_line);
+            } else if let Some(next) = elts.get(last + 1) {
+                // `a = 1, b = 2` -> `b = 2`
+                self.push(start_of(&elts[first]), start_of(next), "".to_string());
+            } else if first > 0 {
+                // `a = 1, b = 2` -> `a = 1`
+                self.push(
+                    end_of(&elts[first - 1]),
+                    end_of(&elts[last]),
+                    "".to_string(),
+                );
+            } else {
+                self.push(start_of(&elts[first]), end_of(&elts[last]), "".to_string());
+            }
+        }
+    }
+
+    /// Append a config entry at the end of the config expression.
+    fn append_entry(
+        &mut self,
+        config_expr: &ast::ConfigExpr,
+        config_node: &ast::NodeRef<ast::Expr>,
+        entry: &str,
+    ) {
+        match config_expr.items.last() {
+            // `{}` -> `{entry}`
+            None => {
+                let pos = TextPosition {
+                    line: config_node.end_line,
+                    column: config_node.end_column.saturating_sub(1),
+                };
+                self.push(pos, pos, entry.to_string());
+            }
+            // Multi-line config, insert a new line after the last entry.
+            Some(last) if config_node.end_line > last.end_line => {
+                let line = self.line_content(last.end_line);
+                let after = line
+                    .chars()
+                    .skip(last.end_column as usize)
+                    .collect::<String>();
+                let comma = if after.trim_start().starts_with(',') {
+                    ","
+                } else {
+                    ""
+                };
+                let pos = TextPosition {
+                    line: last.end_line,
+                    column: line.chars().count() as u64,
+                };
+                let indent = self.indent_of(last.line);
+                self.push(pos, pos, format!("\n{}{}{}", indent, entry, comma));
+            }
+            // Single line config, `{a = 1}` -> `{a = 1, entry}`
+            Some(last) => {
+                let pos = end_of(last);
+                self.push(pos, pos, format!(", {}", entry));
+            }
+        }
+    }
+
+    /// Delete the whole lines from the start line to the end line.
+    fn delete_lines(&mut self, start_line: u64, end_line: u64) {
+        self.push(
+            TextPosition {
+                line: start_line,
+                column: 0,
+            },
+            TextPosition {
+                line: end_line + 1,
+                column: 0,
+            },
+            "".to_string(),
+        );
+    }
+
+    /// Insert the text at the end of the source.
+    fn insert_at_end(&mut self, text: &str) {
+        let (pos, text) = match self.lines.last() {
+            Some(last) if !last.ends_with('\n') => (
+                TextPosition {
+                    line: self.lines.len() as u64,
+                    column: last.chars().count() as u64,
+                },
+                format!("\n{}", text),
+            ),
+            _ => (
+                TextPosition {
+                    line: self.lines.len() as u64 + 1,
+                    column: 0,
+                },
+                text.to_string(),
+            ),
+        };
+        self.push(pos, pos, text);
+    }
+
+    /// Whether the node occupies whole lines, which means there are only whitespaces
+    /// before it and only whitespaces, commas or comments after it.
+    fn occupies_lines<T>(&self, node: &ast::NodeRef<T>) -> bool {
+        let before = self
+            .line_content(node.line)
+            .chars()
+            .take(node.column as usize)
+            .collect::<String>();
+        let after = self
+            .line_content(node.end_line)
+            .chars()
+            .skip(node.end_column as usize)
+            .collect::<String>();
+        let after = after.trim_start();
+        let after = after.strip_prefix(',').unwrap_or(after).trim_start();
+        before.trim().is_empty() && (after.is_empty() || after.starts_with('#'))
+    }
+
+    /// Returns the line content without the newline.
+    fn line_content(&self, line: u64) -> &'a str {
+        match self.lines.get((line as usize).saturating_sub(1)) {
+            Some(content) => content.strip_suffix('\n').unwrap_or(content),
+            None => "",
+        }
+    }
+
+    /// Returns the leading whitespaces of the line.
+    fn indent_of(&self, line: u64) -> String {
+        self.line_content(line)
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .collect()
+    }
+
+    /// Returns the source text in the range `[start, end)`.
+    fn text_between(&self, start: TextPosition, end: TextPosition) -> String {
+        let mut text = String::new();
+        for line in start.line..=end.line {
+            let content = self.lines[(line as usize).saturating_sub(1)];
+            let first = if line == start.line { start.column } else { 0 } as usize;
+            if line == end.line {
+                let last = end.column as usize;
+                text.extend(content.chars().take(last).skip(first));
+            } else {
+                text.extend(content.chars().skip(first));
+            }
+        }
+        text
+    }
+
+    fn push(&mut self, start: TextPosition, end: TextPosition, new_text: String) {
+        self.edits.push(TextEdit {
+            range: TextRange { start, end },
+            new_text,
+        });
+    }
+}
+
+/// Returns the key names of a config entry key, a dotted identifier key
+/// e.g., `spec.replicas` has multiple names.
+fn key_names(key: &Option<ast::NodeRef<ast::Expr>>) -> Vec<String> {
+    match key {
+        Some(key) => match &key.node {
+            ast::Expr::Identifier(identifier) => identifier.names.clone(),
+            ast::Expr::StringLit(string_lit) => vec![string_lit.value.clone()],
+            _ => vec![],
+        },
+        None => vec![],
+    }
+}
+
+/// Whether the key names match the start of the field path parts, only the
+/// last matched part can have a list selector.
+fn is_prefix_matched(names: &[String], parts: &[FieldPathPart]) -> bool {
+    names
+        .iter()
+        .enumerate()
+        .all(|(i, name)| match parts.get(i) {
+            Some(part) => part.name == *name && (part.selector.is_none() || i + 1 == names.len()),
+            None => true,
+        })
+}
+
+/// Whether the identifier names denote the single name.
+#[inline]
+fn is_name(names: &[String], name: &str) -> bool {
+    names.len() == 1 && names[0] == name
+}
+
+/// Join the names of the field path parts with dots.
+fn join_names(parts: &[FieldPathPart]) -> String {
+    parts
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect::<Vec<&str>>()
+        .join(".")
+}
+
+/// Returns the position relative to the start position.
+#[inline]
+fn relative_position(start: TextPosition, pos: TextPosition) -> TextPosition {
+    TextPosition {
+        line: pos.line - start.line + 1,
+        column: if pos.line == start.line {
+            pos.column - start.column
+        } else {
+            pos.column
+        },
+    }
+}
+
+#[inline]
+fn start_of<T>(node: &ast::NodeRef<T>) -> TextPosition {
+    TextPosition {
+        line: node.line,
+        column: node.column,
+    }
+}
+
+#[inline]
+fn end_of<T>(node: &ast::NodeRef<T>) -> TextPosition {
+    TextPosition {
+        line: node.end_line,
+        column: node.end_column,
+    }
+}
@@ -0,0 +1,197 @@
+use anyhow::{anyhow, Result};
+
+use kclvm_ast::path::{get_attr_paths_from_config_expr, get_key_path};
+use kclvm_ast::{ast, MAIN_PKG};
+use kclvm_sema::pre_process::fix_config_expr_nest_attr;
+
+use crate::printer::{print_ast_node, ASTNode};
+
+use super::util::{parse_field_path, split_field_path, ListSelector};
+
+/// The query result of a config path, which contains the source code
+/// and the source range of the expression at the path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryResult {
+    pub filename: String,
+    /// The expression source code printed by the AST printer.
+    pub expr: String,
+    /// The (1-based) line and (0-based) column range of the expression.
+    pub line: u64,
+    pub column: u64,
+    pub end_line: u64,
+    pub end_column: u64,
+}
+
+/// Get the expressions at the config path of the program. There may be more than
+/// one result because a config path can be defined many times in different files
+/// and config entries.
+///
+/// # Parameters
+///
+/// `prog`: &[ast::Program]
+///     The program to query.
+///
+/// `path`: [&str]
+///     The config path satisfies the form: <pkgpath>:<field_path>, when the pkgpath
+///     is '__main__', `<pkgpath>:` can be omitted.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_parser::load_program;
+/// use kclvm_tools::query::get;
+///
+/// let prog = load_program(&["main.k"], None).unwrap();
+/// for result in get(&prog, "app.spec.replicas").unwrap() {
+///     println!("{}:{}: {}", result.filename, result.line, result.expr);
+/// }
+/// ```
+pub fn get(prog: &ast::Program, path: &str) -> Result<Vec<QueryResult>> {
+    let (pkgpath, field_path) = split_field_path(path)?;
+    let parts = parse_field_path(&field_path)?;
+    if parts.iter().any(|p| p.selector.is_some()) {
+        return Err(anyhow!(
+            "Invalid query path {:?}, list selectors are not supported",
+            path
+        ));
+    }
+    let pkgpath = if pkgpath.is_empty() || pkgpath == MAIN_PKG {
+        prog.main.clone()
+    } else {
+        pkgpath
+    };
+    let modules = prog
+        .pkgs
+        .get(&pkgpath)
+        .ok_or_else(|| anyhow!("Package {} not found", pkgpath))?;
+    let name = &parts[0].name;
+    let field = parts[1..]
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect::<Vec<&str>>();
+    let mut results = vec![];
+    for module in modules {
+        // Transform config expr to simplify the config path query.
+        let mut module = module.clone();
+        fix_config_expr_nest_attr(&mut module);
+        for stmt in &module.body {
+            let value = match &stmt.node {
+                ast::Stmt::Assign(assign_stmt)
+                    if assign_stmt
+                        .targets
+                        .iter()
+                        .any(|t| t.node.names == [name.as_str()]) =>
+                {
+                    assign_stmt.value.clone()
+                }
+                ast::Stmt::Unification(unification_stmt)
+                    if unification_stmt.target.node.names == [name.as_str()] =>
+                {
+                    Box::new(ast::Node::node_with_pos(
+                        ast::Expr::Schema(unification_stmt.value.node.clone()),
+                        unification_stmt.value.pos(),
+                    ))
+                }
+                _ => continue,
+            };
+            lookup_expr(&module.filename, &value, &field, &mut results);
+        }
+    }
+    Ok(results)
+}
+
+/// Get the evaluated value at the config path from the JSON result of executing
+/// the program, the value is returned in the JSON format. The list elements can be
+/// selected by the index or the key e.g., `app.containers[0].image` and
+/// `app.containers[name=web]`, the first matched element is used. Note that only
+/// values in the main package are in the result.
+///
+/// # Examples
+///
+/// ```
+/// use kclvm_tools::query::get_value;
+///
+/// let json_result = r#"{"app": {"replicas": 3, "containers": [{"name": "web", "image": "nginx"}]}}"#;
+/// let value = get_value(json_result, "app.replicas").unwrap();
+/// assert_eq!(value, Some("3".to_string()));
+/// let value = get_value(json_result, "app.containers[name=web].image").unwrap();
+/// assert_eq!(value, Some("\"nginx\"".to_string()));
+/// ```
+pub fn get_value(json_result: &str, path: &str) -> Result<Option<String>> {
+    let (pkgpath, field_path) = split_field_path(path)?;
+    let parts = parse_field_path(&field_path)?;
+    if !pkgpath.is_empty() && pkgpath != MAIN_PKG {
+        return Ok(None);
+    }
+    let mut value: &serde_json::Value = &serde_json::from_str(json_result)?;
+    for part in &parts {
+        value = match value.get(&part.name) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        if let Some(selector) = &part.selector {
+            value = match value
+                .as_array()
+                .and_then(|elts| select_json_element(elts, selector))
+            {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+        }
+    }
+    Ok(Some(value.to_string()))
+}
+
+/// Returns the first element of the JSON list selected by the selector, a key
+/// selector e.g., `[name=web]` selects the element whose `name` value is the
+/// string `"web"` or is printed as `web`.
+fn select_json_element<'a>(
+    elts: &'a [serde_json::Value],
+    selector: &ListSelector,
+) -> Option<&'a serde_json::Value> {
+    match selector {
+        ListSelector::Index(index) => elts.get(*index),
+        ListSelector::Key(key, value) => elts.iter().find(|elt| match elt.get(key) {
+            Some(serde_json::Value::String(s)) => s == value,
+            Some(v) => v.to_string() == *value,
+            None => false,
+        }),
+    }
+}
+
+/// Lookup the expressions at the field path in the expression.
+fn lookup_expr(
+    filename: &str,
+    expr: &ast::NodeRef<ast::Expr>,
+    field: &[&str],
+    results: &mut Vec<QueryResult>,
+) {
+    if field.is_empty() {
+        results.push(QueryResult {
+            filename: filename.to_string(),
+            expr: print_ast_node(ASTNode::Expr(expr)),
+            line: expr.line,
+            column: expr.column,
+            end_line: expr.end_line,
+            end_column: expr.end_column,
+        });
+        return;
+    }
+    let config_expr = match &expr.node {
+        ast::Expr::Schema(schema_expr) => match &schema_expr.config.node {
+            ast::Expr::Config(config_expr) => config_expr,
+            _ => return,
+        },
+        ast::Expr::Config(config_expr) => config_expr,
+        _ => return,
+    };
+    // Query whether there is a matching path from the path lookup table.
+    if !get_attr_paths_from_config_expr(config_expr).contains(&field.join(".")) {
+        return;
+    }
+    for item in &config_expr.items {
+        if get_key_path(&item.node.key) == field[0] {
+            lookup_expr(filename, &item.node.value, &field[1..], results);
+        }
+    }
+}
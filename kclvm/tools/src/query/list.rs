@@ -0,0 +1,278 @@
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+
+use kclvm_ast::path::{get_attr_paths_from_config_expr, get_key_path};
+use kclvm_ast::{ast, MAIN_PKG};
+use kclvm_sema::pre_process::fix_config_expr_nest_attr;
+
+/// A top-level variable and the config paths it defines.
+#[derive(Debug, Clone)]
+pub struct Variable {
+    pub name: String,
+    /// The file and (1-based) line of the first definition.
+    pub filename: String,
+    pub line: u64,
+    /// The schema name of the first schema expression or unification value e.g.,
+    /// `App` of `app = App {}`, it is `None` for plain config values.
+    pub schema: Option<String>,
+    /// All config paths including the variable name in the definition order e.g.,
+    /// `["app.spec", "app.spec.replicas"]`.
+    pub paths: Vec<String>,
+    /// The tree of config attributes of the variable.
+    pub attrs: Vec<ConfigAttr>,
+}
+
+/// A config attribute of a config path tree.
+#[derive(Debug, Clone)]
+pub struct ConfigAttr {
+    /// The attribute key name e.g., `replicas`.
+    pub name: String,
+    /// The full config path including the variable name e.g., `app.spec.replicas`.
+    pub path: String,
+    pub filename: String,
+    pub line: u64,
+    /// The config entry operation, override `=`, union `:` or insert `+=`.
+    pub operation: ast::ConfigEntryOperation,
+    pub children: Vec<ConfigAttr>,
+}
+
+/// A schema and its attributes.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    pub name: String,
+    /// The parent schema name e.g., `Base` of `schema App(Base)`.
+    pub parent: Option<String>,
+    pub filename: String,
+    pub line: u64,
+    /// The attributes defined in the schema body in the definition order,
+    /// the attributes of the parent schema are not included.
+    pub attrs: Vec<SchemaAttribute>,
+}
+
+/// A schema attribute e.g., `replicas?: int = 1`.
+#[derive(Debug, Clone)]
+pub struct SchemaAttribute {
+    pub name: String,
+    /// The type annotation string e.g., `int` and `{str:str}`.
+    pub type_str: String,
+    pub is_optional: bool,
+    pub has_default: bool,
+    pub doc: String,
+    pub filename: String,
+    pub line: u64,
+}
+
+/// List top-level variables and the config paths they define of the package,
+/// variables defined more than once are merged into one in the definition order.
+///
+/// # Parameters
+///
+/// `prog`: &[ast::Program]
+///     The program to list.
+///
+/// `pkgpath`: [&str]
+///     The package path, the main package is used when it is empty or '__main__'.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_parser::load_program;
+/// use kclvm_tools::query::list;
+///
+/// let prog = load_program(&["main.k"], None).unwrap();
+/// for var in list(&prog, "").unwrap() {
+///     println!("{}: {:?}", var.name, var.paths);
+/// }
+/// ```
+pub fn list(prog: &ast::Program, pkgpath: &str) -> Result<Vec<Variable>> {
+    let pkgpath = if pkgpath.is_empty() || pkgpath == MAIN_PKG {
+        prog.main.as_str()
+    } else {
+        pkgpath
+    };
+    let modules = prog
+        .pkgs
+        .get(pkgpath)
+        .ok_or_else(|| anyhow!("Package {} not found", pkgpath))?;
+    let mut variables: IndexMap<String, Variable> = IndexMap::new();
+    for module in modules {
+        // Transform config expr to simplify the config path query.
+        let mut module = module.clone();
+        fix_config_expr_nest_attr(&mut module);
+        for stmt in &module.body {
+            let (names, config_expr) = match &stmt.node {
+                ast::Stmt::Assign(assign_stmt) => (
+                    assign_stmt
+                        .targets
+                        .iter()
+                        .filter(|t| t.node.names.len() == 1)
+                        .map(|t| t.node.names[0].clone())
+                        .collect::<Vec<String>>(),
+                    get_config_expr(&assign_stmt.value.node),
+                ),
+                ast::Stmt::Unification(unification_stmt) => (
+                    unification_stmt.target.node.names.clone(),
+                    match &unification_stmt.value.node.config.node {
+                        ast::Expr::Config(config_expr) => Some(config_expr),
+                        _ => None,
+                    },
+                ),
+                _ => continue,
+            };
+            let schema = match &stmt.node {
+                ast::Stmt::Assign(assign_stmt) => match &assign_stmt.value.node {
+                    ast::Expr::Schema(schema_expr) => Some(schema_expr.name.node.get_name()),
+                    _ => None,
+                },
+                ast::Stmt::Unification(unification_stmt) => {
+                    Some(unification_stmt.value.node.name.node.get_name())
+                }
+                _ => None,
+            };
+            for name in names {
+                let var = variables.entry(name.clone()).or_insert_with(|| Variable {
+                    name: name.clone(),
+                    filename: module.filename.clone(),
+                    line: stmt.line,
+                    schema: None,
+                    paths: vec![],
+                    attrs: vec![],
+                });
+                if var.schema.is_none() {
+                    var.schema = schema.clone();
+                }
+                if let Some(config_expr) = config_expr {
+                    for path in get_attr_paths_from_config_expr(config_expr) {
+                        let path = format!("{}.{}", name, path);
+                        if !var.paths.contains(&path) {
+                            var.paths.push(path);
+                        }
+                    }
+                    var.attrs
+                        .extend(get_config_attrs(&module.filename, &name, config_expr));
+                }
+            }
+        }
+    }
+    Ok(variables.into_iter().map(|(_, var)| var).collect())
+}
+
+/// List the schemas and their attributes defined in the package.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kclvm_parser::load_program;
+/// use kclvm_tools::query::list_schemas;
+///
+/// let prog = load_program(&["main.k"], None).unwrap();
+/// for schema in list_schemas(&prog, "").unwrap() {
+///     for attr in &schema.attrs {
+///         println!("{}.{}: {}", schema.name, attr.name, attr.type_str);
+///     }
+/// }
+/// ```
+pub fn list_schemas(prog: &ast::Program, pkgpath: &str) -> Result<Vec<Schema>> {
+    let pkgpath = if pkgpath.is_empty() || pkgpath == MAIN_PKG {
+        prog.main.as_str()
+    } else {
+        pkgpath
+    };
+    let modules = prog
+        .pkgs
+        .get(pkgpath)
+        .ok_or_else(|| anyhow!("Package {} not found", pkgpath))?;
+    let mut schemas = vec![];
+    for module in modules {
+        for stmt in &module.body {
+            if let ast::Stmt::Schema(schema_stmt) = &stmt.node {
+                let mut attrs = vec![];
+                get_schema_attrs(&module.filename, &schema_stmt.body, &mut attrs);
+                schemas.push(Schema {
+                    name: schema_stmt.name.node.clone(),
+                    parent: schema_stmt
+                        .parent_name
+                        .as_ref()
+                        .map(|name| name.node.get_name()),
+                    filename: module.filename.clone(),
+                    line: stmt.line,
+                    attrs,
+                });
+            }
+        }
+    }
+    Ok(schemas)
+}
+
+/// Get the schema attributes of the schema body, including the attributes
+/// defined in the if statements.
+fn get_schema_attrs(
+    filename: &str,
+    body: &[ast::NodeRef<ast::Stmt>],
+    attrs: &mut Vec<SchemaAttribute>,
+) {
+    for stmt in body {
+        match &stmt.node {
+            ast::Stmt::SchemaAttr(schema_attr) => {
+                if attrs.iter().any(|attr| attr.name == schema_attr.name.node) {
+                    continue;
+                }
+                attrs.push(SchemaAttribute {
+                    name: schema_attr.name.node.clone(),
+                    type_str: schema_attr.type_str.node.clone(),
+                    is_optional: schema_attr.is_optional,
+                    has_default: schema_attr.value.is_some(),
+                    doc: schema_attr.doc.clone(),
+                    filename: filename.to_string(),
+                    line: stmt.line,
+                });
+            }
+            ast::Stmt::If(if_stmt) => {
+                get_schema_attrs(filename, &if_stmt.body, attrs);
+                get_schema_attrs(filename, &if_stmt.orelse, attrs);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Get the config attribute tree of the config expression recursively.
+fn get_config_attrs(
+    filename: &str,
+    prefix: &str,
+    config_expr: &ast::ConfigExpr,
+) -> Vec<ConfigAttr> {
+    let mut attrs = vec![];
+    for item in &config_expr.items {
+        let name = get_key_path(&item.node.key);
+        if name.trim().is_empty() {
+            continue;
+        }
+        let path = format!("{}.{}", prefix, name);
+        let children = match get_config_expr(&item.node.value.node) {
+            Some(config_expr) => get_config_attrs(filename, &path, config_expr),
+            None => vec![],
+        };
+        attrs.push(ConfigAttr {
+            name,
+            path,
+            filename: filename.to_string(),
+            line: item.line,
+            operation: item.node.operation.clone(),
+            children,
+        });
+    }
+    attrs
+}
+
+/// Get the config expression of a schema or config expression.
+fn get_config_expr(expr: &ast::Expr) -> Option<&ast::ConfigExpr> {
+    match expr {
+        ast::Expr::Schema(schema_expr) => match &schema_expr.config.node {
+            ast::Expr::Config(config_expr) => Some(config_expr),
+            _ => None,
+        },
+        ast::Expr::Config(config_expr) => Some(config_expr),
+        _ => None,
+    }
+}
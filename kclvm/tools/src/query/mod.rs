@@ -1,8 +1,14 @@
 //! This package is mainly the implementation of the KCL query tool, mainly including
-//! KCL code modification `override` and other implementations. We can call the `override_file`
-//! function to modify the file. The main principle is to parse the AST according to the
-//! input file name, and according to the ast::OverrideSpec transforms the nodes in the
-//! AST, recursively modifying or deleting the values of the nodes in the AST.
+//! KCL code modification `override`, config path query `get` and `list`, and other implementations.
+//! We can call the `override_file` function to modify the file. The main principle is
+//! to parse the AST according to the input file name, and according to the
+//! ast::OverrideSpec transforms the nodes in the AST, recursively modifying or deleting
+//! the values of the nodes in the AST. Overrides of `override_file` are applied as
+//! minimal text edits computed from the AST node positions, so comments and code
+//! users never touched are kept.
+mod edit;
+mod get;
+mod list;
 pub mod r#override;
 
 #[cfg(test)]
@@ -12,15 +18,97 @@ mod util;
 use anyhow::{anyhow, Result};
 use kclvm_ast::ast;
 use kclvm_parser::parse_file;
+use similar::{DiffTag, TextDiff};
 
+pub use get::{get, get_value, QueryResult};
+pub use list::{list, list_schemas, ConfigAttr, Schema, SchemaAttribute, Variable};
 pub use r#override::{apply_override_on_module, apply_overrides};
 
+use self::edit::{import_source_edits, override_source_edits, SourceEdits};
 use self::r#override::parse_override_spec;
-use crate::printer::print_ast_module;
+use self::util::parse_field_path;
+use crate::format::apply_text_edits;
+
+/// The override status of a spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverrideStatus {
+    /// The spec has been applied on the file.
+    Applied,
+    /// The spec is valid but matches nothing in the file.
+    NotFound,
+    /// The spec is invalid with the reason.
+    Invalid(String),
+    /// The spec is valid but can't be applied on the source of the file with the
+    /// reason e.g., on the dotted config key `spec.replicas` of the path `spec`.
+    Unsupported(String),
+}
+
+/// The override result of a spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecResult {
+    /// The override spec string.
+    pub spec: String,
+    pub status: OverrideStatus,
+    /// The (1-based) first and last lines changed by the spec in the overridden
+    /// file, deleted lines are denoted by the line where they were. It is `None`
+    /// when nothing is changed.
+    pub changed_lines: Option<(u64, u64)>,
+}
+
+/// The override result of a file, which contains the result of each spec
+/// in the input order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OverrideFileResult {
+    pub specs: Vec<SpecResult>,
+}
+
+impl OverrideFileResult {
+    /// Whether any spec has been applied and the file has been rewritten.
+    #[inline]
+    pub fn changed(&self) -> bool {
+        self.specs
+            .iter()
+            .any(|s| matches!(s.status, OverrideStatus::Applied))
+    }
+
+    /// Whether all specs have been applied.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.specs
+            .iter()
+            .all(|s| matches!(s.status, OverrideStatus::Applied))
+    }
+
+    /// Returns the error messages of all specs which are not applied.
+    pub fn errors(&self) -> Vec<String> {
+        self.specs
+            .iter()
+            .filter_map(|s| match &s.status {
+                OverrideStatus::Applied => None,
+                OverrideStatus::NotFound => {
+                    Some(format!("Override spec '{}' matches nothing", s.spec))
+                }
+                OverrideStatus::Invalid(reason) => {
+                    Some(format!("Invalid override spec '{}': {}", s.spec, reason))
+                }
+                OverrideStatus::Unsupported(reason) => Some(format!(
+                    "Override spec '{}' is not supported: {}",
+                    s.spec, reason
+                )),
+            })
+            .collect()
+    }
+}
 
 /// Override and rewrite a file with override specifications. Please note that this is an external user API,
 /// and it can directly modify the KCL file in place.
 ///
+/// Invalid specs do not abort the override, they are reported in the result with the reason
+/// along with the specs that match nothing or can't be applied on the source e.g., on the dotted
+/// config key `spec.replicas` of the path `spec`, and the rest specs are still applied. The multiple
+/// target assignments e.g., `a = b = {}` are split into single target assignments when the value
+/// of a target is overridden.
+///
 /// # Parameters
 ///
 /// `file`: [&str]
@@ -30,14 +118,21 @@ use crate::printer::print_ast_module;
 ///     List of specs that need to be overridden.
 ///     Each spec string satisfies the form: <pkgpath>:<field_path>=<filed_value> or <pkgpath>:<field_path>-
 ///     When the pkgpath is '__main__', `<pkgpath>:` can be omitted.
+///     The field path can select list elements by the index or the key e.g., `app.containers[0].image`
+///     and `app.containers[name=web].image`, and a field path without dots e.g., `replicas` denotes
+///     the top-level variable.
 ///
 /// `import_paths`: &\[[String]\]
 ///     List of import paths that are need to be added.
 ///
+/// `create_missing`: [bool]
+///     Whether to insert a new top-level assignment for the spec whose target variable does not
+///     exist, otherwise the spec matches nothing.
+///
 /// # Returns
 ///
-/// result: [Result<bool>]
-///     Whether the file has been modified.
+/// result: [Result<OverrideFileResult>]
+///     The override result of each spec.
 ///
 /// # Examples
 ///
@@ -47,8 +142,10 @@ use crate::printer::print_ast_module;
 /// let result = override_file(
 ///     "test.k",
 ///     &["alice.age=18".to_string()],
-///     &[]
+///     &[],
+///     false,
 /// ).unwrap();
+/// assert!(result.changed());
 /// ```
 ///
 /// - test.k (before override)
@@ -72,29 +169,81 @@ use crate::printer::print_ast_module;
 ///     age = 18
 /// }
 /// ```
-pub fn override_file(file: &str, specs: &[String], import_paths: &[String]) -> Result<bool> {
-    // Parse override spec strings.
-    let overrides = specs
-        .iter()
-        .map(|s| parse_override_spec(s))
-        .filter_map(Result::ok)
-        .collect::<Vec<ast::OverrideSpec>>();
-    // Parse file to AST module.
-    let mut module = match parse_file(file, None) {
-        Ok(module) => module,
-        Err(msg) => return Err(anyhow!("{}", msg)),
-    };
-    let mut result = false;
-    // Override AST module.
-    for o in &overrides {
-        if apply_override_on_module(&mut module, o, import_paths)? {
-            result = true;
-        }
+pub fn override_file(
+    file: &str,
+    specs: &[String],
+    import_paths: &[String],
+    create_missing: bool,
+) -> Result<OverrideFileResult> {
+    let mut code_str = std::fs::read_to_string(file)?;
+    // Insert the missing import paths at the top of the file.
+    let module = parse_source(file, &code_str)?;
+    code_str = apply_text_edits(&code_str, &import_source_edits(&module, import_paths));
+    let mut result = OverrideFileResult::default();
+    for spec in specs {
+        // Parse override spec strings.
+        let (o, parts) = match parse_override_spec(spec)
+            .and_then(|o| parse_field_path(&o.field_path).map(|parts| (o, parts)))
+        {
+            Ok(spec) => spec,
+            Err(err) => {
+                result.specs.push(SpecResult {
+                    spec: spec.to_string(),
+                    status: OverrideStatus::Invalid(err.to_string()),
+                    changed_lines: None,
+                });
+                continue;
+            }
+        };
+        // Override the source with minimal text edits, so comments and untouched
+        // code are kept. The module is parsed again because the positions of the
+        // source are changed by the previous spec.
+        let module = parse_source(file, &code_str)?;
+        let (status, changed_lines) =
+            match override_source_edits(&module, &code_str, &parts, &o, create_missing) {
+                SourceEdits::Edits(edits) => {
+                    let new_code_str = apply_text_edits(&code_str, &edits);
+                    let lines = changed_lines(&code_str, &new_code_str);
+                    code_str = new_code_str;
+                    (OverrideStatus::Applied, lines)
+                }
+                SourceEdits::NotFound => (OverrideStatus::NotFound, None),
+                SourceEdits::Invalid(reason) => (OverrideStatus::Invalid(reason), None),
+                // The file is never re-printed, so the spec is reported instead.
+                SourceEdits::Unsupported(reason) => (OverrideStatus::Unsupported(reason), None),
+            };
+        result.specs.push(SpecResult {
+            spec: spec.to_string(),
+            status,
+            changed_lines,
+        });
     }
-    // Print AST module.
-    if result {
-        let code_str = print_ast_module(&module);
+    if result.changed() {
         std::fs::write(file, &code_str)?
     }
     Ok(result)
 }
+
+/// Parse the source code of the file to AST module.
+fn parse_source(file: &str, code: &str) -> Result<ast::Module> {
+    parse_file(file, Some(code.to_string())).map_err(|err| anyhow!("{}", err))
+}
+
+/// Returns the (1-based) first and last changed lines of the new code.
+fn changed_lines(old: &str, new: &str) -> Option<(u64, u64)> {
+    let diff = TextDiff::from_lines(old, new);
+    let mut lines: Option<(u64, u64)> = None;
+    for op in diff.ops() {
+        if op.tag() == DiffTag::Equal {
+            continue;
+        }
+        let range = op.new_range();
+        let start = range.start as u64 + 1;
+        let end = (range.end as u64).max(start);
+        lines = Some(match lines {
+            Some((first, _)) => (first, end),
+            None => (start, end),
+        });
+    }
+    lines
+}
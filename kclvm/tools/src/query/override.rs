@@ -3,15 +3,18 @@ use std::collections::HashSet;
 use anyhow::{anyhow, Result};
 
 use kclvm_ast::config::try_get_config_expr_mut;
-use kclvm_ast::path::{get_attr_paths_from_config_expr, get_key_path};
+use kclvm_ast::path::get_key_path;
 use kclvm_ast::walker::MutSelfMutWalker;
 use kclvm_ast::{ast, walk_if_mut};
-use kclvm_parser::parse_expr;
+use kclvm_parser::try_parse_expr;
 use kclvm_sema::pre_process::{fix_config_expr_nest_attr, transform_multi_assign};
 
-use crate::printer::print_ast_module;
+use crate::printer::{print_ast_module, print_ast_node, ASTNode};
 
-use super::util::{invalid_spec_error, split_field_path};
+use super::util::{
+    invalid_spec_error, parse_field_path, split_field_path, split_outside_brackets, FieldPathPart,
+    ListSelector,
+};
 
 /// Import statement column offset always start with 1.
 /// todo: The (1-based) column offset needs to be constrained by specifications.
@@ -48,7 +51,7 @@ pub fn apply_overrides(
         };
         if let Some(modules) = prog.pkgs.get_mut(pkgpath) {
             for m in modules.iter_mut() {
-                if apply_override_on_module(m, o, import_paths)? && print_ast {
+                if apply_override_on_module(m, o, import_paths, false)? && print_ast {
                     let code_str = print_ast_module(m);
                     std::fs::write(&m.filename, &code_str)?
                 }
@@ -58,25 +61,22 @@ pub fn apply_overrides(
     Ok(())
 }
 
-/// Build a expression from string.
-fn build_expr_from_string(value: &str) -> Option<ast::NodeRef<ast::Expr>> {
-    let expr: Option<ast::NodeRef<ast::Expr>> = parse_expr(value);
-    match &expr {
-        Some(e) => match &e.node {
-            // fix attr=value to attr="value"
-            ast::Expr::Identifier(_) | ast::Expr::Unary(_) | ast::Expr::Binary(_) => {
-                Some(ast::NodeRef::new(ast::Node::node_with_pos(
-                    ast::Expr::StringLit(ast::StringLit {
-                        is_long_string: false,
-                        raw_value: format!("{:?}", value),
-                        value: value.to_string(),
-                    }),
-                    e.pos(),
-                )))
-            }
-            _ => expr,
-        },
-        None => None,
+/// Build a expression from string, the syntax error of the value is returned.
+fn try_build_expr_from_string(value: &str) -> Result<ast::NodeRef<ast::Expr>> {
+    let expr = try_parse_expr(value).map_err(|err| anyhow!(err))?;
+    match &expr.node {
+        // fix attr=value to attr="value"
+        ast::Expr::Identifier(_) | ast::Expr::Unary(_) | ast::Expr::Binary(_) => {
+            Ok(ast::NodeRef::new(ast::Node::node_with_pos(
+                ast::Expr::StringLit(ast::StringLit {
+                    is_long_string: false,
+                    raw_value: format!("{:?}", value),
+                    value: value.to_string(),
+                }),
+                expr.pos(),
+            )))
+        }
+        _ => Ok(expr),
     }
 }
 
@@ -86,6 +86,9 @@ fn build_expr_from_string(value: &str) -> Option<ast::NodeRef<ast::Expr>> {
 /// The parameters of the method are all compiler internal concepts such as
 /// AST, etc.
 ///
+/// A new top-level assignment is inserted for the spec whose target variable
+/// does not exist only when `create_missing` is true.
+///
 /// # Examples
 ///
 /// ```no_check
@@ -95,27 +98,24 @@ fn build_expr_from_string(value: &str) -> Option<ast::NodeRef<ast::Expr>> {
 /// let mut module = parse_file("", None).unwrap();
 /// let override_spec = parse_override_spec("config.id=1").unwrap();
 /// let import_paths = vec!["path.to.pkg".to_string()];
-/// let result = apply_override_on_module(&mut module, override_spec, &import_paths).unwrap();
+/// let result = apply_override_on_module(&mut module, override_spec, &import_paths, false).unwrap();
 /// ```
 pub fn apply_override_on_module(
     m: &mut ast::Module,
     o: &ast::OverrideSpec,
     import_paths: &[String],
+    create_missing: bool,
 ) -> Result<bool> {
+    let parts = parse_field_path(&o.field_path)?;
+    let target = &parts[0];
+    // The spec may not be parsed by `parse_override_spec` e.g., from the runner, so the
+    // invalid override value is returned as the error before the module is changed.
+    let override_value = match o.action {
+        ast::OverrideAction::CreateOrUpdate => Some(build_override_value(&o.field_value)?),
+        ast::OverrideAction::Delete => None,
+    };
     // Apply import paths on AST module.
     apply_import_paths_on_module(m, import_paths)?;
-    let ss = o.field_path.split('.').collect::<Vec<&str>>();
-    if ss.len() <= 1 {
-        return Ok(false);
-    }
-    let target_id = ss[0];
-    let field = ss[1..].join(".");
-    let value = &o.field_value;
-    let key = ast::Identifier {
-        names: field.split('.').map(|s| s.to_string()).collect(),
-        ctx: ast::ExprContext::Store,
-        pkgpath: "".to_string(),
-    };
     // Transform config expr to simplify the config path query and override.
     fix_config_expr_nest_attr(m);
     // When there is a multi-target assignment statement of the form `a = b = Config {}`,
@@ -126,20 +126,123 @@ pub fn apply_override_on_module(
     // b = Config {}
     // ```
     transform_multi_assign(m);
+    // Override the top-level scalar e.g., `replicas=3`.
+    if parts.len() == 1 && target.selector.is_none() {
+        return Ok(apply_override_on_top_level(
+            m,
+            &target.name,
+            override_value,
+            &o.action,
+            create_missing,
+        ));
+    }
+    let field = parts[1..]
+        .iter()
+        .map(|p| p.name.clone())
+        .collect::<Vec<String>>();
     let mut transformer = OverrideTransformer {
-        target_id: target_id.to_string(),
-        field_path: field,
-        override_key: key,
-        override_value: build_expr_from_string(value),
+        target_id: target.name.clone(),
+        target_selector: target.selector.clone(),
+        field_parts: parts[1..].to_vec(),
+        override_key: ast::Identifier {
+            names: field,
+            ctx: ast::ExprContext::Store,
+            pkgpath: "".to_string(),
+        },
+        override_value,
         override_target_count: 0,
         has_override: false,
         action: o.action.clone(),
     };
     transformer.walk_module(m);
+    // Insert a new top-level assignment when the target does not exist e.g.,
+    // `app.image="nginx"` inserts `app = {image = "nginx"}`.
+    if create_missing
+        && !transformer.has_override
+        && matches!(o.action, ast::OverrideAction::CreateOrUpdate)
+        && parts.iter().all(|p| p.selector.is_none())
+        && find_top_level_assign(m, &target.name).is_none()
+    {
+        let entry = ast::ConfigEntry {
+            key: Some(Box::new(ast::Node::dummy_node(ast::Expr::Identifier(
+                transformer.override_key.clone(),
+            )))),
+            value: transformer.clone_override_value(),
+            operation: ast::ConfigEntryOperation::Override,
+            insert_index: -1,
+        };
+        let value = Box::new(ast::Node::dummy_node(ast::Expr::Config(ast::ConfigExpr {
+            items: vec![Box::new(ast::Node::dummy_node(entry))],
+        })));
+        m.body.push(build_assign_stmt(&target.name, value));
+        return Ok(true);
+    }
     Ok(transformer.has_override)
 }
 
-/// Parse override spec string to override structure.
+/// Apply the override on the top-level assignment of the name, a new assignment
+/// is inserted when it does not exist, the action is CREATE_OR_UPDATE and
+/// `create_missing` is true.
+fn apply_override_on_top_level(
+    m: &mut ast::Module,
+    name: &str,
+    value: Option<ast::NodeRef<ast::Expr>>,
+    action: &ast::OverrideAction,
+    create_missing: bool,
+) -> bool {
+    let index = find_top_level_assign(m, name);
+    match (action, index, value) {
+        (ast::OverrideAction::CreateOrUpdate, Some(index), Some(mut value)) => {
+            match &mut m.body[index].node {
+                ast::Stmt::Assign(assign_stmt) => {
+                    // Use position information that needs to override the expression.
+                    value.set_pos(assign_stmt.value.pos());
+                    assign_stmt.value = value;
+                    true
+                }
+                _ => false,
+            }
+        }
+        (ast::OverrideAction::CreateOrUpdate, None, Some(value)) if create_missing => {
+            m.body.push(build_assign_stmt(name, value));
+            true
+        }
+        (ast::OverrideAction::Delete, Some(index), _) => {
+            m.body.remove(index);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Find the index of the top-level assignment statement whose only target is the name.
+fn find_top_level_assign(m: &ast::Module, name: &str) -> Option<usize> {
+    m.body.iter().position(|stmt| match &stmt.node {
+        ast::Stmt::Assign(assign_stmt) => {
+            assign_stmt.targets.len() == 1 && assign_stmt.targets[0].node.names == [name]
+        }
+        ast::Stmt::Unification(unification_stmt) => unification_stmt.target.node.names == [name],
+        _ => false,
+    })
+}
+
+/// Build a top-level assignment statement `name = value`.
+fn build_assign_stmt(name: &str, value: ast::NodeRef<ast::Expr>) -> ast::NodeRef<ast::Stmt> {
+    Box::new(ast::Node::dummy_node(ast::Stmt::Assign(ast::AssignStmt {
+        targets: vec![Box::new(ast::Node::dummy_node(ast::Identifier {
+            names: vec![name.to_string()],
+            ctx: ast::ExprContext::Store,
+            pkgpath: "".to_string(),
+        }))],
+        value,
+        type_annotation: None,
+        ty: None,
+    })))
+}
+
+/// Parse override spec string to override structure, the field path and the
+/// field value are both checked and an error with the reason is returned when
+/// the spec is invalid.
 ///
 /// parse_override_spec("alice.age=10") -> ast::OverrideSpec {
 ///     pkgpath: "".to_string(),
@@ -148,16 +251,15 @@ pub fn apply_override_on_module(
 ///     action: ast::OverrideAction::CreateOrUpdate,
 /// }
 pub(crate) fn parse_override_spec(spec: &str) -> Result<ast::OverrideSpec> {
-    if spec.contains('=') {
+    // The `=` in list selectors e.g., `containers[name=web]` is not the separator.
+    let split_values = split_outside_brackets(spec, '=');
+    if split_values.len() > 1 {
         // Create or update the override value.
-        let split_values = spec.splitn(2, '=').collect::<Vec<&str>>();
-        let path = split_values
-            .get(0)
-            .ok_or_else(|| invalid_spec_error(spec))?;
-        let field_value = split_values
-            .get(1)
-            .ok_or_else(|| invalid_spec_error(spec))?;
+        let path = split_values[0];
+        let field_value = &spec[path.len() + 1..];
         let (pkgpath, field_path) = split_field_path(path)?;
+        parse_field_path(&field_path)?;
+        build_override_value(field_value)?;
         Ok(ast::OverrideSpec {
             pkgpath,
             field_path,
@@ -167,6 +269,7 @@ pub(crate) fn parse_override_spec(spec: &str) -> Result<ast::OverrideSpec> {
     } else if let Some(stripped_spec) = spec.strip_suffix('-') {
         // Delete the override value.
         let (pkgpath, field_path) = split_field_path(stripped_spec)?;
+        parse_field_path(&field_path)?;
         Ok(ast::OverrideSpec {
             pkgpath,
             field_path,
@@ -178,6 +281,22 @@ pub(crate) fn parse_override_spec(spec: &str) -> Result<ast::OverrideSpec> {
     }
 }
 
+/// Build the override value expression, the empty value and the value which can't
+/// be parsed into an expression are returned as the error.
+pub(super) fn build_override_value(value: &str) -> Result<ast::NodeRef<ast::Expr>> {
+    if value.trim().is_empty() {
+        return Err(anyhow!("Invalid override value, the value is empty"));
+    }
+    match try_build_expr_from_string(value) {
+        Ok(expr) => Ok(expr),
+        Err(err) => Err(anyhow!(
+            "Invalid override value {:?}, it is not a valid expression: {}",
+            value,
+            err
+        )),
+    }
+}
+
 // Transform the AST module with the import path list.
 fn apply_import_paths_on_module(m: &mut ast::Module, import_paths: &[String]) -> Result<()> {
     if import_paths.is_empty() {
@@ -225,7 +344,8 @@ fn apply_import_paths_on_module(m: &mut ast::Module, import_paths: &[String]) ->
 /// OverrideTransformer is used to walk AST and transform it with the override values.
 struct OverrideTransformer {
     pub target_id: String,
-    pub field_path: String,
+    pub target_selector: Option<ListSelector>,
+    pub field_parts: Vec<FieldPathPart>,
     pub override_key: ast::Identifier,
     pub override_value: Option<ast::NodeRef<ast::Expr>>,
     pub override_target_count: usize,
@@ -242,16 +362,17 @@ impl<'ctx> MutSelfMutWalker<'ctx> for OverrideTransformer {
                 unification_stmt.target.node.names
             ),
         };
-        if name != &self.target_id {
+        if name != &self.target_id || self.target_selector.is_some() {
             return;
         }
         self.override_target_count = 1;
-        self.has_override = true;
         self.walk_schema_expr(&mut unification_stmt.value.node);
     }
 
     fn walk_assign_stmt(&mut self, assign_stmt: &'ctx mut ast::AssignStmt) {
-        if let ast::Expr::Schema(_) = &assign_stmt.value.node {
+        if let ast::Expr::Schema(_) | ast::Expr::Config(_) | ast::Expr::List(_) =
+            &assign_stmt.value.node
+        {
             self.override_target_count = 0;
             for target in &assign_stmt.targets {
                 if target.node.names.len() != 1 {
@@ -265,8 +386,19 @@ impl<'ctx> MutSelfMutWalker<'ctx> for OverrideTransformer {
             if self.override_target_count == 0 {
                 return;
             }
-            self.has_override = true;
-            self.walk_expr(&mut assign_stmt.value.node);
+            match (&mut assign_stmt.value.node, self.target_selector.clone()) {
+                // Override the list elements selected by the target e.g., `containers[0].image`.
+                (ast::Expr::List(list_expr), Some(selector)) => {
+                    let parts = self.field_parts.clone();
+                    if self.replace_list_with_path_parts(list_expr, &selector, &parts) {
+                        self.has_override = true;
+                    }
+                }
+                (ast::Expr::Config(config_expr), None) => self.override_config_expr(config_expr),
+                (ast::Expr::Schema(schema_expr), None) => self.walk_schema_expr(schema_expr),
+                _ => {}
+            }
+            self.override_target_count = 0;
         }
     }
 
@@ -275,23 +407,7 @@ impl<'ctx> MutSelfMutWalker<'ctx> for OverrideTransformer {
             return;
         }
         if let ast::Expr::Config(config_expr) = &mut schema_expr.config.node {
-            if !self.lookup_config_and_replace(config_expr) {
-                // Not exist and append an override value when the action is CREATE_OR_UPDATE
-                if let ast::OverrideAction::CreateOrUpdate = self.action {
-                    if let ast::Expr::Config(config_expr) = &mut schema_expr.config.node {
-                        config_expr
-                            .items
-                            .push(Box::new(ast::Node::dummy_node(ast::ConfigEntry {
-                                key: Some(Box::new(ast::Node::dummy_node(ast::Expr::Identifier(
-                                    self.override_key.clone(),
-                                )))),
-                                value: self.clone_override_value(),
-                                operation: ast::ConfigEntryOperation::Override,
-                                insert_index: -1,
-                            })));
-                    }
-                }
-            }
+            self.override_config_expr(config_expr);
         }
         self.override_target_count = 0;
     }
@@ -315,37 +431,46 @@ impl<'ctx> MutSelfMutWalker<'ctx> for OverrideTransformer {
 }
 
 impl OverrideTransformer {
-    /// Lookup schema config all fields and replace if it is matched with the override spec,
-    /// return whether is found a replaced one.
-    fn lookup_config_and_replace(&mut self, config_expr: &mut ast::ConfigExpr) -> bool {
-        // Get all entry paths from a config expression.
-        let paths = get_attr_paths_from_config_expr(config_expr);
-        // Query whether there is a matching path from the path lookup table.
-        match paths.iter().position(|r| r == &self.field_path) {
-            Some(pos) => {
-                let path = &paths[pos];
-                // Split a path into multiple parts. `a.b.c` -> ["a", "b", "c"]
-                let parts = path.split('.').collect::<Vec<&str>>();
-                self.replace_config_with_path_parts(config_expr, &parts);
-                true
+    /// Override the config expression of the target, the override value is appended
+    /// when the field is not found and the action is CREATE_OR_UPDATE.
+    fn override_config_expr(&mut self, config_expr: &mut ast::ConfigExpr) {
+        let parts = self.field_parts.clone();
+        if self.replace_config_with_path_parts(config_expr, &parts) {
+            self.has_override = true;
+        } else if let ast::OverrideAction::CreateOrUpdate = self.action {
+            // Not exist and append an override value when the action is CREATE_OR_UPDATE,
+            // the list elements selected by the path can't be created.
+            if parts.iter().all(|p| p.selector.is_none()) {
+                config_expr
+                    .items
+                    .push(Box::new(ast::Node::dummy_node(ast::ConfigEntry {
+                        key: Some(Box::new(ast::Node::dummy_node(ast::Expr::Identifier(
+                            self.override_key.clone(),
+                        )))),
+                        value: self.clone_override_value(),
+                        operation: ast::ConfigEntryOperation::Override,
+                        insert_index: -1,
+                    })));
+                self.has_override = true;
             }
-            None => false,
         }
     }
 
     /// Replace AST config expr with one part of path. The implementation of this function
-    /// uses recursive matching to find the config entry need to be modified.
+    /// uses recursive matching to find the config entry need to be modified, and returns
+    /// whether the whole path is matched.
     fn replace_config_with_path_parts(
         &mut self,
         config_expr: &mut ast::ConfigExpr,
-        parts: &[&str],
-    ) {
+        parts: &[FieldPathPart],
+    ) -> bool {
         // Do not replace empty path parts and out of index parts on the config expression.
         if parts.is_empty() {
-            return;
+            return false;
         }
         // Always take the first part to match, because recursive search is required.
-        let part = parts[0];
+        let part = &parts[0];
+        let mut found = false;
         let mut delete_index_set = HashSet::new();
         // Loop all entries in the config expression and replace, because there may be duplicate
         // configuration items in config.
@@ -355,12 +480,24 @@ impl OverrideTransformer {
             // - `get_path_key` returns the real config key name.
             // For example, the real config node is `a: {b: c: {}}`. The path
             // that needs to be modified is `a.b.c`, and its parts are ["a", "b", "c"].
-            if part == get_key_path(&item.node.key) {
+            if part.name != get_key_path(&item.node.key) {
+                continue;
+            }
+            match &part.selector {
+                // Replace the list elements selected by the part e.g., `containers[name=web]`.
+                Some(selector) => {
+                    if let ast::Expr::List(list_expr) = &mut item.node.value.node {
+                        if self.replace_list_with_path_parts(list_expr, selector, &parts[1..]) {
+                            found = true;
+                        }
+                    }
+                }
                 // When the last part of the path is successfully recursively matched,
                 // it indicates that the original value that needs to be overwritten
                 // is successfully found, and the new value is used to overwrite it.
                 // - `parts.len() == 1` denotes the path matches exactly.
-                if parts.len() == 1 {
+                None if parts.len() == 1 => {
+                    found = true;
                     match self.action {
                         ast::OverrideAction::CreateOrUpdate => {
                             let mut value = self.clone_override_value();
@@ -382,9 +519,12 @@ impl OverrideTransformer {
                 // is that rust cannot directly hold shared references to AST nodes
                 // (ast::NodeRef<T> is a Box<T>), so recursive search is performed
                 // directly on AST nodes.
-                else if let Some(config_expr) = try_get_config_expr_mut(&mut item.node.value.node)
-                {
-                    self.replace_config_with_path_parts(config_expr, &parts[1..]);
+                None => {
+                    if let Some(config_expr) = try_get_config_expr_mut(&mut item.node.value.node) {
+                        if self.replace_config_with_path_parts(config_expr, &parts[1..]) {
+                            found = true;
+                        }
+                    }
                 }
             }
         }
@@ -401,6 +541,58 @@ impl OverrideTransformer {
                 .map(|(_, item)| <&ast::NodeRef<ast::ConfigEntry>>::clone(item).clone())
                 .collect();
         }
+        found
+    }
+
+    /// Replace the list elements selected by the selector with the subsequent parts
+    /// of the path, and returns whether the whole path is matched. The selected
+    /// elements are replaced or deleted when there are no subsequent parts.
+    fn replace_list_with_path_parts(
+        &mut self,
+        list_expr: &mut ast::ListExpr,
+        selector: &ListSelector,
+        parts: &[FieldPathPart],
+    ) -> bool {
+        let indices = list_expr
+            .elts
+            .iter()
+            .enumerate()
+            .filter(|(i, elt)| is_selected_element(*i, elt, selector))
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        if indices.is_empty() {
+            return false;
+        }
+        if !parts.is_empty() {
+            let mut found = false;
+            for i in indices {
+                if let Some(config_expr) = try_get_config_expr_mut(&mut list_expr.elts[i].node) {
+                    if self.replace_config_with_path_parts(config_expr, parts) {
+                        found = true;
+                    }
+                }
+            }
+            return found;
+        }
+        match self.action {
+            ast::OverrideAction::CreateOrUpdate => {
+                for i in indices {
+                    let mut value = self.clone_override_value();
+                    // Use position information that needs to override the expression.
+                    value.set_pos(list_expr.elts[i].pos());
+                    list_expr.elts[i] = value;
+                }
+            }
+            ast::OverrideAction::Delete => {
+                let mut i = 0;
+                list_expr.elts.retain(|_| {
+                    let retained = !indices.contains(&i);
+                    i += 1;
+                    retained
+                });
+            }
+        }
+        true
     }
 
     /// Clone a override value
@@ -412,3 +604,33 @@ impl OverrideTransformer {
         }
     }
 }
+
+/// Whether the list element at the index is selected by the selector. A key
+/// selector e.g., `[name=web]` selects the config elements which have the key
+/// `name` and the value is the string `"web"` or the literal `web`.
+pub(super) fn is_selected_element(
+    index: usize,
+    elt: &ast::NodeRef<ast::Expr>,
+    selector: &ListSelector,
+) -> bool {
+    match selector {
+        ListSelector::Index(i) => *i == index,
+        ListSelector::Key(key, value) => {
+            let config_expr = match &elt.node {
+                ast::Expr::Schema(schema_expr) => match &schema_expr.config.node {
+                    ast::Expr::Config(config_expr) => config_expr,
+                    _ => return false,
+                },
+                ast::Expr::Config(config_expr) => config_expr,
+                _ => return false,
+            };
+            config_expr.items.iter().any(|item| {
+                get_key_path(&item.node.key) == *key
+                    && match &item.node.value.node {
+                        ast::Expr::StringLit(string_lit) => string_lit.value == *value,
+                        _ => print_ast_node(ASTNode::Expr(&item.node.value)) == *value,
+                    }
+            })
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::printer::print_ast_module;
 use kclvm_ast::ast;
 use kclvm_parser::parse_file;
 use pretty_assertions::assert_eq;
+use tempfile::tempdir;
 
 /// Test override_file result.
 #[test]
@@ -13,10 +14,15 @@ fn test_override_file_simple() {
         ":config.data={id=1,value=\"override_value\"}".to_string(),
     ];
     let import_paths = vec![];
-    assert_eq!(
-        override_file("./src/query/test_data/simple.k", &specs, &import_paths).unwrap(),
-        true
+    let result = override_file(
+        "./src/query/test_data/simple.k",
+        &specs,
+        &import_paths,
+        false,
     )
+    .unwrap();
+    assert!(result.changed());
+    assert!(result.is_ok(), "{:?}", result.errors());
 }
 /// Test override_file result.
 #[test]
@@ -32,9 +38,11 @@ fn test_override_file_import_paths() {
         override_file(
             "./src/query/test_data/import_paths.k",
             &specs,
-            &import_paths
+            &import_paths,
+            false
         )
-        .unwrap(),
+        .unwrap()
+        .changed(),
         true
     )
 }
@@ -63,7 +71,7 @@ fn test_override_file_config() {
     let import_paths = vec![];
     let mut module = parse_file("./src/query/test_data/config.k", None).unwrap();
     for o in &overrides {
-        apply_override_on_module(&mut module, o, &import_paths).unwrap();
+        apply_override_on_module(&mut module, o, &import_paths, false).unwrap();
     }
     let expected_code = print_ast_module(&module);
     assert_eq!(
@@ -113,11 +121,370 @@ appConfigurationUnification: AppConfiguration {
     );
 }
 
+/// Test override_file result of each spec.
+#[test]
+fn test_override_file_spec_results() {
+    let temp_dir = tempdir().unwrap();
+    let file = temp_dir.path().join("report.k");
+    let file = file.to_str().unwrap();
+    std::fs::write(file, "config = Config {image = \"image:v1\"}\n").unwrap();
+    let specs = vec![
+        "config.image=\"image:v2\"".to_string(),
+        "config.replicas-".to_string(),
+        "missing.image=\"image:v2\"".to_string(),
+        "config..image=1".to_string(),
+        "config.image=".to_string(),
+        "config.image".to_string(),
+    ];
+    let result = override_file(file, &specs, &[], false).unwrap();
+    let status = result
+        .specs
+        .iter()
+        .map(|s| s.status.clone())
+        .collect::<Vec<OverrideStatus>>();
+    assert!(matches!(status[0], OverrideStatus::Applied));
+    assert_eq!(result.specs[0].changed_lines, Some((1, 1)));
+    assert!(matches!(status[1], OverrideStatus::NotFound));
+    assert!(matches!(status[2], OverrideStatus::NotFound));
+    assert!(matches!(status[3], OverrideStatus::Invalid(_)));
+    assert!(matches!(status[4], OverrideStatus::Invalid(_)));
+    assert!(matches!(status[5], OverrideStatus::Invalid(_)));
+    assert!(result.changed());
+    assert!(!result.is_ok());
+    assert_eq!(result.errors().len(), 5);
+}
+
+/// Test overrides on config literals, list elements and top-level scalars.
+#[test]
+fn test_override_config_list_and_scalar() {
+    let specs = vec![
+        "replicas=3",
+        "debug-",
+        "version=\"v2\"",
+        "app.name=\"app\"",
+        "app.containers[name=web].image=\"nginx:v2\"",
+        "app.containers[1]-",
+        "app.ports[0]=8080",
+        "service.type=\"ClusterIP\"",
+    ];
+    let mut module = parse_file(
+        "",
+        Some(
+            r#"replicas = 1
+debug = True
+app = {
+    name = "demo"
+    containers = [{name = "web", image = "nginx:v1"}, {name = "sidecar", image = "envoy"}]
+    ports = [80, 443]
+}
+"#
+            .to_string(),
+        ),
+    )
+    .unwrap();
+    for spec in specs {
+        let o = parse_override_spec(spec).unwrap();
+        assert!(
+            apply_override_on_module(&mut module, &o, &[], true).unwrap(),
+            "{} test failed",
+            spec
+        );
+    }
+    assert_eq!(
+        print_ast_module(&module),
+        r#"replicas = 3
+app = {
+    name = "app"
+    containers = [{name = "web", image = "nginx:v2"}]
+    ports = [8080, 443]
+}
+version = "v2"
+service = {type = "ClusterIP"}
+"#
+    );
+    let o = parse_override_spec("app.containers[name=db].image=\"mysql\"").unwrap();
+    assert!(!apply_override_on_module(&mut module, &o, &[], true).unwrap());
+    // The missing top-level variables are not created without `create_missing`.
+    for spec in ["owner=\"team\"", "ingress.host=\"example.com\""] {
+        let o = parse_override_spec(spec).unwrap();
+        assert!(!apply_override_on_module(&mut module, &o, &[], false).unwrap());
+    }
+}
+
+/// Test overrides with the invalid values which are not parsed by `parse_override_spec`.
+#[test]
+fn test_override_invalid_value() {
+    let src = "app = {name = \"demo\"}\n";
+    let mut module = parse_file("", Some(src.to_string())).unwrap();
+    for (field_path, field_value) in [("app.name", "{"), ("app.image", ""), ("replicas", "1 +")] {
+        let o = ast::OverrideSpec {
+            pkgpath: "".to_string(),
+            field_path: field_path.to_string(),
+            field_value: field_value.to_string(),
+            action: ast::OverrideAction::CreateOrUpdate,
+        };
+        assert!(
+            apply_override_on_module(&mut module, &o, &["pkg".to_string()], true).is_err(),
+            "{} test failed",
+            field_path
+        );
+    }
+    // The module is not changed by the invalid overrides.
+    assert_eq!(print_ast_module(&module), src);
+}
+
 /// Test override spec parser.
 #[test]
 fn test_parse_override_spec_invalid() {
-    let specs = vec![":a:", "=a=", ":a", "a-1"];
+    let specs = vec![
+        ":a:", "=a=", ":a", "a-1", "a..b=1", "a.b=", "a.1b-", "a.b[=1", "a.b[x]=1", "a.b[0",
+    ];
     for spec in specs {
         assert!(parse_override_spec(spec).is_err(), "{} test failed", spec);
     }
 }
+
+/// Test the config path query.
+#[test]
+fn test_get() {
+    let prog = kclvm_parser::load_program(&["./src/query/test_data/get.k"], None).unwrap();
+    let results = get(&prog, "app.spec.replicas").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].expr, "3");
+    assert_eq!(results[0].line, 6);
+    let results = get(&prog, "__main__:app.labels").unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].expr, "{app = \"nginx\"}");
+    assert_eq!(results[1].expr, "{env = \"prod\"}");
+    assert!(get(&prog, "app.spec.image").unwrap().is_empty());
+    assert!(get(&prog, "app.containers[0]").is_err());
+    assert!(get(&prog, "pkg:app").is_err());
+}
+
+/// Test the evaluated value query.
+#[test]
+fn test_get_value() {
+    let json_result = r#"{"app": {"spec": {"replicas": 3}, "labels": {"app": "nginx"}}}"#;
+    assert_eq!(
+        get_value(json_result, "app.spec.replicas").unwrap(),
+        Some("3".to_string())
+    );
+    assert_eq!(
+        get_value(json_result, "app.labels").unwrap(),
+        Some(r#"{"app":"nginx"}"#.to_string())
+    );
+    assert_eq!(get_value(json_result, "app.spec.image").unwrap(), None);
+    assert_eq!(get_value(json_result, "pkg:app").unwrap(), None);
+    let json_result = r#"{"app": {"containers": [{"name": "web", "image": "nginx"}, {"name": "db", "port": 3306}]}}"#;
+    assert_eq!(
+        get_value(json_result, "app.containers[0].image").unwrap(),
+        Some(r#""nginx""#.to_string())
+    );
+    assert_eq!(
+        get_value(json_result, "app.containers[name=db]").unwrap(),
+        Some(r#"{"name":"db","port":3306}"#.to_string())
+    );
+    assert_eq!(
+        get_value(json_result, "app.containers[port=3306].name").unwrap(),
+        Some(r#""db""#.to_string())
+    );
+    assert_eq!(get_value(json_result, "app.containers[2]").unwrap(), None);
+    assert_eq!(get_value(json_result, "app[0]").unwrap(), None);
+    assert!(get_value(json_result, "app.containers[0").is_err());
+}
+
+/// Test listing top-level variables and config paths.
+#[test]
+fn test_list() {
+    let prog = kclvm_parser::load_program(&["./src/query/test_data/get.k"], None).unwrap();
+    let variables = list(&prog, "").unwrap();
+    assert_eq!(variables.len(), 1);
+    let app = &variables[0];
+    assert_eq!((app.name.as_str(), app.line), ("app", 5));
+    assert_eq!(app.schema.as_deref(), Some("App"));
+    assert_eq!(
+        app.paths,
+        vec![
+            "app.spec",
+            "app.spec.replicas",
+            "app.labels",
+            "app.labels.app",
+            "app.labels.env"
+        ]
+    );
+    let attrs = app
+        .attrs
+        .iter()
+        .map(|attr| (attr.path.as_str(), attr.line))
+        .collect::<Vec<(&str, u64)>>();
+    assert_eq!(
+        attrs,
+        vec![("app.spec", 6), ("app.labels", 7), ("app.labels", 10)]
+    );
+    assert_eq!(app.attrs[0].children[0].path, "app.spec.replicas");
+    assert!(matches!(
+        app.attrs[0].children[0].operation,
+        ast::ConfigEntryOperation::Override
+    ));
+    assert!(matches!(
+        app.attrs[1].operation,
+        ast::ConfigEntryOperation::Union
+    ));
+    assert!(list(&prog, "pkg").is_err());
+}
+
+/// Test listing schemas and their attributes.
+#[test]
+fn test_list_schemas() {
+    let prog = kclvm_parser::load_program(&["./src/query/test_data/get.k"], None).unwrap();
+    let schemas = list_schemas(&prog, "").unwrap();
+    assert_eq!(schemas.len(), 1);
+    let schema = &schemas[0];
+    assert_eq!((schema.name.as_str(), schema.line), ("App", 1));
+    assert_eq!(schema.parent, None);
+    let attrs = schema
+        .attrs
+        .iter()
+        .map(|attr| (attr.name.as_str(), attr.type_str.as_str(), attr.line))
+        .collect::<Vec<(&str, &str, u64)>>();
+    assert_eq!(
+        attrs,
+        vec![("spec", "{str:}", 2), ("labels", "{str:str}", 3)]
+    );
+    assert!(!schema.attrs[0].is_optional && !schema.attrs[0].has_default);
+    assert!(list_schemas(&prog, "pkg").is_err());
+}
+
+/// Test override_file keeps comments and untouched code.
+#[test]
+fn test_override_file_source_preserving() {
+    let temp_dir = tempdir().unwrap();
+    let file = temp_dir.path().join("source_preserving.k");
+    let file = file.to_str().unwrap();
+    std::fs::write(
+        file,
+        r#"# App config
+app = {
+    name    =   "demo"  # the name
+    spec.replicas = 1
+    containers = [
+        {name = "web", image = "nginx:v1"}
+        {name = "sidecar", image = "envoy"}
+    ]
+    labels = {app = "demo", env = "test"}
+}
+"#,
+    )
+    .unwrap();
+    let specs = vec![
+        "app.spec.replicas=3".to_string(),
+        "app.containers[name=web].image=\"nginx:v2\"".to_string(),
+        "app.containers[name=sidecar]-".to_string(),
+        "app.labels.env-".to_string(),
+        "app.owner=\"team\"".to_string(),
+        "replicas=2".to_string(),
+    ];
+    let result = override_file(file, &specs, &["pkg".to_string()], true).unwrap();
+    let code = std::fs::read_to_string(file).unwrap();
+    assert!(result.is_ok(), "{:?}", result.errors());
+    assert_eq!(result.specs[0].changed_lines, Some((5, 5)));
+    assert_eq!(
+        code,
+        r#"import pkg
+# App config
+app = {
+    name    =   "demo"  # the name
+    spec.replicas = 3
+    containers = [
+        {name = "web", image = "nginx:v2"}
+    ]
+    labels = {app = "demo"}
+    owner = "team"
+}
+replicas = 2
+"#
+    );
+}
+
+/// Test override_file reports the specs which can't be applied on the source
+/// and keeps the file untouched.
+#[test]
+fn test_override_file_unsupported() {
+    let temp_dir = tempdir().unwrap();
+    let file = temp_dir.path().join("unsupported.k");
+    let file = file.to_str().unwrap();
+    let code = r#"schema App:
+    name?: str
+    spec?: {str:}
+
+app: App {name = "app"}
+config = {
+    spec.replicas = 1
+}
+"#;
+    std::fs::write(file, code).unwrap();
+    let specs = vec![
+        "app={}".to_string(),
+        "config.spec={replicas = 2}".to_string(),
+    ];
+    let result = override_file(file, &specs, &[], false).unwrap();
+    for spec_result in &result.specs {
+        assert!(
+            matches!(spec_result.status, OverrideStatus::Unsupported(_)),
+            "{:?}",
+            spec_result
+        );
+    }
+    assert!(!result.changed());
+    assert_eq!(result.errors().len(), 2);
+    assert_eq!(std::fs::read_to_string(file).unwrap(), code);
+}
+
+/// Test the source-preserving overrides on multi-target assignments and repeated targets.
+#[test]
+fn test_override_file_multi_assign() {
+    let temp_dir = tempdir().unwrap();
+    let file = temp_dir.path().join("multi_assign.k");
+    let file = file.to_str().unwrap();
+    std::fs::write(
+        file,
+        r#"a = b = {
+    x = 1  # shared
+}
+c = d = e = 1
+f = {x = 1}
+f = {y = 1}
+"#,
+    )
+    .unwrap();
+    let specs = vec![
+        "a.x=2".to_string(),
+        "b.y=3".to_string(),
+        "d-".to_string(),
+        "e=2".to_string(),
+        "f.z=1".to_string(),
+        "f.x=2".to_string(),
+    ];
+    let result = override_file(file, &specs, &[], false).unwrap();
+    assert!(result.is_ok(), "{:?}", result.errors());
+    assert_eq!(
+        std::fs::read_to_string(file).unwrap(),
+        r#"a = {
+    x = 2  # shared
+}
+b = {
+    x = 1  # shared
+    y = 3
+}
+c = 1
+e = 2
+f = {x = 2}
+f = {y = 1, z = 1}
+"#
+    );
+    // The multi-target assignments are split in the same way by the AST overrides.
+    let mut module = parse_file("", Some("a = b = {x = 1}\n".to_string())).unwrap();
+    let o = parse_override_spec("a.x=2").unwrap();
+    assert!(apply_override_on_module(&mut module, &o, &[], false).unwrap());
+    assert_eq!(print_ast_module(&module), "a = {x = 2}\nb = {x = 1}\n");
+}
@@ -22,6 +22,127 @@ pub(crate) fn split_field_path(path: &str) -> Result<(String, String)> {
     }
 }
 
+/// A part of the override field path e.g., `image`, `containers[0]`
+/// and `containers[name=web]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FieldPathPart {
+    pub name: String,
+    pub selector: Option<ListSelector>,
+}
+
+/// The list element selector of a field path part.
+/// - `Index` selects the element by the index e.g., `containers[0]`.
+/// - `Key` selects the config elements whose key equals to the value
+///    e.g., `containers[name=web]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ListSelector {
+    Index(usize),
+    Key(String, String),
+}
+
+/// Parse the field path into parts and check whether each part name is a
+/// valid identifier and each selector is valid.
+///
+/// parse_field_path("app.containers[name=web].image") -> [
+///     FieldPathPart { name: "app", selector: None },
+///     FieldPathPart { name: "containers", selector: Some(ListSelector::Key("name", "web")) },
+///     FieldPathPart { name: "image", selector: None },
+/// ]
+pub(crate) fn parse_field_path(field_path: &str) -> Result<Vec<FieldPathPart>> {
+    let mut parts = vec![];
+    for part in split_outside_brackets(field_path, '.') {
+        let (name, selector) = match part.find('[') {
+            Some(pos) => match part[pos + 1..].strip_suffix(']') {
+                Some(selector) => (
+                    &part[..pos],
+                    Some(parse_list_selector(field_path, selector)?),
+                ),
+                None => {
+                    return Err(anyhow!(
+                        "Invalid field path {:?}, the part {:?} has an unclosed selector",
+                        field_path,
+                        part
+                    ))
+                }
+            },
+            None => (part, None),
+        };
+        if !is_identifier(name) {
+            return Err(anyhow!(
+                "Invalid field path {:?}, the part {:?} is not a valid identifier",
+                field_path,
+                part
+            ));
+        }
+        parts.push(FieldPathPart {
+            name: name.to_string(),
+            selector,
+        });
+    }
+    Ok(parts)
+}
+
+/// Parse the list selector in brackets e.g., `0` and `name=web`.
+fn parse_list_selector(field_path: &str, selector: &str) -> Result<ListSelector> {
+    let selector = selector.trim();
+    if let Ok(index) = selector.parse::<usize>() {
+        return Ok(ListSelector::Index(index));
+    }
+    if let Some((key, value)) = selector.split_once('=') {
+        let (key, value) = (key.trim(), value.trim());
+        let value = strip_quotes(value).unwrap_or(value);
+        if is_identifier(key) && !value.is_empty() {
+            return Ok(ListSelector::Key(key.to_string(), value.to_string()));
+        }
+    }
+    Err(anyhow!(
+        "Invalid field path {:?}, the selector [{}] is neither an index nor a <key>=<value> pair",
+        field_path,
+        selector
+    ))
+}
+
+/// Split the string by the separator which is not in brackets.
+///
+/// split_outside_brackets("a[b=c].d=e", '=') -> ["a[b=c].d", "e"]
+pub(crate) fn split_outside_brackets(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth > 0 => depth -= 1,
+            _ if c == sep && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Whether the string is a valid identifier name, the `$` prefix is used
+/// to escape keywords.
+fn is_identifier(s: &str) -> bool {
+    let name = s.strip_prefix('$').unwrap_or(s);
+    match name.chars().next() {
+        Some(c) => {
+            (c.is_alphabetic() || c == '_') && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        }
+        None => false,
+    }
+}
+
+/// Strip the quotes of a quoted string e.g., `"web"` and `'web'`.
+fn strip_quotes(s: &str) -> Option<&str> {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+}
+
 /// Get the invalid spec error message.
 #[inline]
 pub(crate) fn invalid_spec_error(spec: &str) -> anyhow::Error {
@@ -0,0 +1,18 @@
+import sys
+import os
+
+import kclvm.kcl.error as kcl_error
+
+cwd = os.path.dirname(os.path.realpath(__file__))
+
+kcl_error.print_kcl_error_message(
+    kcl_error.get_exception(err_type=kcl_error.ErrType.ValueError_TYPE,
+                            file_msgs=[
+                                kcl_error.ErrFileMsg(
+                                    filename=cwd + "/main.k",
+                                    line_no=3,
+                                ),
+                            ],
+                            arg_msg="invalid glob pattern '/etc/*': the pattern must be relative to the module root")
+    , file=sys.stdout
+)
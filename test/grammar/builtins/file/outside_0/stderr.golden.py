@@ -0,0 +1,18 @@
+import sys
+import os
+
+import kclvm.kcl.error as kcl_error
+
+cwd = os.path.dirname(os.path.realpath(__file__))
+
+kcl_error.print_kcl_error_message(
+    kcl_error.get_exception(err_type=kcl_error.ErrType.EvaluationError_TYPE,
+                            file_msgs=[
+                                kcl_error.ErrFileMsg(
+                                    filename=cwd + "/main.k",
+                                    line_no=3,
+                                ),
+                            ],
+                            arg_msg="the file '../read_0/data/hello.txt' is outside of the module root '{}'".format(cwd))
+    , file=sys.stdout
+)